@@ -16,6 +16,26 @@ fn display_hex(v: Option<u8>) -> String {
     }
 }
 
+/// The suffix of ops that take an 8 bit immediate.
+fn imm8_suffix(imm8: bool) -> &'static str {
+    if imm8 { "_imm8" } else { "" }
+}
+
+/// The immediate parameter, for ops that take one.
+fn imm8_param(imm8: bool) -> &'static str {
+    if imm8 { ", imm: u8" } else { "" }
+}
+
+/// The immediate argument passed on to the encoder.
+fn imm8_arg(imm8: bool) -> &'static str {
+    if imm8 { "Some(imm)" } else { "None" }
+}
+
+fn doc_comment(doc: Option<&str>) -> String {
+    doc.map(|doc| format!("    /// {doc}\n"))
+        .unwrap_or_default()
+}
+
 #[derive(Debug, serde_derive::Deserialize, Clone)]
 struct Op {
     name: String,
@@ -25,9 +45,20 @@ struct Op {
     mm: Option<u8>,
     min: u8,
     max: u8,
+    #[serde(default)]
+    lock: bool,
+    prefix: Option<u8>,
+    doc: Option<String>,
 }
 
 impl Op {
+    /// Writes the mandatory prefix, if there is one.
+    fn prefix(&self) -> String {
+        self.prefix
+            .map(|prefix| format!("self.write_byte({prefix:#02x?})?;\n        "))
+            .unwrap_or_default()
+    }
+
     fn mm(&self) -> String {
         display_hex(self.mm)
     }
@@ -43,6 +74,8 @@ struct SingleSizeOp {
     op: u8,
     rm: Option<u8>,
     mm: Option<u8>,
+    #[serde(default)]
+    lock: bool,
 }
 
 impl SingleSizeOp {
@@ -51,6 +84,9 @@ impl SingleSizeOp {
     }
 }
 
+/// A VEX encoded general purpose instruction, `pp` and `mm` are like in VEX.
+///
+/// `rm` is the opcode extension for group encodings (like `blsr`), where the destination goes in `vvvv` instead.
 #[derive(Debug, serde_derive::Deserialize, Clone)]
 struct VexOp {
     name: String,
@@ -58,6 +94,193 @@ struct VexOp {
     rm: Option<u8>,
     mm: u8,
     pp: u8,
+    #[serde(default)]
+    imm8: bool,
+    doc: Option<String>,
+}
+
+impl VexOp {
+    /// The start of every call to the encoder, `pp`, `mm`, `op` and the opcode extension.
+    fn opcode(&self) -> String {
+        format!(
+            "{}, {}, {:#02x?}, {}",
+            self.pp,
+            self.mm,
+            self.op,
+            display_hex(self.rm)
+        )
+    }
+}
+
+/// A legacy SSE instruction, `prefix` is the mandatory prefix and `width` is the size of the memory operand (if it's fixed).
+///
+/// `map` is the opcode map (1 is `0F`, 2 is `0F 38` and 3 is `0F 3A`), it defaults to `0F`.
+/// `reg_width` is the size of the general purpose register, for ops where it isn't generic.
+#[derive(Debug, serde_derive::Deserialize, Clone)]
+struct SseOp {
+    name: String,
+    prefix: Option<u8>,
+    map: Option<u8>,
+    op: u8,
+    width: Option<u8>,
+    reg_width: Option<u8>,
+    #[serde(default)]
+    imm8: bool,
+    doc: Option<String>,
+}
+
+impl SseOp {
+    fn prefix(&self) -> String {
+        display_hex(self.prefix)
+    }
+
+    fn map(&self) -> u8 {
+        self.map.unwrap_or(1)
+    }
+
+    fn width(&self) -> u8 {
+        self.width.expect("op needs a memory width")
+    }
+
+    fn reg_width(&self) -> u8 {
+        self.reg_width.expect("op needs a register width")
+    }
+
+    /// The bound for the memory operand, vectors aren't general purpose widths.
+    fn mem_bound(&self) -> String {
+        match self.width() {
+            128 => "VectorMemory<W128>".to_owned(),
+            width => format!("Memory<W{width}>"),
+        }
+    }
+}
+
+/// A VEX encoded vector instruction, `pp` and `mm` are like in VEX.
+///
+/// `width` is the size of the source for broadcasts, which take an [`Xmm`] or memory of that size.
+/// `ymm_only` ops don't have a 128 bit form.
+#[derive(Debug, serde_derive::Deserialize, Clone)]
+struct AvxOp {
+    name: String,
+    pp: u8,
+    mm: u8,
+    op: u8,
+    #[serde(default)]
+    w: bool,
+    width: Option<u8>,
+    #[serde(default)]
+    imm8: bool,
+    #[serde(default)]
+    ymm_only: bool,
+    doc: Option<String>,
+}
+
+impl AvxOp {
+    /// The generic parameters, with `extra` appended.
+    fn generics(&self, extra: &str) -> String {
+        let mut params = Vec::new();
+
+        if !self.ymm_only {
+            params.push("Width: VectorWidth, V: VectorRegister<Width>");
+        }
+
+        if !extra.is_empty() {
+            params.push(extra);
+        }
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", params.join(", "))
+        }
+    }
+
+    /// The name of vector operands.
+    fn vec(&self) -> &'static str {
+        if self.ymm_only { "ymm" } else { "vec" }
+    }
+
+    fn vec_type(&self) -> &'static str {
+        if self.ymm_only { "Ymm" } else { "V" }
+    }
+
+    fn width_type(&self) -> &'static str {
+        if self.ymm_only { "W256" } else { "Width" }
+    }
+
+    fn mem_bound(&self) -> String {
+        match self.width {
+            Some(width) => format!("M: Memory<W{width}>"),
+            None => format!("M: VectorMemory<{}>", self.width_type()),
+        }
+    }
+}
+
+/// An EVEX encoded AVX-512 instruction, these only come in the 512 bit form.
+///
+/// `bcst` is the element size for ops that support embedded broadcast,
+/// `width` is the size of the source for broadcasts, which take an [`Xmm`] or memory of that size.
+#[derive(Debug, serde_derive::Deserialize, Clone)]
+struct Avx512Op {
+    name: String,
+    pp: u8,
+    mm: u8,
+    op: u8,
+    #[serde(default)]
+    w: bool,
+    bcst: Option<u8>,
+    width: Option<u8>,
+    #[serde(default)]
+    rounding: bool,
+    #[serde(default)]
+    imm8: bool,
+    doc: Option<String>,
+}
+
+impl Avx512Op {
+    /// The start of every call to the encoder, `pp`, `mm`, `op` and `W`.
+    fn opcode(&self) -> String {
+        format!("{}, {}, {:#02x?}, {}", self.pp, self.mm, self.op, self.w)
+    }
+}
+
+/// A string instruction, which comes in every width with a `b`, `w`, `d` or `q` suffix.
+///
+/// `prefixes` are the repeat prefixes it can take (`rep`, `repe` or `repne`).
+#[derive(Debug, serde_derive::Deserialize, Clone)]
+struct StringOp {
+    name: String,
+    op8: u8,
+    op: u8,
+    prefixes: Vec<String>,
+    doc: String,
+}
+
+/// A sign or zero extending move from a `src` bit register or memory operand into a register that's at least `min` bits.
+#[derive(Debug, serde_derive::Deserialize, Clone)]
+struct ExtendOp {
+    name: String,
+    op: u8,
+    mm: Option<u8>,
+    src: u8,
+    min: u8,
+    doc: Option<String>,
+}
+
+impl ExtendOp {
+    /// The generic parameters for the destination register, with `extra` appended.
+    fn generics(&self, extra: &str) -> String {
+        let reg = match self.min {
+            64 => "R: GeneralRegister<W64>".to_owned(),
+            min => format!("Width: WidthAtLeast{min}, R: GeneralRegister<Width>"),
+        };
+
+        if extra.is_empty() {
+            format!("<{reg}>")
+        } else {
+            format!("<{reg}, {extra}>")
+        }
+    }
 }
 
 #[derive(Debug, serde_derive::Deserialize)]
@@ -70,58 +293,109 @@ struct Ops {
     rm_reg: Vec<Op>,
     no_operands: Vec<SingleSizeOp>,
     reg_rm_reg: Vec<VexOp>,
+    reg_reg_rm: Vec<VexOp>,
+    vex_rm: Vec<VexOp>,
     rm: Vec<Op>,
+    rm_1: Vec<Op>,
+    rm_cl: Vec<Op>,
+    extend: Vec<ExtendOp>,
+    jcc: Vec<SingleSizeOp>,
+    sse_xmm_rm: Vec<SseOp>,
+    sse_xmm_gpr: Vec<SseOp>,
+    sse_gpr_xmm: Vec<SseOp>,
+    sse_mem_xmm: Vec<SseOp>,
+    sse_xmm_gpr_imm8: Vec<SseOp>,
+    sse_gpr_xmm_imm8: Vec<SseOp>,
+    avx_rvm: Vec<AvxOp>,
+    avx_rvm_mask: Vec<AvxOp>,
+    avx_rm: Vec<AvxOp>,
+    avx_mr: Vec<AvxOp>,
+    avx512_rvm: Vec<Avx512Op>,
+    avx512_rm: Vec<Avx512Op>,
+    avx512_mr: Vec<Avx512Op>,
+    avx512_k_rvm: Vec<Avx512Op>,
+    string: Vec<StringOp>,
 }
 
 fn write_op_zax_imm(f: &mut File, op: Op) {
-    writeln!(f, r#"    pub fn {name}_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> io::Result<()> {{
+    writeln!(f, r#"    pub fn {name}_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> Result<()> {{
         self.op_zax_imm(imm, {op8:#02x?}, {op:#02x?})
     }}
 "#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op)).unwrap();
 }
 
 fn write_op_reg_imm(f: &mut File, op: Op) {
-    writeln!(f, r#"    pub fn {name}_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R, imm: impl Immediate<Width>) -> io::Result<()> {{
+    writeln!(f, r#"    pub fn {name}_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R, imm: impl Immediate<Width>) -> Result<()> {{
         self.op_reg_imm(reg, imm, {op8:#02x?}, {op:#02x?}, {rm})
     }}
 "#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op), rm=op.rm.unwrap()).unwrap();
 }
 
 fn write_op_mem_imm(f: &mut File, op: Op) {
-    writeln!(f, r#"    pub fn {name}_mem_imm<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: impl Immediate<Width>) -> io::Result<()> {{
+    writeln!(f, r#"    pub fn {name}_mem_imm<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: impl Immediate<Width>) -> Result<()> {{
         self.op_mem_imm(mem, imm, {op8:#02x?}, {op:#02x?}, {rm})
     }}
 "#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op), rm=op.rm.unwrap()).unwrap();
 }
 
-fn write_op_reg_imm8(f: &mut File, op: Op) {
-    writeln!(f, r#"    pub fn {name}_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R, imm: u8) -> io::Result<()> {{
-        self.op_reg_imm8(reg, imm, {op8:#02x?}, {op:#02x?}, {rm}, {mm})
+/// Ops that have a by one form (in `rm_1`) use it when `imm` is 1, since it's shorter.
+fn by_one(name: &str, operand: &str, arg: &str, has_one: bool) -> String {
+    if !has_one {
+        return String::new();
+    }
+
+    format!(
+        r#"if imm == 1 {{
+            return self.{name}_{operand}_1({arg});
+        }}
+
+        "#
+    )
+}
+
+fn write_op_reg_imm8(f: &mut File, op: Op, has_one: bool) {
+    writeln!(f, r#"    pub fn {name}_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R, imm: u8) -> Result<()> {{
+        {by_one}self.op_reg_imm8(reg, imm, {op8:#02x?}, {op:#02x?}, {rm}, {mm})
     }}
-"#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op), rm=op.rm.unwrap(), mm=op.mm()).unwrap();
+"#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op), rm=op.rm.unwrap(), mm=op.mm(), by_one=by_one(&op.name, "reg", "reg", has_one)).unwrap();
 }
 
-fn write_op_mem_imm8(f: &mut File, op: Op) {
-    writeln!(f, r#"    pub fn {name}_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> io::Result<()> {{
-        self.op_mem_imm8(mem, imm, {op8:#02x?}, {op:#02x?}, {rm}, {mm})
+fn write_op_mem_imm8(f: &mut File, op: Op, has_one: bool) {
+    writeln!(f, r#"    pub fn {name}_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {{
+        {by_one}self.op_mem_imm8(mem, imm, {op8:#02x?}, {op:#02x?}, {rm}, {mm})
     }}
-"#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op), rm=op.rm.unwrap(), mm=op.mm()).unwrap();
+"#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op), rm=op.rm.unwrap(), mm=op.mm(), by_one=by_one(&op.name, "mem", "mem", has_one)).unwrap();
 }
 
 fn write_op_reg_sximm8(f: &mut File, op: SingleSizeOp) {
-    writeln!(f, r#"    pub fn {name}_reg_sximm8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(&mut self, reg: R, imm: i8) -> io::Result<()> {{
+    writeln!(f, r#"    pub fn {name}_reg_sximm8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(&mut self, reg: R, imm: i8) -> Result<()> {{
         self.op_reg_imm8(reg, imm as u8, {op:#02x?}, {op:#02x?}, {rm}, {mm})
     }}
 "#, name=op.name, op=op.op, rm=op.rm.unwrap(), mm=op.mm()).unwrap();
 }
 
 fn write_op_mem_sximm8(f: &mut File, op: SingleSizeOp) {
-    writeln!(f, r#"    pub fn {name}_mem_sximm8<Width: WidthAtLeast16, M: Memory<Width>>(&mut self, mem: M, imm: i8) -> io::Result<()> {{
+    writeln!(f, r#"    pub fn {name}_mem_sximm8<Width: WidthAtLeast16, M: Memory<Width>>(&mut self, mem: M, imm: i8) -> Result<()> {{
         self.op_mem_imm8(mem, imm as u8, {op:#02x?}, {op:#02x?}, {rm}, {mm})
     }}
 "#, name=op.name, op=op.op, rm=op.rm.unwrap(), mm=op.mm()).unwrap();
 }
 
+/// Writes `lock_{name}{suffix}`, which is `{name}{suffix}` with a `LOCK` prefix.
+///
+/// These only exist for memory destinations, `LOCK` is `#UD` on anything else.
+fn write_op_lock(f: &mut File, name: &str, suffix: &str, generics: &str, params: &str, args: &str) {
+    writeln!(
+        f,
+        r#"    pub fn lock_{name}{suffix}{generics}(&mut self, {params}) -> Result<()> {{
+        self.lock()?;
+        self.{name}{suffix}({args})
+    }}
+"#
+    )
+    .unwrap();
+}
+
 fn width_bound(op: &Op) -> String {
     match (op.min > 8, op.max < 64) {
         (true, true) => format!("WidthAtLeast{} + WidthAtMost{}", op.min, op.max),
@@ -132,28 +406,119 @@ fn width_bound(op: &Op) -> String {
 }
 
 fn write_op_mem_reg(f: &mut File, op: Op) {
-    writeln!(f, r#"    pub fn {name}_mem_reg<Width: {width_bound}, R, M>(&mut self, mem: M, reg: R) -> io::Result<()> where R: GeneralRegister<Width>, M: Memory<Width> {{
+    writeln!(f, r#"    pub fn {name}_mem_reg<Width: {width_bound}, R, M>(&mut self, mem: M, reg: R) -> Result<()> where R: GeneralRegister<Width>, M: Memory<Width> {{
         self.op_rm_mr(reg, mem, {op8:#02x?}, {op:#02x?}, {mm})
     }}
 "#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op), width_bound=width_bound(&op), mm=op.mm()).unwrap();
 }
 
 fn write_op_reg_mem(f: &mut File, op: Op) {
-    writeln!(f, r#"    pub fn {name}_reg_mem<Width: {width_bound}, R, M>(&mut self, reg: R, mem: M) -> io::Result<()> where R: GeneralRegister<Width>, M: Memory<Width> {{
-        self.op_rm_mr(reg, mem, {op8:#02x?}, {op:#02x?}, {mm})
+    writeln!(f, r#"    pub fn {name}_reg_mem<Width: {width_bound}, R, M>(&mut self, reg: R, mem: M) -> Result<()> where R: GeneralRegister<Width>, M: Memory<Width> {{
+        {prefix}self.op_rm_mr(reg, mem, {op8:#02x?}, {op:#02x?}, {mm})
     }}
-"#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op), width_bound=width_bound(&op), mm=op.mm()).unwrap();
+"#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op), width_bound=width_bound(&op), mm=op.mm(), prefix=op.prefix()).unwrap();
+}
+
+fn write_op_hi8_imm(f: &mut File, op: &Op) {
+    writeln!(
+        f,
+        r#"    pub fn {name}_hi8_imm(&mut self, reg: Hi8, imm: impl Immediate<W8>) -> Result<()> {{
+        self.op_hi8_imm(reg, imm, {op8:#02x?}, {rm})
+    }}
+"#,
+        name = op.name,
+        op8 = op.op8.unwrap_or(op.op),
+        rm = op.rm.unwrap()
+    )
+    .unwrap();
+}
+
+fn write_op_hi8_imm8(f: &mut File, op: &Op, has_one: bool) {
+    writeln!(
+        f,
+        r#"    pub fn {name}_hi8_imm8(&mut self, reg: Hi8, imm: u8) -> Result<()> {{
+        {by_one}self.op_hi8_imm(reg, Imm8(imm), {op8:#02x?}, {rm})
+    }}
+"#,
+        by_one = by_one(&op.name, "hi8", "reg", has_one),
+        name = op.name,
+        op8 = op.op8.unwrap_or(op.op),
+        rm = op.rm.unwrap()
+    )
+    .unwrap();
+}
+
+fn write_op_hi8_mem(f: &mut File, op: &Op) {
+    writeln!(
+        f,
+        r#"    pub fn {name}_hi8_mem<M: Memory<W8>>(&mut self, reg: Hi8, mem: M) -> Result<()> {{
+        self.op_hi8_mem(reg, mem.into(), {op8:#02x?}, {mm}, false)
+    }}
+"#,
+        name = op.name,
+        op8 = op.op8.unwrap_or(op.op),
+        mm = op.mm()
+    )
+    .unwrap();
+}
+
+/// `lock` writes `lock_{name}_mem_hi8` instead, the prefix has to go through `op_hi8_mem`
+/// so that it doesn't get written when the memory operand needs a REX prefix.
+fn write_op_mem_hi8(f: &mut File, op: &Op, lock: bool) {
+    writeln!(
+        f,
+        r#"    pub fn {prefix}{name}_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {{
+        self.op_hi8_mem(reg, mem.into(), {op8:#02x?}, {mm}, {lock})
+    }}
+"#,
+        prefix = if lock { "lock_" } else { "" },
+        name = op.name,
+        op8 = op.op8.unwrap_or(op.op),
+        mm = op.mm()
+    )
+    .unwrap();
+}
+
+/// The same as [`write_op_reg_reg`], for every combination with a high byte register.
+fn write_op_hi8_reg(f: &mut File, op: &Op) {
+    writeln!(f, r#"    pub fn {name}_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {{
+        self.op_hi8_reg(reg1, reg2.into(), {op8:#02x?}, {mm})
+    }}
+
+    pub fn {name}_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {{
+        self.op_reg_hi8(reg1.into(), reg2, {op8:#02x?}, {mm})
+    }}
+
+    pub fn {name}_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {{
+        self.op_hi8_hi8(reg1, reg2, {op8:#02x?}, {mm})
+    }}
+"#, name=op.name, op8=op.op8.unwrap_or(op.op), mm=op.mm()).unwrap();
+}
+
+fn write_op_hi8(f: &mut File, op: &Op, suffix: &str) {
+    writeln!(
+        f,
+        r#"    pub fn {name}_hi8{suffix}(&mut self, reg: Hi8) -> Result<()> {{
+        self.op_hi8_rm_bits(reg, {op8:#02x?}, {rm}, {mm}, None)
+    }}
+"#,
+        name = op.name,
+        op8 = op.op8.unwrap_or(op.op),
+        rm = op.rm(),
+        mm = op.mm()
+    )
+    .unwrap();
 }
 
 fn write_op_reg_reg(f: &mut File, op: Op) {
-    writeln!(f, r#"    pub fn {name}_reg_reg<Width: {width_bound}, R>(&mut self, reg1: R, reg2: R) -> io::Result<()> where R: GeneralRegister<Width> {{
-        self.op_reg_reg(reg1, reg2, {op8:#02x?}, {op:#02x?}, {mm})
+    writeln!(f, r#"    pub fn {name}_reg_reg<Width: {width_bound}, R>(&mut self, reg1: R, reg2: R) -> Result<()> where R: GeneralRegister<Width> {{
+        {prefix}self.op_reg_reg(reg1, reg2, {op8:#02x?}, {op:#02x?}, {mm})
     }}
-"#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op), width_bound=width_bound(&op), mm=op.mm()).unwrap();
+"#, name=op.name, op=op.op, op8=op.op8.unwrap_or(op.op), width_bound=width_bound(&op), mm=op.mm(), prefix=op.prefix()).unwrap();
 }
 
 fn write_op_reg_mem_reg(f: &mut File, op: VexOp) {
-    writeln!(f, r#"    pub fn {name}_reg_mem_reg<Width: WidthAtLeast32, R, M>(&mut self, rd: R, mem: M, rs: R) -> io::Result<()>
+    writeln!(f, r#"    pub fn {name}_reg_mem_reg<Width: WidthAtLeast32, R, M>(&mut self, rd: R, mem: M, rs: R) -> Result<()>
         where R: GeneralRegister<Width>, M: Memory<Width>
     {{
         self.op_reg_mem_reg(rd, mem, rs, {mm:#02x?}, {op:#02x?}, {pp:#02x?})
@@ -162,29 +527,99 @@ fn write_op_reg_mem_reg(f: &mut File, op: VexOp) {
 }
 
 fn write_op_reg_reg_reg(f: &mut File, op: VexOp) {
-    writeln!(f, r#"    pub fn {name}_reg_reg_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(&mut self, rd: R, rs1: R, rs2: R) -> io::Result<()>
+    writeln!(f, r#"    pub fn {name}_reg_reg_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(&mut self, rd: R, rs1: R, rs2: R) -> Result<()>
     {{
         self.op_reg_reg_reg(rd, rs1, rs2, {mm:#02x?}, {op:#02x?}, {pp:#02x?})
     }}
 "#, name=op.name, op=op.op, mm=op.mm, pp=op.pp).unwrap();
 }
 
-fn write_op_reg(f: &mut File, op: Op) -> io::Result<()> {
-    write!(f, "    pub fn {name}_reg", name = op.name)?;
+/// Like [`write_op_reg_reg_reg`], but `rs1` goes in `vvvv` and `rs2` in ModRM.rm, so the memory operand comes last.
+fn write_op_reg_reg_rm(f: &mut File, op: &VexOp) {
+    writeln!(f, r#"{doc}    pub fn {name}_reg_reg_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(&mut self, rd: R, rs1: R, rs2: R) -> Result<()> {{
+        self.op_reg_reg_reg(rd, rs2, rs1, {mm:#02x?}, {op:#02x?}, {pp:#02x?})
+    }}
+
+{doc}    pub fn {name}_reg_reg_mem<Width: WidthAtLeast32, R, M>(&mut self, rd: R, rs: R, mem: M) -> Result<()>
+        where R: GeneralRegister<Width>, M: Memory<Width>
+    {{
+        self.op_reg_mem_reg(rd, mem, rs, {mm:#02x?}, {op:#02x?}, {pp:#02x?})
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, op=op.op, mm=op.mm, pp=op.pp).unwrap();
+}
+
+fn write_op_vex_rm(f: &mut File, op: &VexOp) {
+    writeln!(f, r#"{doc}    pub fn {name}_reg_reg{suffix}<Width: WidthAtLeast32, R: GeneralRegister<Width>>(&mut self, rd: R, rs: R{imm_param}) -> Result<()> {{
+        self.op_vex_reg_rm::<Width>({opcode}, rd.into(), rs.into(), {imm_arg})
+    }}
+
+{doc}    pub fn {name}_reg_mem{suffix}<Width: WidthAtLeast32, R, M>(&mut self, rd: R, mem: M{imm_param}) -> Result<()>
+        where R: GeneralRegister<Width>, M: Memory<Width>
+    {{
+        self.op_vex_reg_mem::<Width>({opcode}, rd.into(), mem.into(), {imm_arg})
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, suffix=imm8_suffix(op.imm8), opcode=op.opcode(), imm_param=imm8_param(op.imm8), imm_arg=imm8_arg(op.imm8)).unwrap();
+}
+
+fn write_op_extend(f: &mut File, op: &ExtendOp) {
+    writeln!(f, r#"{doc}    pub fn {name}_reg_reg{src}{reg_generics}(&mut self, reg: R, src: S) -> Result<()> {{
+        self.op_extend_reg(reg, src, {op:#02x?}, {mm})
+    }}
+
+{doc}    pub fn {name}_reg_mem{src}{mem_generics}(&mut self, reg: R, mem: M) -> Result<()> {{
+        self.op_extend_mem(reg, mem, {op:#02x?}, {mm})
+    }}
+"#,
+        doc = doc_comment(op.doc.as_deref()),
+        name = op.name,
+        src = op.src,
+        reg_generics = op.generics(&format!("S: GeneralRegister<W{}>", op.src)),
+        mem_generics = op.generics(&format!("M: Memory<W{}>", op.src)),
+        op = op.op,
+        mm = display_hex(op.mm),
+    ).unwrap();
+
+    // a high byte register can only be a source for 8 bit ones.
+    if op.src == 8 {
+        writeln!(f, r#"{doc}    pub fn {name}_reg_hi8{generics}(&mut self, reg: R, src: Hi8) -> Result<()> {{
+        self.op_extend_hi8(reg, src, {op:#02x?}, {mm})
+    }}
+"#,
+            doc = doc_comment(op.doc.as_deref()),
+            name = op.name,
+            generics = op.generics(""),
+            op = op.op,
+            mm = display_hex(op.mm),
+        ).unwrap();
+    }
+}
+
+/// `suffix` goes after the operands, for ops that have an implicit one (like `shl_reg_cl`).
+fn write_op_reg(f: &mut File, op: Op, suffix: &str) -> io::Result<()> {
+    write!(
+        f,
+        "{doc}    pub fn {name}_reg",
+        doc = doc_comment(op.doc.as_deref()),
+        name = op.name
+    )?;
 
     if op.max == op.min {
-        write!(f, "{size}<R: GeneralRegister<W{size}>>", size = op.min)?;
+        write!(
+            f,
+            "{size}{suffix}<R: GeneralRegister<W{size}>>",
+            size = op.min
+        )?;
     } else {
         write!(
             f,
-            "<Width: {width_bound}, R: GeneralRegister<Width>>",
+            "{suffix}<Width: {width_bound}, R: GeneralRegister<Width>>",
             width_bound = width_bound(&op)
         )?;
     }
 
     writeln!(
         f,
-        r#"(&mut self, reg: R) -> io::Result<()> {{
+        r#"(&mut self, reg: R) -> Result<()> {{
         self.op_reg(reg, {op8:#02x?}, {op:#02x?}, {rm}, {mm})
     }}
 "#,
@@ -195,22 +630,27 @@ fn write_op_reg(f: &mut File, op: Op) -> io::Result<()> {
     )
 }
 
-fn write_op_mem(f: &mut File, op: Op) -> io::Result<()> {
-    write!(f, "    pub fn {name}_mem", name = op.name)?;
+fn write_op_mem(f: &mut File, op: Op, suffix: &str) -> io::Result<()> {
+    write!(
+        f,
+        "{doc}    pub fn {name}_mem",
+        doc = doc_comment(op.doc.as_deref()),
+        name = op.name
+    )?;
 
     if op.max == op.min {
-        write!(f, "{size}<M: Memory<W{size}>>", size = op.min)?;
+        write!(f, "{size}{suffix}<M: Memory<W{size}>>", size = op.min)?;
     } else {
         write!(
             f,
-            "<Width: {width_bound}, M: Memory<Width>>",
+            "{suffix}<Width: {width_bound}, M: Memory<Width>>",
             width_bound = width_bound(&op)
         )?;
     }
 
     writeln!(
         f,
-        r#"(&mut self, mem: M) -> io::Result<()> {{
+        r#"(&mut self, mem: M) -> Result<()> {{
         self.op_mem(mem, {op8:#02x?}, {op:#02x?}, {rm}, {mm})
     }}
 "#,
@@ -225,7 +665,7 @@ fn write_op_no_operand(f: &mut File, op: SingleSizeOp) {
     assert_eq!(op.rm, None);
     writeln!(
         f,
-        r#"    pub fn {name}(&mut self) -> io::Result<()> {{
+        r#"    pub fn {name}(&mut self) -> Result<()> {{
         self.op_no_operands({op:#02x?}, {mm})
     }}
 "#,
@@ -236,6 +676,264 @@ fn write_op_no_operand(f: &mut File, op: SingleSizeOp) {
     .unwrap();
 }
 
+fn write_op_jcc_label(f: &mut File, op: SingleSizeOp) {
+    writeln!(
+        f,
+        r#"    pub fn {name}_label(&mut self, label: Label) -> Result<()> {{
+        self.op_jcc_label({op:#02x?}, label)
+    }}
+"#,
+        name = op.name,
+        op = op.op,
+    )
+    .unwrap();
+}
+
+fn write_op_xmm_xmm(f: &mut File, op: &SseOp) {
+    writeln!(f, r#"{doc}    pub fn {name}_xmm_xmm{suffix}(&mut self, dst: Xmm, src: Xmm{imm_param}) -> Result<()> {{
+        self.op_sse_rr::<W32>({prefix}, {map}, {op:#02x?}, dst.value(), src.value(), {imm_arg})
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, suffix=imm8_suffix(op.imm8), imm_param=imm8_param(op.imm8), prefix=op.prefix(), map=op.map(), op=op.op, imm_arg=imm8_arg(op.imm8)).unwrap();
+}
+
+fn write_op_xmm_mem(f: &mut File, op: &SseOp) {
+    writeln!(f, r#"{doc}    pub fn {name}_xmm_mem{suffix}<M: {bound}>(&mut self, dst: Xmm, mem: M{imm_param}) -> Result<()> {{
+        self.op_sse_rm::<W32>({prefix}, {map}, {op:#02x?}, dst.value(), mem.into(), {imm_arg})
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, suffix=imm8_suffix(op.imm8), bound=op.mem_bound(), imm_param=imm8_param(op.imm8), prefix=op.prefix(), map=op.map(), op=op.op, imm_arg=imm8_arg(op.imm8)).unwrap();
+}
+
+fn write_op_mem_xmm(f: &mut File, op: &SseOp) {
+    writeln!(
+        f,
+        r#"{doc}    pub fn {name}_mem_xmm<M: {bound}>(&mut self, mem: M, src: Xmm) -> Result<()> {{
+        self.op_sse_rm::<W32>({prefix}, {map}, {op:#02x?}, src.value(), mem.into(), None)
+    }}
+"#,
+        doc = doc_comment(op.doc.as_deref()),
+        name = op.name,
+        bound = op.mem_bound(),
+        prefix = op.prefix(),
+        map = op.map(),
+        op = op.op
+    )
+    .unwrap();
+}
+
+fn write_op_xmm_gpr(f: &mut File, op: &SseOp) {
+    writeln!(f, r#"    pub fn {name}_xmm_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(&mut self, dst: Xmm, src: R) -> Result<()> {{
+        let src: Register = src.into();
+        self.op_sse_rr::<Width>({prefix}, {map}, {op:#02x?}, dst.value(), src.value(), None)
+    }}
+
+    pub fn {name}_xmm_mem<Width: WidthAtLeast32, M: Memory<Width>>(&mut self, dst: Xmm, mem: M) -> Result<()> {{
+        self.op_sse_rm::<Width>({prefix}, {map}, {op:#02x?}, dst.value(), mem.into(), None)
+    }}
+"#, name=op.name, prefix=op.prefix(), map=op.map(), op=op.op).unwrap();
+}
+
+fn write_op_gpr_xmm(f: &mut File, op: &SseOp) {
+    writeln!(f, r#"{doc}    pub fn {name}_reg_xmm<Width: WidthAtLeast32, R: GeneralRegister<Width>>(&mut self, dst: R, src: Xmm) -> Result<()> {{
+        let dst: Register = dst.into();
+        self.op_sse_rr::<Width>({prefix}, {map}, {op:#02x?}, dst.value(), src.value(), None)
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, prefix=op.prefix(), map=op.map(), op=op.op).unwrap();
+
+    // some of these (`pmovmskb`) only take a register.
+    if op.width.is_some() {
+        writeln!(f, r#"    pub fn {name}_reg_mem<Width: WidthAtLeast32, R: GeneralRegister<Width>, M: {bound}>(&mut self, dst: R, mem: M) -> Result<()> {{
+        let dst: Register = dst.into();
+        self.op_sse_rm::<Width>({prefix}, {map}, {op:#02x?}, dst.value(), mem.into(), None)
+    }}
+"#, name=op.name, prefix=op.prefix(), map=op.map(), op=op.op, bound=op.mem_bound()).unwrap();
+    }
+}
+
+fn write_op_xmm_gpr_imm8(f: &mut File, op: &SseOp) {
+    writeln!(f, r#"    pub fn {name}_xmm_reg_imm8<R: GeneralRegister<W{reg_width}>>(&mut self, dst: Xmm, src: R, imm: u8) -> Result<()> {{
+        let src: Register = src.into();
+        self.op_sse_rr::<W{reg_width}>({prefix}, {map}, {op:#02x?}, dst.value(), src.value(), Some(imm))
+    }}
+
+    pub fn {name}_xmm_mem_imm8<M: {bound}>(&mut self, dst: Xmm, mem: M, imm: u8) -> Result<()> {{
+        self.op_sse_rm::<W{reg_width}>({prefix}, {map}, {op:#02x?}, dst.value(), mem.into(), Some(imm))
+    }}
+"#, name=op.name, reg_width=op.reg_width(), prefix=op.prefix(), map=op.map(), op=op.op, bound=op.mem_bound()).unwrap();
+}
+
+fn write_op_gpr_xmm_imm8(f: &mut File, op: &SseOp) {
+    writeln!(f, r#"    pub fn {name}_reg_xmm_imm8<R: GeneralRegister<W{reg_width}>>(&mut self, dst: R, src: Xmm, imm: u8) -> Result<()> {{
+        let dst: Register = dst.into();
+        self.op_sse_rr::<W{reg_width}>({prefix}, {map}, {op:#02x?}, src.value(), dst.value(), Some(imm))
+    }}
+
+    pub fn {name}_mem_xmm_imm8<M: {bound}>(&mut self, mem: M, src: Xmm, imm: u8) -> Result<()> {{
+        self.op_sse_rm::<W{reg_width}>({prefix}, {map}, {op:#02x?}, src.value(), mem.into(), Some(imm))
+    }}
+"#, name=op.name, reg_width=op.reg_width(), prefix=op.prefix(), map=op.map(), op=op.op, bound=op.mem_bound()).unwrap();
+}
+
+fn write_op_vec_vec_vec(f: &mut File, op: &AvxOp) {
+    writeln!(f, r#"{doc}    pub fn {name}_{v}_{v}_{v}{suffix}{generics}(&mut self, dst: {vt}, src1: {vt}, src2: {vt}{imm_param}) -> Result<()> {{
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<{wt}>({pp}, {mm}, {op:#02x?}, {w}, dst.value(), src1.value(), src2.value(), {imm_arg})
+    }}
+
+{doc}    pub fn {name}_{v}_{v}_mem{suffix}{mem_generics}(&mut self, dst: {vt}, src1: {vt}, mem: M{imm_param}) -> Result<()> {{
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<{wt}>({pp}, {mm}, {op:#02x?}, {w}, dst.value(), src1.value(), mem.into(), {imm_arg})
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, v=op.vec(), suffix=imm8_suffix(op.imm8), generics=op.generics(""), mem_generics=op.generics(&op.mem_bound()), vt=op.vec_type(), wt=op.width_type(), imm_param=imm8_param(op.imm8), pp=op.pp, mm=op.mm, op=op.op, w=op.w, imm_arg=imm8_arg(op.imm8)).unwrap();
+}
+
+fn write_op_vec_vec_vec_mask(f: &mut File, op: &AvxOp) {
+    writeln!(f, r#"{doc}    pub fn {name}_{v}_{v}_{v}_{v}{generics}(&mut self, dst: {vt}, src1: {vt}, src2: {vt}, mask: {vt}) -> Result<()> {{
+        let (dst, src1, src2, mask): (Xmm, Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into(), mask.into());
+        self.op_avx_rr::<{wt}>({pp}, {mm}, {op:#02x?}, {w}, dst.value(), src1.value(), src2.value(), Some(mask.value() << 4))
+    }}
+
+{doc}    pub fn {name}_{v}_{v}_mem_{v}{mem_generics}(&mut self, dst: {vt}, src1: {vt}, mem: M, mask: {vt}) -> Result<()> {{
+        let (dst, src1, mask): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), mask.into());
+        self.op_avx_rm::<{wt}>({pp}, {mm}, {op:#02x?}, {w}, dst.value(), src1.value(), mem.into(), Some(mask.value() << 4))
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, v=op.vec(), generics=op.generics(""), mem_generics=op.generics(&op.mem_bound()), vt=op.vec_type(), wt=op.width_type(), pp=op.pp, mm=op.mm, op=op.op, w=op.w).unwrap();
+}
+
+fn write_op_vec_rm(f: &mut File, op: &AvxOp) {
+    // broadcasts take their source from an xmm register.
+    let (src, src_type, convert) = match op.width {
+        Some(_) => ("xmm", "Xmm", "let dst: Xmm = dst.into();"),
+        None => (
+            op.vec(),
+            op.vec_type(),
+            "let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());",
+        ),
+    };
+
+    writeln!(f, r#"{doc}    pub fn {name}_{v}_{src}{suffix}{generics}(&mut self, dst: {vt}, src: {src_type}{imm_param}) -> Result<()> {{
+        {convert}
+        self.op_avx_rr::<{wt}>({pp}, {mm}, {op:#02x?}, {w}, dst.value(), 0, src.value(), {imm_arg})
+    }}
+
+{doc}    pub fn {name}_{v}_mem{suffix}{mem_generics}(&mut self, dst: {vt}, mem: M{imm_param}) -> Result<()> {{
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<{wt}>({pp}, {mm}, {op:#02x?}, {w}, dst.value(), 0, mem.into(), {imm_arg})
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, v=op.vec(), suffix=imm8_suffix(op.imm8), generics=op.generics(""), mem_generics=op.generics(&op.mem_bound()), vt=op.vec_type(), wt=op.width_type(), imm_param=imm8_param(op.imm8), pp=op.pp, mm=op.mm, op=op.op, w=op.w, imm_arg=imm8_arg(op.imm8), convert=convert).unwrap();
+}
+
+fn write_op_mem_vec(f: &mut File, op: &AvxOp) {
+    writeln!(f, r#"{doc}    pub fn {name}_mem_{v}{mem_generics}(&mut self, mem: M, src: {vt}) -> Result<()> {{
+        let src: Xmm = src.into();
+        self.op_avx_rm::<{wt}>({pp}, {mm}, {op:#02x?}, {w}, src.value(), 0, mem.into(), None)
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, v=op.vec(), mem_generics=op.generics(&op.mem_bound()), vt=op.vec_type(), wt=op.width_type(), pp=op.pp, mm=op.mm, op=op.op, w=op.w).unwrap();
+}
+
+/// `dst` is either a [`Zmm`] or an [`Opmask`] (for compares), which can only be merge masked.
+fn write_op_avx512_rvm(f: &mut File, op: &Avx512Op, dst: &str) {
+    let (dst_type, mask_param, masking) = match dst {
+        "zmm" => ("Zmm", "masking: Masking", "masking"),
+        _ => ("Opmask", "mask: Option<Opmask>", "mask.into()"),
+    };
+
+    writeln!(f, r#"{doc}    pub fn {name}_{dst}_zmm_zmm{suffix}(&mut self, dst: {dst_type}, src1: Zmm, src2: Zmm, {mask_param}{imm_param}) -> Result<()> {{
+        self.op_evex_rr::<W512>({opcode}, dst.value(), src1.value(), src2.value(), {masking}, None, {imm_arg})
+    }}
+
+{doc}    pub fn {name}_{dst}_zmm_mem{suffix}<M: VectorMemory<W512>>(&mut self, dst: {dst_type}, src1: Zmm, mem: M, {mask_param}{imm_param}) -> Result<()> {{
+        self.op_evex_rm::<W512>({opcode}, dst.value(), src1.value(), mem.into(), {masking}, 64, false, {imm_arg})
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, suffix=imm8_suffix(op.imm8), imm_param=imm8_param(op.imm8), opcode=op.opcode(), imm_arg=imm8_arg(op.imm8)).unwrap();
+
+    if let Some(bcst) = op.bcst {
+        writeln!(f, r#"    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn {name}_{dst}_zmm_bcst{suffix}<M: Memory<W{bcst}>>(&mut self, dst: {dst_type}, src1: Zmm, mem: M, {mask_param}{imm_param}) -> Result<()> {{
+        self.op_evex_rm::<W512>({opcode}, dst.value(), src1.value(), mem.into(), {masking}, {n}, true, {imm_arg})
+    }}
+"#, name=op.name, suffix=imm8_suffix(op.imm8), imm_param=imm8_param(op.imm8), opcode=op.opcode(), imm_arg=imm8_arg(op.imm8), n=bcst / 8).unwrap();
+    }
+
+    if op.rounding {
+        writeln!(f, r#"    pub fn {name}_zmm_zmm_zmm_round(&mut self, dst: Zmm, src1: Zmm, src2: Zmm, masking: Masking, rounding: Rounding) -> Result<()> {{
+        self.op_evex_rr::<W512>({opcode}, dst.value(), src1.value(), src2.value(), masking, Some(rounding), None)
+    }}
+"#, name=op.name, opcode=op.opcode()).unwrap();
+    }
+}
+
+fn write_op_avx512_rm(f: &mut File, op: &Avx512Op) {
+    // broadcasts take their source from an xmm register.
+    let (src, src_type, mem_bound, n) = match op.width {
+        Some(width) => ("xmm", "Xmm", format!("Memory<W{width}>"), width / 8),
+        None => ("zmm", "Zmm", "VectorMemory<W512>".to_owned(), 64),
+    };
+
+    writeln!(f, r#"{doc}    pub fn {name}_zmm_{src}{suffix}(&mut self, dst: Zmm, src: {src_type}, masking: Masking{imm_param}) -> Result<()> {{
+        self.op_evex_rr::<W512>({opcode}, dst.value(), 0, src.value(), masking, None, {imm_arg})
+    }}
+
+{doc}    pub fn {name}_zmm_mem{suffix}<M: {mem_bound}>(&mut self, dst: Zmm, mem: M, masking: Masking{imm_param}) -> Result<()> {{
+        self.op_evex_rm::<W512>({opcode}, dst.value(), 0, mem.into(), masking, {n}, false, {imm_arg})
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, suffix=imm8_suffix(op.imm8), imm_param=imm8_param(op.imm8), opcode=op.opcode(), imm_arg=imm8_arg(op.imm8)).unwrap();
+
+    if let Some(bcst) = op.bcst {
+        writeln!(f, r#"    /// Broadcasts the element at `mem` to every element of the source.
+    pub fn {name}_zmm_bcst{suffix}<M: Memory<W{bcst}>>(&mut self, dst: Zmm, mem: M, masking: Masking{imm_param}) -> Result<()> {{
+        self.op_evex_rm::<W512>({opcode}, dst.value(), 0, mem.into(), masking, {n}, true, {imm_arg})
+    }}
+"#, name=op.name, suffix=imm8_suffix(op.imm8), imm_param=imm8_param(op.imm8), opcode=op.opcode(), imm_arg=imm8_arg(op.imm8), n=bcst / 8).unwrap();
+    }
+
+    if op.rounding {
+        writeln!(f, r#"    pub fn {name}_zmm_zmm_round(&mut self, dst: Zmm, src: Zmm, masking: Masking, rounding: Rounding) -> Result<()> {{
+        self.op_evex_rr::<W512>({opcode}, dst.value(), 0, src.value(), masking, Some(rounding), None)
+    }}
+"#, name=op.name, opcode=op.opcode()).unwrap();
+    }
+}
+
+fn write_op_avx512_mr(f: &mut File, op: &Avx512Op) {
+    writeln!(f, r#"{doc}    pub fn {name}_mem_zmm<M: VectorMemory<W512>>(&mut self, mem: M, src: Zmm, mask: Option<Opmask>) -> Result<()> {{
+        self.op_evex_rm::<W512>({opcode}, src.value(), 0, mem.into(), mask.into(), 64, false, None)
+    }}
+"#, doc=doc_comment(op.doc.as_deref()), name=op.name, opcode=op.opcode()).unwrap();
+}
+
+fn write_op_string(f: &mut File, op: &StringOp) {
+    for (suffix, width) in [("b", 8), ("w", 16), ("d", 32), ("q", 64)] {
+        writeln!(
+            f,
+            r#"    /// {doc}
+    pub fn {name}{suffix}(&mut self) -> Result<()> {{
+        self.op_string::<W{width}>(None, {op8:#02x?}, {op:#02x?})
+    }}
+"#,
+            doc = op.doc,
+            name = op.name,
+            op8 = op.op8,
+            op = op.op
+        )
+        .unwrap();
+
+        for prefix in &op.prefixes {
+            let (byte, doc) = match prefix.as_str() {
+                "rep" => (0xf3, "`rcx` times"),
+                "repe" => (0xf3, "while `rcx` isn't zero and the elements are equal"),
+                "repne" => (0xf2, "while `rcx` isn't zero and the elements aren't equal"),
+                _ => panic!("unknown prefix `{prefix}`"),
+            };
+
+            writeln!(f, r#"    /// Repeats [`{name}{suffix}`](Self::{name}{suffix}) {doc}, decrementing `rcx` every time.
+    pub fn {prefix}_{name}{suffix}(&mut self) -> Result<()> {{
+        self.op_string::<W{width}>(Some({byte:#02x?}), {op8:#02x?}, {op:#02x?})
+    }}
+"#, name=op.name, op8=op.op8, op=op.op).unwrap();
+        }
+    }
+}
+
 #[allow(unused_macros)]
 macro_rules! skip_name {
     ($name:literal, $op:ident) => {
@@ -249,17 +947,18 @@ fn write_ops(f: &mut File) {
     writeln!(
         f,
         r#"use crate::params::{{
-    mem::Memory,
-    GeneralRegister, Immediate, WWidth, WidthAtLeast16, WidthAtLeast32, WidthAtMost32, W16, W64,
-    W8,
+    imm::Imm8,
+    mem::{{Memory, VectorMemory}},
+    GeneralRegister, Hi8, Immediate, Masking, Opmask, Register, Rounding, VectorRegister, VectorWidth,
+    WWidth, WidthAtLeast16, WidthAtLeast32, WidthAtMost32, Xmm, Ymm, Zmm, W128, W16, W256, W32,
+    W512, W64, W8,
 }};
-use crate::Assembler;
-use std::io;
+use crate::{{Assembler, CodeSink, Label, Result}};
 "#
     )
     .unwrap();
 
-    writeln!(f, "impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {{").unwrap();
+    writeln!(f, "impl<'a, T: CodeSink> Assembler<'a, T> {{").unwrap();
 
     let ops: Ops = serde_json::from_str(OPS).unwrap();
 
@@ -268,16 +967,61 @@ use std::io;
     }
 
     for op in ops.rm_imm {
+        if op.min == 8 {
+            write_op_hi8_imm(f, &op);
+        }
+
+        if op.lock {
+            write_op_lock(
+                f,
+                &op.name,
+                "_mem_imm",
+                "<Width: WWidth, M: Memory<Width>>",
+                "mem: M, imm: impl Immediate<Width>",
+                "mem, imm",
+            );
+        }
+
         write_op_reg_imm(f, op.clone());
         write_op_mem_imm(f, op);
     }
 
+    let by_one_ops: HashSet<_> = ops.rm_1.iter().map(|op| op.name.clone()).collect();
+
     for op in ops.rm_imm8 {
-        write_op_reg_imm8(f, op.clone());
-        write_op_mem_imm8(f, op);
+        let has_one = by_one_ops.contains(&op.name);
+
+        if op.min == 8 {
+            write_op_hi8_imm8(f, &op, has_one);
+        }
+
+        if op.lock {
+            write_op_lock(
+                f,
+                &op.name,
+                "_mem_imm8",
+                "<Width: WWidth, M: Memory<Width>>",
+                "mem: M, imm: u8",
+                "mem, imm",
+            );
+        }
+
+        write_op_reg_imm8(f, op.clone(), has_one);
+        write_op_mem_imm8(f, op, has_one);
     }
 
     for op in ops.rm_sximm8 {
+        if op.lock {
+            write_op_lock(
+                f,
+                &op.name,
+                "_mem_sximm8",
+                "<Width: WidthAtLeast16, M: Memory<Width>>",
+                "mem: M, imm: i8",
+                "mem, imm",
+            );
+        }
+
         write_op_reg_sximm8(f, op.clone());
         write_op_mem_sximm8(f, op);
     }
@@ -285,16 +1029,48 @@ use std::io;
     let mut reg_reg_ops = HashSet::new();
 
     for op in ops.reg_rm {
+        if op.min == 8 {
+            write_op_hi8_mem(f, &op);
+            write_op_hi8_reg(f, &op);
+        }
+
         write_op_reg_mem(f, op.clone());
         reg_reg_ops.insert(op.name.clone());
         write_op_reg_reg(f, op);
     }
 
     for op in ops.rm_reg {
+        if op.min == 8 {
+            write_op_mem_hi8(f, &op, false);
+        }
+
+        if op.lock {
+            if op.min == 8 {
+                write_op_mem_hi8(f, &op, true);
+            }
+
+            let generics = format!(
+                "<Width: {}, R: GeneralRegister<Width>, M: Memory<Width>>",
+                width_bound(&op)
+            );
+            write_op_lock(
+                f,
+                &op.name,
+                "_mem_reg",
+                &generics,
+                "mem: M, reg: R",
+                "mem, reg",
+            );
+        }
+
         write_op_mem_reg(f, op.clone());
 
         // todo: do this anyway, but with a suffix.
         if reg_reg_ops.insert(op.name.clone()) {
+            if op.min == 8 {
+                write_op_hi8_reg(f, &op);
+            }
+
             write_op_reg_reg(f, op);
         }
     }
@@ -308,9 +1084,117 @@ use std::io;
         write_op_reg_reg_reg(f, op);
     }
 
+    for op in &ops.reg_reg_rm {
+        write_op_reg_reg_rm(f, op);
+    }
+
+    for op in &ops.vex_rm {
+        write_op_vex_rm(f, op);
+    }
+
     for op in ops.rm {
-        write_op_reg(f, op.clone()).unwrap();
-        write_op_mem(f, op).unwrap();
+        if op.min == 8 {
+            write_op_hi8(f, &op, "");
+        }
+
+        if op.lock {
+            let (suffix, generics) = if op.min == op.max {
+                (
+                    format!("_mem{}", op.min),
+                    format!("<M: Memory<W{}>>", op.min),
+                )
+            } else {
+                (
+                    "_mem".to_owned(),
+                    format!("<Width: {}, M: Memory<Width>>", width_bound(&op)),
+                )
+            };
+
+            write_op_lock(f, &op.name, &suffix, &generics, "mem: M", "mem");
+        }
+
+        write_op_reg(f, op.clone(), "").unwrap();
+        write_op_mem(f, op, "").unwrap();
+    }
+
+    for (ops, suffix) in [(ops.rm_1, "_1"), (ops.rm_cl, "_cl")] {
+        for op in ops {
+            if op.min == 8 {
+                write_op_hi8(f, &op, suffix);
+            }
+
+            write_op_reg(f, op.clone(), suffix).unwrap();
+            write_op_mem(f, op, suffix).unwrap();
+        }
+    }
+
+    for op in &ops.extend {
+        write_op_extend(f, op);
+    }
+
+    for op in &ops.string {
+        write_op_string(f, op);
+    }
+
+    for op in ops.jcc {
+        write_op_jcc_label(f, op);
+    }
+
+    for op in &ops.sse_xmm_rm {
+        write_op_xmm_xmm(f, op);
+        write_op_xmm_mem(f, op);
+    }
+
+    for op in &ops.sse_mem_xmm {
+        write_op_mem_xmm(f, op);
+    }
+
+    for op in &ops.sse_xmm_gpr {
+        write_op_xmm_gpr(f, op);
+    }
+
+    for op in &ops.sse_gpr_xmm {
+        write_op_gpr_xmm(f, op);
+    }
+
+    for op in &ops.sse_xmm_gpr_imm8 {
+        write_op_xmm_gpr_imm8(f, op);
+    }
+
+    for op in &ops.sse_gpr_xmm_imm8 {
+        write_op_gpr_xmm_imm8(f, op);
+    }
+
+    for op in &ops.avx_rvm {
+        write_op_vec_vec_vec(f, op);
+    }
+
+    for op in &ops.avx_rvm_mask {
+        write_op_vec_vec_vec_mask(f, op);
+    }
+
+    for op in &ops.avx_rm {
+        write_op_vec_rm(f, op);
+    }
+
+    for op in &ops.avx_mr {
+        write_op_mem_vec(f, op);
+    }
+
+    for op in &ops.avx512_rvm {
+        write_op_avx512_rvm(f, op, "zmm");
+    }
+
+    for op in &ops.avx512_k_rvm {
+        write_op_avx512_rvm(f, op, "k");
+    }
+
+    for op in &ops.avx512_rm {
+        write_op_avx512_rm(f, op);
+    }
+
+    for op in &ops.avx512_mr {
+        write_op_avx512_mr(f, op);
     }
 
     writeln!(f, "}}").unwrap();
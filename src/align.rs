@@ -0,0 +1,59 @@
+/// What alignment padding is made of.
+#[derive(Copy, Clone)]
+pub enum Fill {
+    /// The fewest NOP instructions that cover the padding, so that it's cheap to execute through.
+    Nop,
+    Byte(u8),
+}
+
+/// Intel's recommended NOP sequences, `NOPS[n - 1]` is `n` bytes long.
+const NOPS: [&[u8]; 9] = [
+    &[0x90],
+    &[0x66, 0x90],
+    &[0x0f, 0x1f, 0x00],
+    &[0x0f, 0x1f, 0x40, 0x00],
+    &[0x0f, 0x1f, 0x44, 0x00, 0x00],
+    &[0x66, 0x0f, 0x1f, 0x44, 0x00, 0x00],
+    &[0x0f, 0x1f, 0x80, 0x00, 0x00, 0x00, 0x00],
+    &[0x0f, 0x1f, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[0x66, 0x0f, 0x1f, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00],
+];
+
+/// How many bytes of padding are needed to bring `offset` up to a multiple of `align`, which has to be a power of two.
+pub fn padding_len(offset: u64, align: u64) -> u64 {
+    debug_assert!(align.is_power_of_two());
+    offset.wrapping_neg() & (align - 1)
+}
+
+/// Appends `len` bytes of padding to `out`.
+pub fn write_padding(len: u64, fill: Fill, out: &mut Vec<u8>) {
+    let mut len = len as usize;
+
+    match fill {
+        Fill::Byte(byte) => out.resize(out.len() + len, byte),
+        Fill::Nop => {
+            while len > 0 {
+                let nop = NOPS[len.min(NOPS.len()) - 1];
+                out.extend_from_slice(nop);
+                len -= nop.len();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Fill, write_padding};
+
+    #[test]
+    fn long_padding_uses_the_longest_nops() {
+        let mut out = Vec::new();
+        write_padding(12, Fill::Nop, &mut out);
+
+        assert_eq!(
+            &out[..9],
+            &[0x66, 0x0f, 0x1f, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(&out[9..], &[0x0f, 0x1f, 0x00]);
+    }
+}
@@ -0,0 +1,804 @@
+//! Writing assembled code out as ELF64 x86-64 files.
+
+use crate::{Assembled, Error, Label, RelocationKind, RelocationTarget, Result};
+
+use std::collections::HashMap;
+use std::io::Write;
+
+const EHDR_SIZE: u64 = 64;
+const PHDR_SIZE: u64 = 56;
+const SHDR_SIZE: u64 = 64;
+const SYM_SIZE: u64 = 24;
+const RELA_SIZE: u64 = 24;
+
+const ET_REL: u16 = 1;
+const ET_EXEC: u16 = 2;
+const EM_X86_64: u16 = 62;
+
+const PT_LOAD: u32 = 1;
+const PT_GNU_STACK: u32 = 0x6474_e551;
+
+const PF_X: u32 = 0x1;
+const PF_W: u32 = 0x2;
+const PF_R: u32 = 0x4;
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_RELA: u32 = 4;
+
+const SHF_ALLOC: u64 = 0x2;
+const SHF_EXECINSTR: u64 = 0x4;
+const SHF_INFO_LINK: u64 = 0x40;
+
+const STB_LOCAL: u8 = 0;
+const STB_GLOBAL: u8 = 1;
+const STT_NOTYPE: u8 = 0;
+const STT_OBJECT: u8 = 1;
+const STT_FUNC: u8 = 2;
+const STT_SECTION: u8 = 3;
+
+const R_X86_64_64: u32 = 1;
+const R_X86_64_PC32: u32 = 2;
+const R_X86_64_PLT32: u32 = 4;
+const R_X86_64_PC8: u32 = 15;
+const R_X86_64_PC64: u32 = 24;
+
+// section indices, in the order they're written.
+// `.shstrtab` is always last, after `.rela.text` and `.note.GNU-stack`.
+const TEXT: u16 = 1;
+const RODATA: u16 = 2;
+const SYMTAB: u32 = 3;
+const STRTAB: u32 = 4;
+const SECTION_COUNT: u16 = 8;
+
+/// Where executables get loaded, this is the traditional base for non-PIE x86-64 executables.
+const BASE_ADDRESS: u64 = 0x40_0000;
+const PAGE_SIZE: u64 = 0x1000;
+
+/// Little endian encoding helpers, everything in an ELF file is written through these.
+trait Put {
+    fn put_u8(&mut self, v: u8);
+    fn put_u16(&mut self, v: u16);
+    fn put_u32(&mut self, v: u32);
+    fn put_u64(&mut self, v: u64);
+    fn pad_to(&mut self, align: u64);
+}
+
+impl Put for Vec<u8> {
+    fn put_u8(&mut self, v: u8) {
+        self.push(v);
+    }
+
+    fn put_u16(&mut self, v: u16) {
+        self.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn put_u32(&mut self, v: u32) {
+        self.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn put_u64(&mut self, v: u64) {
+        self.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn pad_to(&mut self, align: u64) {
+        let len = (self.len() as u64).next_multiple_of(align);
+        self.resize(len as usize, 0);
+    }
+}
+
+/// A string table, names get appended as they're added.
+struct StringTable(Vec<u8>);
+
+impl StringTable {
+    fn new() -> Self {
+        // index 0 is always the empty string.
+        Self(vec![0])
+    }
+
+    fn add(&mut self, name: &str) -> u32 {
+        let idx = self.0.len() as u32;
+        self.0.extend_from_slice(name.as_bytes());
+        self.0.push(0);
+        idx
+    }
+}
+
+/// Writes the `e_ident` and everything else that's the same between object files and executables.
+fn write_header(out: &mut Vec<u8>, e_type: u16, entry: u64, phnum: u16, shoff: u64, shnum: u16) {
+    out.extend_from_slice(&[0x7f, b'E', b'L', b'F']);
+    // 64 bit, little endian, version 1, System V ABI.
+    out.extend_from_slice(&[2, 1, 1, 0]);
+    out.resize(16, 0);
+
+    out.put_u16(e_type);
+    out.put_u16(EM_X86_64);
+    out.put_u32(1);
+    out.put_u64(entry);
+    out.put_u64(if phnum == 0 { 0 } else { EHDR_SIZE });
+    out.put_u64(shoff);
+    out.put_u32(0);
+    out.put_u16(EHDR_SIZE as u16);
+    out.put_u16(if phnum == 0 { 0 } else { PHDR_SIZE as u16 });
+    out.put_u16(phnum);
+    out.put_u16(if shnum == 0 { 0 } else { SHDR_SIZE as u16 });
+    out.put_u16(shnum);
+    out.put_u16(if shnum == 0 { 0 } else { shnum - 1 });
+}
+
+struct SectionHeader {
+    name: u32,
+    kind: u32,
+    flags: u64,
+    offset: u64,
+    size: u64,
+    link: u32,
+    info: u32,
+    align: u64,
+    entsize: u64,
+}
+
+impl SectionHeader {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.put_u32(self.name);
+        out.put_u32(self.kind);
+        out.put_u64(self.flags);
+        // sections in relocatable files don't have an address.
+        out.put_u64(0);
+        out.put_u64(self.offset);
+        out.put_u64(self.size);
+        out.put_u32(self.link);
+        out.put_u32(self.info);
+        out.put_u64(self.align);
+        out.put_u64(self.entsize);
+    }
+}
+
+struct Sym {
+    name: u32,
+    info: u8,
+    shndx: u16,
+    value: u64,
+    size: u64,
+}
+
+impl Sym {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.put_u32(self.name);
+        out.put_u8(self.info);
+        out.put_u8(0);
+        out.put_u16(self.shndx);
+        out.put_u64(self.value);
+        out.put_u64(self.size);
+    }
+}
+
+struct ProgramHeader {
+    kind: u32,
+    flags: u32,
+    offset: u64,
+    address: u64,
+    size: u64,
+}
+
+impl ProgramHeader {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.put_u32(self.kind);
+        out.put_u32(self.flags);
+        out.put_u64(self.offset);
+        out.put_u64(self.address);
+        out.put_u64(self.address);
+        out.put_u64(self.size);
+        out.put_u64(self.size);
+        out.put_u64(PAGE_SIZE);
+    }
+}
+
+/// A named piece of data in a data section.
+struct DataSymbol {
+    name: String,
+    offset: u64,
+    size: u64,
+}
+
+/// Appends `bytes` to a data section, returning the symbol for it.
+fn push_data(section: &mut Vec<u8>, name: &str, bytes: &[u8], align: u64) -> DataSymbol {
+    assert!(align.is_power_of_two(), "{align} isn't a power of two");
+
+    section.pad_to(align);
+    let offset = section.len() as u64;
+    section.extend_from_slice(bytes);
+
+    DataSymbol {
+        name: name.to_owned(),
+        offset,
+        size: bytes.len() as u64,
+    }
+}
+
+/// An ELF64 relocatable object file (`.o`) that can be linked with the system linker.
+///
+/// Named labels (see [`make_named_label`](crate::Assembler::make_named_label)) become global symbols in `.text`,
+/// and external symbols that aren't defined here are left undefined for the linker.
+///
+/// An external symbol with the same name as a named label or a [`rodata`](Self::rodata) entry refers to that definition,
+/// this is how code gets at read only data.
+///
+/// Branches to external symbols are emitted as `R_X86_64_PLT32`, so they can go through a PLT for symbols from shared libraries,
+/// and RIP-relative memory operands are emitted as `R_X86_64_PC32`, which have to point at the data itself.
+pub struct Object<'a> {
+    code: &'a [u8],
+    assembled: &'a Assembled,
+    rodata: Vec<u8>,
+    rodata_align: u64,
+    rodata_symbols: Vec<DataSymbol>,
+}
+
+impl<'a> Object<'a> {
+    /// # Panics
+    /// If `code` isn't exactly as long as the assembled code.
+    #[must_use]
+    pub fn new(code: &'a [u8], assembled: &'a Assembled) -> Self {
+        assert_eq!(code.len() as u64, assembled.code_len());
+
+        Self {
+            code,
+            assembled,
+            rodata: Vec::new(),
+            rodata_align: 1,
+            rodata_symbols: Vec::new(),
+        }
+    }
+
+    /// Adds `bytes` to `.rodata` as the global symbol `name`, aligned to `align` bytes.
+    ///
+    /// # Panics
+    /// If `align` isn't a power of two.
+    pub fn rodata(&mut self, name: &str, bytes: &[u8], align: u64) {
+        let symbol = push_data(&mut self.rodata, name, bytes, align);
+        self.rodata_align = self.rodata_align.max(align);
+        self.rodata_symbols.push(symbol);
+    }
+
+    /// Writes the object file out to `writer`.
+    ///
+    /// # Errors
+    /// If a name is defined more than once, a named label was never attached, or writing fails.
+    pub fn write(&self, writer: &mut impl Write) -> Result<()> {
+        let mut strtab = StringTable::new();
+        let mut symbols = vec![
+            Sym {
+                name: 0,
+                info: 0,
+                shndx: 0,
+                value: 0,
+                size: 0,
+            },
+            Sym {
+                name: 0,
+                info: (STB_LOCAL << 4) | STT_SECTION,
+                shndx: TEXT,
+                value: 0,
+                size: 0,
+            },
+            Sym {
+                name: 0,
+                info: (STB_LOCAL << 4) | STT_SECTION,
+                shndx: RODATA,
+                value: 0,
+                size: 0,
+            },
+        ];
+
+        let text_symbol = 1;
+        let first_global = symbols.len() as u32;
+
+        let mut defined = HashMap::new();
+        let mut define = |symbols: &mut Vec<Sym>, name: &str, sym: Sym| {
+            if defined
+                .insert(name.to_owned(), symbols.len() as u64)
+                .is_some()
+            {
+                return Err(Error::DuplicateSymbol(name.to_owned()));
+            }
+
+            symbols.push(sym);
+            Ok(())
+        };
+
+        for (label, name) in self.assembled.named_labels() {
+            let value = self
+                .assembled
+                .label_offset(label)
+                .ok_or(Error::UnresolvedLabel(label))?;
+
+            let sym = Sym {
+                name: strtab.add(name),
+                info: (STB_GLOBAL << 4) | STT_FUNC,
+                shndx: TEXT,
+                value,
+                size: 0,
+            };
+
+            define(&mut symbols, name, sym)?;
+        }
+
+        for rodata in &self.rodata_symbols {
+            let sym = Sym {
+                name: strtab.add(&rodata.name),
+                info: (STB_GLOBAL << 4) | STT_OBJECT,
+                shndx: RODATA,
+                value: rodata.offset,
+                size: rodata.size,
+            };
+
+            define(&mut symbols, &rodata.name, sym)?;
+        }
+
+        let mut external = Vec::new();
+        for (symbol, name) in self.assembled.symbols() {
+            let idx = match defined.get(name) {
+                Some(idx) => *idx,
+                None => {
+                    let idx = symbols.len() as u64;
+                    symbols.push(Sym {
+                        name: strtab.add(name),
+                        info: (STB_GLOBAL << 4) | STT_NOTYPE,
+                        shndx: 0,
+                        value: 0,
+                        size: 0,
+                    });
+                    idx
+                }
+            };
+
+            debug_assert_eq!(external.len(), symbol.0);
+            external.push(idx);
+        }
+
+        let mut rela = Vec::new();
+        for relocation in self.assembled.relocations() {
+            let (symbol, addend) = match relocation.target {
+                // relative uses of labels are already correct wherever `.text` ends up.
+                RelocationTarget::Label(_) if relocation.kind.is_relative() => continue,
+                RelocationTarget::Label(label) => {
+                    let offset = self
+                        .assembled
+                        .label_offset(label)
+                        .ok_or(Error::UnresolvedLabel(label))?;
+                    (text_symbol, offset as i64 + relocation.addend)
+                }
+
+                RelocationTarget::Symbol(symbol) => (external[symbol.0], relocation.addend),
+            };
+
+            let kind = match relocation.kind {
+                RelocationKind::Abs64 => R_X86_64_64,
+                RelocationKind::Rel64 => R_X86_64_PC64,
+                RelocationKind::Rel32 => R_X86_64_PC32,
+                RelocationKind::Branch32 => R_X86_64_PLT32,
+                RelocationKind::Rel8 => R_X86_64_PC8,
+            };
+
+            rela.put_u64(relocation.offset);
+            rela.put_u64((symbol << 32) | u64::from(kind));
+            rela.put_u64(addend as u64);
+        }
+
+        let mut shstrtab = StringTable::new();
+        let names = [
+            shstrtab.add(".text"),
+            shstrtab.add(".rodata"),
+            shstrtab.add(".symtab"),
+            shstrtab.add(".strtab"),
+            shstrtab.add(".rela.text"),
+            shstrtab.add(".note.GNU-stack"),
+            shstrtab.add(".shstrtab"),
+        ];
+
+        let mut out = Vec::new();
+        write_header(&mut out, ET_REL, 0, 0, 0, SECTION_COUNT);
+
+        let mut sections = Vec::new();
+        let section = |out: &mut Vec<u8>, data: &[u8], align: u64| {
+            out.pad_to(align);
+            let offset = out.len() as u64;
+            out.extend_from_slice(data);
+            (offset, data.len() as u64)
+        };
+
+        let text = section(&mut out, self.code, 16);
+        let rodata = section(&mut out, &self.rodata, self.rodata_align);
+
+        let mut symtab_data = Vec::new();
+        for sym in &symbols {
+            sym.write(&mut symtab_data);
+        }
+
+        let symtab = section(&mut out, &symtab_data, 8);
+        let strtab = section(&mut out, &strtab.0, 1);
+        let rela_text = section(&mut out, &rela, 8);
+        let shstrtab = section(&mut out, &shstrtab.0, 1);
+
+        let header = |name, kind, flags, (offset, size): (u64, u64), align| SectionHeader {
+            name,
+            kind,
+            flags,
+            offset,
+            size,
+            link: 0,
+            info: 0,
+            align,
+            entsize: 0,
+        };
+
+        sections.push(header(0, 0, 0, (0, 0), 0));
+        sections.push(header(
+            names[0],
+            SHT_PROGBITS,
+            SHF_ALLOC | SHF_EXECINSTR,
+            text,
+            16,
+        ));
+        sections.push(header(
+            names[1],
+            SHT_PROGBITS,
+            SHF_ALLOC,
+            rodata,
+            self.rodata_align,
+        ));
+        sections.push(SectionHeader {
+            link: STRTAB,
+            info: first_global,
+            entsize: SYM_SIZE,
+            ..header(names[2], SHT_SYMTAB, 0, symtab, 8)
+        });
+        sections.push(header(names[3], SHT_STRTAB, 0, strtab, 1));
+        sections.push(SectionHeader {
+            link: SYMTAB,
+            info: u32::from(TEXT),
+            entsize: RELA_SIZE,
+            ..header(names[4], SHT_RELA, SHF_INFO_LINK, rela_text, 8)
+        });
+        // marks the stack as non-executable, without it linkers assume that it has to be.
+        sections.push(header(names[5], SHT_PROGBITS, 0, (0, 0), 1));
+        sections.push(header(names[6], SHT_STRTAB, 0, shstrtab, 1));
+
+        debug_assert_eq!(sections.len(), usize::from(SECTION_COUNT));
+
+        out.pad_to(8);
+        let shoff = out.len() as u64;
+        for section in &sections {
+            section.write(&mut out);
+        }
+
+        // now that the section headers have a place, the file header can point at them.
+        out[0x28..0x30].copy_from_slice(&shoff.to_le_bytes());
+
+        Ok(writer.write_all(&out)?)
+    }
+}
+
+/// A minimal static ELF64 executable, made of the code and an optional writable data segment.
+///
+/// There's no dynamic linking or libc, so external symbols can only refer to named labels or [`data`](Self::data) entries,
+/// and the entry point is jumped to directly with the stack as the kernel set it up.
+pub struct Executable<'a> {
+    code: &'a [u8],
+    assembled: &'a Assembled,
+    entry: Label,
+    data: Vec<u8>,
+    data_symbols: Vec<DataSymbol>,
+}
+
+impl<'a> Executable<'a> {
+    /// # Panics
+    /// If `code` isn't exactly as long as the assembled code.
+    #[must_use]
+    pub fn new(code: &'a [u8], assembled: &'a Assembled, entry: Label) -> Self {
+        assert_eq!(code.len() as u64, assembled.code_len());
+
+        Self {
+            code,
+            assembled,
+            entry,
+            data: Vec::new(),
+            data_symbols: Vec::new(),
+        }
+    }
+
+    /// Adds `bytes` to the data segment, external symbols named `name` refer to it.
+    ///
+    /// # Panics
+    /// If `align` isn't a power of two, or is bigger than a page.
+    pub fn data(&mut self, name: &str, bytes: &[u8], align: u64) {
+        assert!(align <= PAGE_SIZE, "{align} is bigger than a page");
+        let symbol = push_data(&mut self.data, name, bytes, align);
+        self.data_symbols.push(symbol);
+    }
+
+    /// Writes the executable out to `writer`, it still has to be made executable afterwards.
+    ///
+    /// # Errors
+    /// If a name is defined more than once, the entry label or a named label was never attached,
+    /// an external symbol isn't defined, or writing fails.
+    pub fn write(&self, writer: &mut impl Write) -> Result<()> {
+        let has_data = !self.data.is_empty();
+        let phnum: u16 = if has_data { 3 } else { 2 };
+
+        let text_offset = (EHDR_SIZE + u64::from(phnum) * PHDR_SIZE).next_multiple_of(16);
+        let text_address = BASE_ADDRESS + text_offset;
+        let data_offset = (text_offset + self.code.len() as u64).next_multiple_of(PAGE_SIZE);
+        let data_address = BASE_ADDRESS + data_offset;
+
+        let mut symbols = HashMap::new();
+        for (label, name) in self.assembled.named_labels() {
+            let offset = self
+                .assembled
+                .label_offset(label)
+                .ok_or(Error::UnresolvedLabel(label))?;
+
+            if symbols
+                .insert(name.to_owned(), text_address + offset)
+                .is_some()
+            {
+                return Err(Error::DuplicateSymbol(name.to_owned()));
+            }
+        }
+
+        for symbol in &self.data_symbols {
+            if symbols
+                .insert(symbol.name.clone(), data_address + symbol.offset)
+                .is_some()
+            {
+                return Err(Error::DuplicateSymbol(symbol.name.clone()));
+            }
+        }
+
+        let entry = self
+            .assembled
+            .label_offset(self.entry)
+            .ok_or(Error::UnresolvedLabel(self.entry))?;
+
+        let mut code = self.code.to_vec();
+        self.assembled.relocate(&mut code, text_address, &symbols)?;
+
+        let mut out = Vec::new();
+        write_header(&mut out, ET_EXEC, text_address + entry, phnum, 0, 0);
+
+        // the headers are loaded along with the code, which keeps everything in one segment.
+        ProgramHeader {
+            kind: PT_LOAD,
+            flags: PF_R | PF_X,
+            offset: 0,
+            address: BASE_ADDRESS,
+            size: text_offset + code.len() as u64,
+        }
+        .write(&mut out);
+
+        if has_data {
+            ProgramHeader {
+                kind: PT_LOAD,
+                flags: PF_R | PF_W,
+                offset: data_offset,
+                address: data_address,
+                size: self.data.len() as u64,
+            }
+            .write(&mut out);
+        }
+
+        // marks the stack as non-executable.
+        ProgramHeader {
+            kind: PT_GNU_STACK,
+            flags: PF_R | PF_W,
+            offset: 0,
+            address: 0,
+            size: 0,
+        }
+        .write(&mut out);
+
+        out.pad_to(16);
+        debug_assert_eq!(out.len() as u64, text_offset);
+        out.extend_from_slice(&code);
+
+        if has_data {
+            out.pad_to(PAGE_SIZE);
+            out.extend_from_slice(&self.data);
+        }
+
+        Ok(writer.write_all(&out)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Executable, Object};
+    use crate::{Assembler, CodeBuffer, Result};
+
+    fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes(bytes[offset..offset + 2].try_into().unwrap())
+    }
+
+    fn u64_at(bytes: &[u8], offset: usize) -> u64 {
+        u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+    }
+
+    #[test]
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    fn executable_exits_with_computed_status() -> Result<()> {
+        use crate::params::imm::Imm32;
+        use crate::params::mem::Mem;
+        use crate::params::reg::Reg32;
+        use std::os::unix::fs::PermissionsExt;
+
+        let mut buffer = CodeBuffer::new();
+        let mut assembler = Assembler::new(&mut buffer)?;
+
+        let value = assembler.external_symbol("value");
+        let entry = assembler.make_label_attached();
+        assembler.mov_reg_mem(Reg32::ZDI, Mem::symbol(value))?;
+        assembler.add_reg_imm(Reg32::ZDI, Imm32(2))?;
+        // exit
+        assembler.mov_reg_imm(Reg32::ZAX, Imm32(60))?;
+        assembler.syscall()?;
+
+        let assembled = assembler.finish()?;
+        let mut executable = Executable::new(&buffer, &assembled, entry);
+        executable.data("value", &40_u32.to_le_bytes(), 4);
+
+        let path = std::env::temp_dir().join(format!("rasen-exit-{}", std::process::id()));
+        {
+            let mut file = std::fs::File::create(&path)?;
+            executable.write(&mut file)?;
+            file.set_permissions(std::fs::Permissions::from_mode(0o755))?;
+        }
+
+        let status = std::process::Command::new(&path).status();
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(status?.code(), Some(42));
+
+        Ok(())
+    }
+
+    #[test]
+    fn writes_relocatable_object() -> Result<()> {
+        use crate::params::mem::Mem;
+        use crate::params::reg::Reg64;
+
+        let mut buffer = CodeBuffer::new();
+        let mut assembler = Assembler::new(&mut buffer)?;
+
+        let puts = assembler.external_symbol("puts");
+        let message = assembler.external_symbol("message");
+        let main = assembler.make_named_label("main");
+        assembler.attach_label(main)?;
+        assembler.call_symbol(puts)?;
+        assembler.lea_reg_mem(Reg64::ZDI, Mem::symbol(message))?;
+        assembler.ret()?;
+
+        let assembled = assembler.finish()?;
+        let mut object = Object::new(&buffer, &assembled);
+        object.rodata("message", b"hi\0", 1);
+
+        let mut out = Vec::new();
+        object.write(&mut out)?;
+
+        assert_eq!(&out[..4], b"\x7fELF");
+        // ET_REL, EM_X86_64
+        assert_eq!(u16_at(&out, 0x10), 1);
+        assert_eq!(u16_at(&out, 0x12), 62);
+
+        let shoff = u64_at(&out, 0x28) as usize;
+        assert_eq!(shoff % 8, 0);
+        assert_eq!(out.len(), shoff + 8 * 64);
+
+        // `.text` holds the code as is.
+        let text = shoff + 64;
+        let offset = u64_at(&out, text + 0x18) as usize;
+        assert_eq!(u64_at(&out, text + 0x20), 13);
+        assert_eq!(
+            &out[offset..offset + 13],
+            &[0xe8, 0, 0, 0, 0, 0x48, 0x8d, 0x3d, 0, 0, 0, 0, 0xc3]
+        );
+
+        // an R_X86_64_PLT32 against `puts`, which comes after the 2 section symbols, `main` and `message`,
+        // and an R_X86_64_PC32 against `message`.
+        let rela = shoff + 5 * 64;
+        let offset = u64_at(&out, rela + 0x18) as usize;
+        assert_eq!(u64_at(&out, rela + 0x20), 48);
+        assert_eq!(u64_at(&out, offset), 1);
+        assert_eq!(u64_at(&out, offset + 8), (5 << 32) | 4);
+        assert_eq!(u64_at(&out, offset + 16), (-4_i64) as u64);
+        assert_eq!(u64_at(&out, offset + 24), 8);
+        assert_eq!(u64_at(&out, offset + 32), (4 << 32) | 2);
+        assert_eq!(u64_at(&out, offset + 40), (-4_i64) as u64);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    fn object_links_and_runs() -> Result<()> {
+        use crate::params::mem::Mem;
+        use crate::params::reg::Reg64;
+        use std::io;
+        use std::process::Command;
+
+        const DRIVER: &str = r#"
+            #include <stdint.h>
+
+            int64_t value = 20;
+            int64_t twice(int64_t x) { return x * 2; }
+            int64_t rasen_entry(void);
+
+            int main(void) { return (int)rasen_entry(); }
+        "#;
+
+        let mut buffer = CodeBuffer::new();
+        let mut assembler = Assembler::new(&mut buffer)?;
+
+        let value = assembler.external_symbol("value");
+        let twice = assembler.external_symbol("twice");
+        let entry = assembler.make_named_label("rasen_entry");
+        let inner = assembler.make_label();
+        let table = assembler.make_label();
+        let two = assembler.constant_u64(2);
+
+        // calls `inner` through an absolute address, so the linker has to fill in an `R_X86_64_64`.
+        assembler.attach_label(entry)?;
+        assembler.sub_reg_sximm8(Reg64::ZSP, 8)?;
+        assembler.call_mem64(Mem::label(table))?;
+        assembler.add_reg_sximm8(Reg64::ZSP, 8)?;
+        assembler.ret()?;
+
+        // `twice(value) + 2`
+        assembler.attach_label(inner)?;
+        assembler.sub_reg_sximm8(Reg64::ZSP, 8)?;
+        assembler.mov_reg_mem(Reg64::ZDI, Mem::symbol(value))?;
+        assembler.call_symbol(twice)?;
+        assembler.add_reg_mem(Reg64::ZAX, Mem::label(two))?;
+        assembler.add_reg_sximm8(Reg64::ZSP, 8)?;
+        assembler.ret()?;
+
+        assembler.align(8)?;
+        assembler.attach_label(table)?;
+        assembler.write_label_address(inner)?;
+
+        let assembled = assembler.finish()?;
+        let object = Object::new(&buffer, &assembled);
+
+        let dir = std::env::temp_dir().join(format!("rasen-link-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        object.write(&mut std::fs::File::create(dir.join("entry.o"))?)?;
+        std::fs::write(dir.join("driver.c"), DRIVER)?;
+
+        let linked = Command::new("cc")
+            .arg("-no-pie")
+            .arg("-o")
+            .arg(dir.join("driver"))
+            .arg(dir.join("driver.c"))
+            .arg(dir.join("entry.o"))
+            .status();
+
+        let status = match linked {
+            Ok(linked) => {
+                assert!(linked.success(), "linking failed");
+                Command::new(dir.join("driver")).status().map(Some)
+            }
+
+            // there's no C compiler to link with.
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        };
+
+        std::fs::remove_dir_all(&dir)?;
+
+        if let Some(status) = status? {
+            assert_eq!(status.code(), Some(42));
+        }
+
+        Ok(())
+    }
+}
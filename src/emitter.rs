@@ -1,40 +1,51 @@
-use std::io::{self, Seek, SeekFrom, Write};
+use crate::CodeSink;
+
+use std::io;
 
 pub struct Emitter<'a, T>
 where
-    T: Write + Seek,
+    T: CodeSink,
 {
     writer: &'a mut T,
     start_offset: u64,
     current_offset: u64,
+    /// When this is `Some`, writes get held here instead of being sent to the writer.
+    buffer: Option<Vec<u8>>,
 }
 
 impl<'a, T> Emitter<'a, T>
 where
-    T: Write + Seek,
+    T: CodeSink,
 {
     pub fn new(writer: &'a mut T) -> io::Result<Self> {
-        let start_offset = writer.stream_position()?;
+        let start_offset = writer.position()?;
         Ok(Self {
             start_offset,
             current_offset: start_offset,
             writer,
+            buffer: None,
         })
     }
 
-    pub fn write_qword_seek(&mut self, seek_from: SeekFrom, value: u64) -> io::Result<()> {
-        let offset = self.current_offset;
-        self.seek(seek_from)?;
-        self.write_qword(value)?;
-        self.seek(SeekFrom::Start(offset))?;
+    /// Starts holding writes in memory, offsets keep counting as if they had been written.
+    pub fn begin_buffering(&mut self) {
+        debug_assert!(self.buffer.is_none());
+        self.buffer = Some(Vec::new());
+    }
 
-        Ok(())
+    /// Stops holding writes in memory, returning everything written since `begin_buffering`.
+    ///
+    /// The current offset gets reset to `start`, which should be where buffering started.
+    pub fn end_buffering(&mut self, start: u64) -> Vec<u8> {
+        self.current_offset = start;
+        self.buffer.take().unwrap_or_default()
     }
 
-    #[inline(always)]
-    fn seek(&mut self, seek_from: SeekFrom) -> io::Result<u64> {
-        self.current_offset = self.writer.seek(seek_from)?;
-        Ok(self.current_offset)
+    /// Overwrites already written bytes at `offset`, this bypasses buffering.
+    pub fn patch(&mut self, offset: u64, buf: &[u8]) -> io::Result<()> {
+        debug_assert!(self.buffer.is_none());
+        debug_assert!(offset + buf.len() as u64 <= self.current_offset);
+        self.writer.patch(offset, buf)
     }
 
     #[inline(always)]
@@ -48,7 +59,11 @@ where
     }
 
     pub fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
-        self.writer.write_all(buf)?;
+        match &mut self.buffer {
+            Some(buffer) => buffer.extend_from_slice(buf),
+            None => self.writer.append(buf)?,
+        }
+
         self.current_offset += buf.len() as u64;
         Ok(())
     }
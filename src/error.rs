@@ -0,0 +1,74 @@
+use crate::params::{Hi8, Masking, Register};
+use crate::{Label, RelocationTarget};
+
+use std::{fmt, io};
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Writing to the underlying writer failed.
+    Io(io::Error),
+    /// The label was attached more than once.
+    DuplicateLabel(Label),
+    /// The label was used but never attached.
+    UnresolvedLabel(Label),
+    /// The register can't be used as an index, this is only ever [`Register::Zsp`].
+    InvalidIndexRegister(Register),
+    /// No address was provided for an external symbol with this name.
+    UnresolvedSymbol(String),
+    /// More than one thing was defined with this name.
+    DuplicateSymbol(String),
+    /// A label or symbol was too far away from the use at `offset` to fit in its displacement.
+    DisplacementOutOfRange {
+        target: RelocationTarget,
+        offset: u64,
+    },
+    /// The high byte register was used with an operand that needs a REX prefix, which would turn it into SPL, BPL, SIL or DIL.
+    Hi8RexConflict(Hi8),
+    /// The masking uses [`Opmask::K0`](crate::params::Opmask::K0), which can't be encoded as a write mask.
+    InvalidWritemask(Masking),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "io error: {err}"),
+            Self::DuplicateLabel(label) => write!(f, "{label:?} was attached more than once"),
+            Self::UnresolvedLabel(label) => write!(f, "{label:?} was used but never attached"),
+            Self::UnresolvedSymbol(name) => write!(f, "no address was provided for `{name}`"),
+            Self::DuplicateSymbol(name) => write!(f, "`{name}` was defined more than once"),
+            Self::InvalidIndexRegister(reg) => write!(f, "{reg:?} can't be used as an index"),
+            Self::DisplacementOutOfRange { target, offset } => write!(
+                f,
+                "{target:?} is out of range of the displacement at {offset:#x}"
+            ),
+            Self::Hi8RexConflict(reg) => write!(
+                f,
+                "{reg:?} can't be used with an operand that needs a REX prefix"
+            ),
+            Self::InvalidWritemask(masking) => {
+                write!(
+                    f,
+                    "{masking:?} can't be used, k0 as a write mask means no masking"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
@@ -1,10 +1,15 @@
 use crate::params::mem::{Displacement, SIB};
 use crate::params::{
-    GeneralRegister, Immediate, W8, W16, W64, WWidth, WidthAtLeast16, WidthAtLeast32,
-    mem::{Memory, ModRM},
+    Condition, GeneralRegister, Hi8, Immediate, Masking, Opmask, Register, Rounding, StackWidth,
+    VectorWidth, W8, W32, W64, W128, WWidth, WidthAtLeast16, WidthAtLeast32, Xmm,
+    imm::{Imm8, Imm32},
+    mem::{Mem, Memory, ModRM, VectorMemory},
+};
+use crate::relaxer::BranchKind;
+use crate::{
+    Assembler, CodeSink, Error, Evex, Label, REXB, REXR, REXW, REXX, RelocationKind,
+    RelocationTarget, Result, Symbol, Vex, WritableImmediate,
 };
-use crate::{Assembler, REXB, REXR, REXW, REXX, Vex, WritableImmediate};
-use std::io;
 
 mod generated;
 
@@ -12,7 +17,7 @@ mod generated;
 //  above is not doable without const_generics, because `WidthAtLeast16` != W8,
 //  and `Width::IS_W8` doesn't prove (to the compiler anyway) that `Width == W8`
 
-impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
+impl<'a, T: CodeSink> Assembler<'a, T> {
     fn op_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
@@ -21,12 +26,12 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         op: u8,
         rm_bits: u8,
         prefix: Option<u8>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         let reg = reg.into();
-        let initial_rex = if reg.needs_rex() { REXB } else { 0b0000_0000 };
+        let mut initial_rex = if reg.needs_rex() { REXB } else { 0b0000_0000 };
 
-        if let Some(prefix) = prefix {
-            self.write_byte(prefix)?;
+        if Width::IS_W8 {
+            initial_rex |= Self::byte_rex(reg);
         }
 
         self.op_rm::<Width>(
@@ -35,6 +40,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
             // this is unused, since Width >= 16, but we have to put _something_ there.
             op8,
             op,
+            prefix,
             initial_rex,
         )
     }
@@ -47,13 +53,9 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         op: u8,
         rm_bits: u8,
         prefix: Option<u8>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         let mem = mem.into();
 
-        if let Some(prefix) = prefix {
-            self.write_byte(prefix)?;
-        }
-
         if let Some(prefix) = mem.address_prefix() {
             self.write_byte(prefix)?;
         }
@@ -66,6 +68,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
             // this is unused, since Width >= 16, but we have to put _something_ there.
             op8,
             op,
+            prefix,
             mem.rex_byte(),
         )
     }
@@ -77,12 +80,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         op: u8,
         rm_bits: Option<u8>,
         mm: Option<u8>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         let reg = reg.into();
-        let initial_rex = if reg.needs_rex() { REXB } else { 0b0000_0000 };
+        let mut initial_rex = if reg.needs_rex() { REXB } else { 0b0000_0000 };
 
-        if let Some(mm) = mm {
-            self.write_byte(mm)?;
+        // SPL, BPL, SIL, DIL are the registers that this matters for.
+        if Width::IS_W8 && reg.value() >= 4 {
+            // SPL, BPL, SIL, DIL
+            initial_rex |= 0b0100_0000;
         }
 
         self.op_rm::<Width>(
@@ -92,9 +97,9 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
                 None,
             ),
             None,
-            // this is unused, since Width >= 16, but we have to put _something_ there.
             op8,
             op,
+            mm,
             initial_rex,
         )
     }
@@ -106,25 +111,21 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         op: u8,
         rm_bits: Option<u8>,
         mm: Option<u8>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         let mem = mem.into();
 
         if let Some(prefix) = mem.address_prefix() {
             self.write_byte(prefix)?;
         }
 
-        if let Some(mm) = mm {
-            self.write_byte(mm)?;
-        }
-
         let (mod_rm, sib, displacement) = mem.encoded();
 
         self.op_rm::<Width>(
             (mod_rm.with_op(rm_bits.unwrap_or(0)), sib, displacement),
             None,
-            // this is unused, since Width >= 16, but we have to put _something_ there.
             op8,
             op,
+            mm,
             mem.rex_byte(),
         )
     }
@@ -137,7 +138,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         mm: u8,
         op: u8,
         pp: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         // RD gets VEX.R
         // RS1 gets VEX.B
         // VEX.X doesn't exist
@@ -167,7 +168,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         mm: u8,
         op: u8,
         pp: u8,
-    ) -> io::Result<()>
+    ) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -180,8 +181,8 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         let rs = r2.into();
 
         let r = !rd.needs_rex();
-        let x = (mem.rex_byte() & REXX) == 0;
-        let b = (mem.rex_byte() & REXB) == 0;
+        let x = (mem.rex_byte() & REXX) != REXX;
+        let b = (mem.rex_byte() & REXB) != REXB;
 
         let vex = Vex::new((!(rs as u8)) & 0xf, pp, mm, r, x, b, Width::IS_W64);
 
@@ -197,20 +198,63 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         }
 
         if let Some(displacement) = displacement {
-            self.write_displacement(displacement)?;
+            self.write_displacement(displacement, 0)?;
         }
 
         Ok(())
     }
 
+    /// Writes a VEX encoded general purpose instruction with `rm` in ModRM.rm.
+    ///
+    /// If `digit` is set it goes in ModRM.reg as an opcode extension and `reg` goes in `vvvv` instead.
+    #[allow(clippy::too_many_arguments)]
+    fn op_vex_reg_rm<Width: WidthAtLeast32>(
+        &mut self,
+        pp: u8,
+        mm: u8,
+        op: u8,
+        digit: Option<u8>,
+        reg: Register,
+        rm: Register,
+        imm: Option<u8>,
+    ) -> Result<()> {
+        let (reg, vvvv) = match digit {
+            Some(digit) => (digit, reg.value()),
+            None => (reg.value(), 0),
+        };
+
+        self.op_avx_rr::<W128>(pp, mm, op, Width::IS_W64, reg, vvvv, rm.value(), imm)
+    }
+
+    /// Like [`op_vex_reg_rm`](Self::op_vex_reg_rm), but with a memory operand.
+    #[allow(clippy::too_many_arguments)]
+    fn op_vex_reg_mem<Width: WidthAtLeast32>(
+        &mut self,
+        pp: u8,
+        mm: u8,
+        op: u8,
+        digit: Option<u8>,
+        reg: Register,
+        mem: Mem,
+        imm: Option<u8>,
+    ) -> Result<()> {
+        let (reg, vvvv) = match digit {
+            Some(digit) => (digit, reg.value()),
+            None => (reg.value(), 0),
+        };
+
+        self.op_avx_rm::<W128>(pp, mm, op, Width::IS_W64, reg, vvvv, mem, imm)
+    }
+
     fn op_rm<Width: WWidth>(
         &mut self,
         mod_bytes: (ModRM, Option<SIB>, Option<Displacement>),
         imm: Option<WritableImmediate>,
         op8: u8,
         op: u8,
+        mm: Option<u8>,
         initial_rex: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         if Width::IS_W16 {
             self.write_byte(0x66)?;
         }
@@ -225,6 +269,11 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
             self.write_byte(rex_byte)?;
         }
 
+        // the opcode map escape is part of the opcode, so it has to come _after_ REX.
+        if let Some(mm) = mm {
+            self.write_byte(mm)?;
+        }
+
         let opcode: u8 = if Width::IS_W8 { op8 } else { op };
 
         self.write_byte(opcode)?;
@@ -236,7 +285,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         }
 
         if let Some(displacement) = mod_bytes.2 {
-            self.write_displacement(displacement)?;
+            self.write_displacement(displacement, imm.map_or(0, WritableImmediate::len))?;
         }
 
         if let Some(imm) = imm {
@@ -253,7 +302,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         op8: u8,
         op: u8,
         rm_bits: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         let mem = mem.into();
 
         if let Some(prefix) = mem.address_prefix() {
@@ -267,6 +316,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
             Some(imm.as_writable()),
             op8,
             op,
+            None,
             mem.rex_byte(),
         )
     }
@@ -278,7 +328,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         op8: u8,
         op: u8,
         rm_bits: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         let reg = reg.into();
 
         let mut initial_rex = if reg.needs_rex() { 0b0100_0001 } else { 0 };
@@ -294,6 +344,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
             Some(imm.as_writable()),
             op8,
             op,
+            None,
             initial_rex,
         )
     }
@@ -303,7 +354,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         imm: impl Immediate<Width>,
         op8: u8,
         op: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         if Width::IS_W16 {
             self.write_byte(0x66)?;
         }
@@ -326,7 +377,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         op8: u8,
         op: u8,
         prefix: Option<u8>,
-    ) -> io::Result<()>
+    ) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -379,7 +430,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         }
 
         if let Some(displacement) = displacement {
-            self.write_displacement(displacement)?;
+            self.write_displacement(displacement, 0)?;
         }
 
         Ok(())
@@ -393,7 +444,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         op8: u8,
         op: u8,
         prefix: Option<u8>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         if Width::IS_W16 {
             self.write_byte(0x66)?;
         }
@@ -440,271 +491,906 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         Ok(())
     }
 
-    fn op_no_operands(&mut self, opcode: u8, prefix: Option<u8>) -> io::Result<()> {
-        if let Some(prefix) = prefix {
-            self.write_byte(prefix)?;
-        }
-
-        self.write_byte(opcode)
-    }
-
-    pub fn mov_reg_imm64<R: GeneralRegister<W64>>(&mut self, reg: R, imm: u64) -> io::Result<()> {
-        let reg = reg.into();
-
-        if reg.needs_rex() {
-            self.write_byte(REXW | REXR)?;
-        } else {
-            self.write_byte(REXW)?;
-        }
-
-        let opcode: u8 = 0xb8;
-
-        self.write_byte(opcode)?;
-
-        self.write_immediate(WritableImmediate::W64(imm))?;
-
-        Ok(())
+    fn op_jcc_label(&mut self, op: u8, label: Label) -> Result<()> {
+        self.write_branch(BranchKind::Jcc(op & 0xf), label)
     }
 
-    pub fn movzx_reg_mem8<Width: WidthAtLeast16, R: GeneralRegister<Width>, M: Memory<W8>>(
+    /// Writes an instruction that encodes `reg` in the low 3 bits of the opcode, like `push` (50+r).
+    ///
+    /// `rex` is any REX bits the instruction needs on top of REX.B.
+    fn op_plus_reg<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
-        mem: M,
-    ) -> io::Result<()> {
-        let op = 0xb6;
-        let prefix = Some(0x0f);
+        op: u8,
+        rex: u8,
+    ) -> Result<()> {
+        let reg = reg.into();
 
         if Width::IS_W16 {
             self.write_byte(0x66)?;
         }
 
-        let reg = reg.into();
-        let mem = mem.into();
+        let rex = if reg.needs_rex() { rex | REXB } else { rex };
 
-        if let Some(prefix) = mem.address_prefix() {
-            self.write_byte(prefix)?;
+        if rex != 0 {
+            self.write_byte(rex)?;
         }
 
-        let mut rex = mem.rex_byte();
+        self.write_byte(op | reg.writable())
+    }
 
-        if reg.needs_rex() {
-            rex |= REXR;
+    /// Writes a legacy SSE instruction, `prefix` is the mandatory prefix, which has to come before REX.
+    ///
+    /// `map` is the opcode map, numbered like VEX.mmmmm (1 is `0F`, 2 is `0F 38` and 3 is `0F 3A`).
+    /// `Width` only decides REX.W.
+    fn op_sse<Width: WidthAtLeast32>(
+        &mut self,
+        prefix: Option<u8>,
+        map: u8,
+        op: u8,
+        mod_bytes: (ModRM, Option<SIB>, Option<Displacement>),
+        rex: u8,
+        imm: Option<u8>,
+    ) -> Result<()> {
+        if let Some(prefix) = prefix {
+            self.write_byte(prefix)?;
         }
 
-        if Width::IS_W64 {
-            rex |= REXW;
-        }
+        let rex = if Width::IS_W64 { rex | REXW } else { rex };
 
         if rex != 0 {
             self.write_byte(rex)?;
         }
 
-        if let Some(prefix) = prefix {
-            self.write_byte(prefix)?;
-        }
-
-        let opcode: u8 = op;
-
-        self.write_byte(opcode)?;
+        self.write_byte(0x0f)?;
 
-        let (mod_rm, sib, displacement) = mem.encoded();
+        match map {
+            1 => {}
+            2 => self.write_byte(0x38)?,
+            3 => self.write_byte(0x3a)?,
+            _ => unreachable!("invalid opcode map {map}"),
+        }
 
-        self.write_mod_rm(mod_rm.with_reg(reg.writable()))?;
+        self.write_byte(op)?;
+        self.write_mod_rm(mod_bytes.0)?;
 
-        if let Some(sib) = sib {
+        if let Some(sib) = mod_bytes.1 {
             self.write_sib(sib)?;
         }
 
-        if let Some(displacement) = displacement {
-            self.write_displacement(displacement)?;
+        if let Some(displacement) = mod_bytes.2 {
+            self.write_displacement(displacement, imm.map_or(0, |_| 1))?;
+        }
+
+        if let Some(imm) = imm {
+            self.write_byte(imm)?;
         }
 
         Ok(())
     }
 
-    pub fn movzx_reg_mem16<Width: WidthAtLeast32, R: GeneralRegister<Width>, M: Memory<W16>>(
+    /// Writes a legacy SSE instruction with a register in ModRM.rm, `reg` and `rm` are register numbers (0-15).
+    ///
+    /// See [`op_sse`](Self::op_sse) for the rest.
+    pub(crate) fn op_sse_rr<Width: WidthAtLeast32>(
         &mut self,
-        reg: R,
-        mem: M,
-    ) -> io::Result<()> {
-        let op = 0xb7;
-        let prefix = Some(0x0f);
+        prefix: Option<u8>,
+        map: u8,
+        op: u8,
+        reg: u8,
+        rm: u8,
+        imm: Option<u8>,
+    ) -> Result<()> {
+        let mut rex = 0;
 
-        let reg = reg.into();
-        let mem = mem.into();
+        if reg >= 8 {
+            rex |= REXR;
+        }
+
+        if rm >= 8 {
+            rex |= REXB;
+        }
 
+        let mod_rm = ModRM::new(0b11, reg & 0b111, rm & 0b111);
+        self.op_sse::<Width>(prefix, map, op, (mod_rm, None, None), rex, imm)
+    }
+
+    /// Like [`op_sse_rr`](Self::op_sse_rr), but with memory in ModRM.rm.
+    pub(crate) fn op_sse_rm<Width: WidthAtLeast32>(
+        &mut self,
+        prefix: Option<u8>,
+        map: u8,
+        op: u8,
+        reg: u8,
+        mem: Mem,
+        imm: Option<u8>,
+    ) -> Result<()> {
         if let Some(prefix) = mem.address_prefix() {
             self.write_byte(prefix)?;
         }
 
         let mut rex = mem.rex_byte();
 
-        if reg.needs_rex() {
+        if reg >= 8 {
             rex |= REXR;
         }
 
-        if Width::IS_W64 {
-            rex |= REXW;
-        }
+        let (mod_rm, sib, displacement) = mem.encoded();
+        let mod_bytes = (mod_rm.with_reg(reg & 0b111), sib, displacement);
+        self.op_sse::<Width>(prefix, map, op, mod_bytes, rex, imm)
+    }
 
-        if rex != 0 {
-            self.write_byte(rex)?;
+    /// Writes a VEX encoded vector instruction with a register in ModRM.rm.
+    ///
+    /// `reg`, `vvvv` and `rm` are register numbers (0-15), instructions without a second source take `vvvv` as 0.
+    /// `pp` and `mm` are like in VEX, `Width` decides VEX.L.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn op_avx_rr<Width: VectorWidth>(
+        &mut self,
+        pp: u8,
+        mm: u8,
+        op: u8,
+        w: bool,
+        reg: u8,
+        vvvv: u8,
+        rm: u8,
+        imm: Option<u8>,
+    ) -> Result<()> {
+        let vex = Vex::new(!vvvv & 0xf, pp, mm, reg < 8, true, rm < 8, w).with_l(Width::LL == 1);
+        let mod_rm = ModRM::new(0b11, reg & 0b111, rm & 0b111);
+
+        self.write_vex(vex)?;
+        self.write_byte(op)?;
+        self.write_mod_rm(mod_rm)?;
+
+        if let Some(imm) = imm {
+            self.write_byte(imm)?;
         }
 
-        if let Some(prefix) = prefix {
+        Ok(())
+    }
+
+    /// Like [`op_avx_rr`](Self::op_avx_rr), but with memory in ModRM.rm.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn op_avx_rm<Width: VectorWidth>(
+        &mut self,
+        pp: u8,
+        mm: u8,
+        op: u8,
+        w: bool,
+        reg: u8,
+        vvvv: u8,
+        mem: Mem,
+        imm: Option<u8>,
+    ) -> Result<()> {
+        if let Some(prefix) = mem.address_prefix() {
             self.write_byte(prefix)?;
         }
 
-        let opcode: u8 = op;
-
-        self.write_byte(opcode)?;
+        let x = (mem.rex_byte() & REXX) != REXX;
+        let b = (mem.rex_byte() & REXB) != REXB;
+        let vex = Vex::new(!vvvv & 0xf, pp, mm, reg < 8, x, b, w).with_l(Width::LL == 1);
 
         let (mod_rm, sib, displacement) = mem.encoded();
 
-        self.write_mod_rm(mod_rm.with_reg(reg.writable()))?;
+        self.write_vex(vex)?;
+        self.write_byte(op)?;
+        self.write_mod_rm(mod_rm.with_reg(reg & 0b111))?;
 
         if let Some(sib) = sib {
             self.write_sib(sib)?;
         }
 
         if let Some(displacement) = displacement {
-            self.write_displacement(displacement)?;
+            self.write_displacement(displacement, imm.map_or(0, |_| 1))?;
+        }
+
+        if let Some(imm) = imm {
+            self.write_byte(imm)?;
         }
 
         Ok(())
     }
 
-    pub fn movsx_reg_mem8<Width: WidthAtLeast16, R: GeneralRegister<Width>, M: Memory<W8>>(
+    /// Writes an EVEX encoded vector instruction with a register in ModRM.rm.
+    ///
+    /// `reg`, `vvvv` and `rm` are register numbers (0-31), instructions without a second source take `vvvv` as 0.
+    /// With `rounding` the vector length is implied to be 512 bits, otherwise `Width` decides EVEX.L'L.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn op_evex_rr<Width: VectorWidth>(
         &mut self,
-        reg: R,
-        mem: M,
-    ) -> io::Result<()> {
-        let op = 0xbe;
-        let prefix = Some(0x0f);
-
-        if Width::IS_W16 {
-            self.write_byte(0x66)?;
-        }
-
-        let reg = reg.into();
-        let mem = mem.into();
-
-        if let Some(prefix) = mem.address_prefix() {
-            self.write_byte(prefix)?;
-        }
-
-        let mut rex = mem.rex_byte();
+        pp: u8,
+        mm: u8,
+        op: u8,
+        w: bool,
+        reg: u8,
+        vvvv: u8,
+        rm: u8,
+        masking: Masking,
+        rounding: Option<Rounding>,
+        imm: Option<u8>,
+    ) -> Result<()> {
+        if !masking.is_valid() {
+            return Err(Error::InvalidWritemask(masking));
+        }
+
+        let evex = Evex::new(reg, vvvv, rm & 0b1_0000 != 0, rm & 0b1000 != 0, pp, mm, w)
+            .with_masking(masking);
+
+        let evex = match rounding {
+            Some(rounding) => evex.with_b().with_ll(rounding as u8),
+            None => evex.with_ll(Width::LL),
+        };
+
+        self.write_evex(evex)?;
+        self.write_byte(op)?;
+        self.write_mod_rm(ModRM::new(0b11, reg & 0b111, rm & 0b111))?;
 
-        if reg.needs_rex() {
-            rex |= REXR;
+        if let Some(imm) = imm {
+            self.write_byte(imm)?;
         }
 
-        if Width::IS_W64 {
-            rex |= REXW;
-        }
+        Ok(())
+    }
 
-        if rex != 0 {
-            self.write_byte(rex)?;
+    /// Like [`op_evex_rr`](Self::op_evex_rr), but with memory in ModRM.rm.
+    ///
+    /// `n` is the size of the memory operand in bytes, a disp8 gets scaled by it.
+    /// With `broadcast` the memory operand is a single element that gets copied to every element.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn op_evex_rm<Width: VectorWidth>(
+        &mut self,
+        pp: u8,
+        mm: u8,
+        op: u8,
+        w: bool,
+        reg: u8,
+        vvvv: u8,
+        mem: Mem,
+        masking: Masking,
+        n: i32,
+        broadcast: bool,
+        imm: Option<u8>,
+    ) -> Result<()> {
+        if !masking.is_valid() {
+            return Err(Error::InvalidWritemask(masking));
         }
 
-        if let Some(prefix) = prefix {
+        if let Some(prefix) = mem.address_prefix() {
             self.write_byte(prefix)?;
         }
 
-        let opcode: u8 = op;
+        let x = (mem.rex_byte() & REXX) == REXX;
+        let b = (mem.rex_byte() & REXB) == REXB;
+        let evex = Evex::new(reg, vvvv, x, b, pp, mm, w)
+            .with_masking(masking)
+            .with_ll(Width::LL);
 
-        self.write_byte(opcode)?;
+        let evex = if broadcast { evex.with_b() } else { evex };
 
-        let (mod_rm, sib, displacement) = mem.encoded();
+        let (mod_rm, sib, displacement) = mem.encoded_compressed(n);
 
-        self.write_mod_rm(mod_rm.with_reg(reg.writable()))?;
+        self.write_evex(evex)?;
+        self.write_byte(op)?;
+        self.write_mod_rm(mod_rm.with_reg(reg & 0b111))?;
 
         if let Some(sib) = sib {
             self.write_sib(sib)?;
         }
 
         if let Some(displacement) = displacement {
-            self.write_displacement(displacement)?;
+            self.write_displacement(displacement, imm.map_or(0, |_| 1))?;
+        }
+
+        if let Some(imm) = imm {
+            self.write_byte(imm)?;
         }
 
         Ok(())
     }
 
-    pub fn movsx_reg_mem16<Width: WidthAtLeast32, R: GeneralRegister<Width>, M: Memory<W16>>(
+    pub fn kmovw_k_k(&mut self, dst: Opmask, src: Opmask) -> Result<()> {
+        self.op_avx_rr::<W128>(0, 1, 0x90, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn kmovw_k_reg<R: GeneralRegister<W32>>(&mut self, dst: Opmask, src: R) -> Result<()> {
+        let src: Register = src.into();
+        self.op_avx_rr::<W128>(0, 1, 0x92, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn kmovw_reg_k<R: GeneralRegister<W32>>(&mut self, dst: R, src: Opmask) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_avx_rr::<W128>(0, 1, 0x93, false, dst.value(), 0, src.value(), None)
+    }
+
+    /// Sets ZF if `src1 | src2` is all zeroes and CF if it's all ones.
+    pub fn kortestw_k_k(&mut self, src1: Opmask, src2: Opmask) -> Result<()> {
+        self.op_avx_rr::<W128>(0, 1, 0x98, false, src1.value(), 0, src2.value(), None)
+    }
+
+    /// Zeroes the upper halves of every [`Ymm`](crate::params::Ymm) register,
+    /// this avoids the penalty for mixing AVX and legacy SSE code.
+    pub fn vzeroupper(&mut self) -> Result<()> {
+        self.write_vex(Vex::new(0b1111, 0, 1, true, true, true, false))?;
+        self.write_byte(0x77)
+    }
+
+    /// `push`/`pop` with a memory operand, these default to 64 bits so they never need REX.W.
+    fn op_stack_mem<Width: StackWidth, M: Memory<Width>>(
         &mut self,
-        reg: R,
         mem: M,
-    ) -> io::Result<()> {
-        let op = 0xbf;
-        let prefix = Some(0x0f);
-
-        let reg = reg.into();
+        op: u8,
+        rm_bits: u8,
+    ) -> Result<()> {
         let mem = mem.into();
 
         if let Some(prefix) = mem.address_prefix() {
             self.write_byte(prefix)?;
         }
 
-        let mut rex = mem.rex_byte();
-
-        if reg.needs_rex() {
-            rex |= REXR;
+        if Width::IS_W16 {
+            self.write_byte(0x66)?;
         }
 
-        if Width::IS_W64 {
-            rex |= REXW;
-        }
+        let (mod_rm, sib, displacement) = mem.encoded();
 
-        if rex != 0 {
-            self.write_byte(rex)?;
-        }
+        self.op_rm::<W32>(
+            (mod_rm.with_op(rm_bits), sib, displacement),
+            None,
+            op,
+            op,
+            None,
+            mem.rex_byte(),
+        )
+    }
 
+    fn op_no_operands(&mut self, opcode: u8, prefix: Option<u8>) -> Result<()> {
         if let Some(prefix) = prefix {
             self.write_byte(prefix)?;
         }
 
-        let opcode: u8 = op;
-
-        self.write_byte(opcode)?;
+        self.write_byte(opcode)
+    }
 
-        let (mod_rm, sib, displacement) = mem.encoded();
+    /// Writes a string instruction, `rep` is either REP/REPE (`F3`) or REPNE (`F2`).
+    fn op_string<Width: WWidth>(&mut self, rep: Option<u8>, op8: u8, op: u8) -> Result<()> {
+        if let Some(rep) = rep {
+            self.write_byte(rep)?;
+        }
 
-        self.write_mod_rm(mod_rm.with_reg(reg.writable()))?;
+        self.op_sized::<Width>(op8, op)
+    }
 
-        if let Some(sib) = sib {
-            self.write_sib(sib)?;
+    /// Writes an instruction whose only operands are implicit, so `Width` just picks the operand size.
+    fn op_sized<Width: WWidth>(&mut self, op8: u8, op: u8) -> Result<()> {
+        if Width::IS_W16 {
+            self.write_byte(0x66)?;
         }
 
-        if let Some(displacement) = displacement {
-            self.write_displacement(displacement)?;
+        if Width::IS_W64 {
+            self.write_byte(REXW)?;
         }
 
-        Ok(())
+        self.write_byte(if Width::IS_W8 { op8 } else { op })
     }
 
-    // xor_hi8_imm(Hi8Bit, u8)
-    // xor_mem_hi8(Mem<W8>, Hi8Bit)
-    // xor_hi8_mem(Hi8Bit, Mem<W8>)
-}
+    /// Sign extends the low half of `zax` into the rest of it.
+    ///
+    /// This is `cbw`, `cwde` or `cdqe` for `W16`, `W32` and `W64` respectively.
+    pub fn sign_extend_zax<Width: WidthAtLeast16>(&mut self) -> Result<()> {
+        self.op_sized::<Width>(0x98, 0x98)
+    }
 
-#[cfg(test)]
-mod test {
-    use crate::Assembler;
-    use crate::params::reg::Reg32;
-    use std::io;
-    use std::io::Cursor;
+    /// Sign extends `zax` into `zdx`, making `zdx:zax` ready for [`idiv`](Self::idiv_reg).
+    ///
+    /// This is `cwd`, `cdq` or `cqo` for `W16`, `W32` and `W64` respectively.
+    pub fn sign_extend_zax_into_zdx<Width: WidthAtLeast16>(&mut self) -> Result<()> {
+        self.op_sized::<Width>(0x99, 0x99)
+    }
 
-    fn create_writer(size: usize) -> Cursor<Box<[u8]>> {
-        let inner = vec![0; size].into_boxed_slice();
-        Cursor::new(inner)
+    /// Writes a `LOCK` prefix.
+    ///
+    /// The `lock_*` functions only take memory destinations, since `LOCK` is `#UD` with a register destination.
+    /// `xchg` with a memory operand is always locked, so it doesn't get one.
+    fn lock(&mut self) -> Result<()> {
+        self.write_byte(0xf0)
     }
 
-    #[test]
-    fn vex_shlx_encodes() -> io::Result<()> {
-        let mut writer = create_writer(5);
-        let mut assembler = Assembler::new(&mut writer)?;
+    /// Compares `EDX:EAX` with `mem`, if they're equal `ECX:EBX` is stored into `mem`,
+    /// otherwise `mem` is loaded into `EDX:EAX`.
+    pub fn cmpxchg8b_mem<M: Memory<W64>>(&mut self, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0xc7, 1, mem.into(), None)
+    }
 
-        assembler.op_reg_reg_reg(Reg32::ZAX, Reg32::R15, Reg32::R8, 0b10, 0xf7, 0b01)?;
+    /// Compares `RDX:RAX` with `mem`, if they're equal `RCX:RBX` is stored into `mem`,
+    /// otherwise `mem` is loaded into `RDX:RAX`.
+    ///
+    /// `mem` has to be 16 byte aligned.
+    pub fn cmpxchg16b_mem<M: VectorMemory<W128>>(&mut self, mem: M) -> Result<()> {
+        self.op_sse_rm::<W64>(None, 1, 0xc7, 1, mem.into(), None)
+    }
+
+    pub fn lock_cmpxchg8b_mem<M: Memory<W64>>(&mut self, mem: M) -> Result<()> {
+        self.lock()?;
+        self.cmpxchg8b_mem(mem)
+    }
+
+    pub fn lock_cmpxchg16b_mem<M: VectorMemory<W128>>(&mut self, mem: M) -> Result<()> {
+        self.lock()?;
+        self.cmpxchg16b_mem(mem)
+    }
+
+    pub fn mov_reg_imm64<R: GeneralRegister<W64>>(&mut self, reg: R, imm: u64) -> Result<()> {
+        self.op_plus_reg(reg, 0xb8, REXW)?;
+        self.write_immediate(WritableImmediate::W64(imm))
+    }
+
+    /// Moves the low 32 bits of `src` into `dst`, zeroing the rest of `dst`.
+    pub fn movd_xmm_reg<R: GeneralRegister<W32>>(&mut self, dst: Xmm, src: R) -> Result<()> {
+        let src: Register = src.into();
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x6e, dst.value(), src.value(), None)
+    }
+
+    /// Moves `src` into the low 64 bits of `dst`, zeroing the rest of `dst`.
+    pub fn movq_xmm_reg<R: GeneralRegister<W64>>(&mut self, dst: Xmm, src: R) -> Result<()> {
+        let src: Register = src.into();
+        self.op_sse_rr::<W64>(Some(0x66), 1, 0x6e, dst.value(), src.value(), None)
+    }
+
+    /// Moves the low 32 bits of `src` into `dst`.
+    pub fn movd_reg_xmm<R: GeneralRegister<W32>>(&mut self, dst: R, src: Xmm) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x7e, src.value(), dst.value(), None)
+    }
+
+    /// Moves the low 64 bits of `src` into `dst`.
+    pub fn movq_reg_xmm<R: GeneralRegister<W64>>(&mut self, dst: R, src: Xmm) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<W64>(Some(0x66), 1, 0x7e, src.value(), dst.value(), None)
+    }
+
+    /// Pushes `reg`, using the short `push r` (50+r) form.
+    pub fn push_reg<Width: StackWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_plus_reg(reg, 0x50, 0)
+    }
+
+    /// Pops into `reg`, using the short `pop r` (58+r) form.
+    pub fn pop_reg<Width: StackWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_plus_reg(reg, 0x58, 0)
+    }
+
+    pub fn push_mem<Width: StackWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_stack_mem(mem, 0xff, 6)
+    }
+
+    pub fn pop_mem<Width: StackWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_stack_mem(mem, 0x8f, 0)
+    }
+
+    /// Pushes `imm` sign extended to 64 bits.
+    pub fn push_imm8(&mut self, imm: Imm8) -> Result<()> {
+        self.write_byte(0x6a)?;
+        self.write_byte(imm.0)
+    }
+
+    /// Pushes `imm` sign extended to 64 bits.
+    pub fn push_imm32(&mut self, imm: Imm32) -> Result<()> {
+        self.write_byte(0x68)?;
+        self.write_dword(imm.0)
+    }
+
+    /// Jumps to `label`, using `jmp rel8` whenever the label is in range and `jmp rel32` otherwise.
+    pub fn jmp_label(&mut self, label: Label) -> Result<()> {
+        self.write_branch(BranchKind::Jmp, label)
+    }
+
+    /// Calls the external `symbol` with `call rel32`, the displacement is left for whoever loads the code.
+    pub fn call_symbol(&mut self, symbol: Symbol) -> Result<()> {
+        self.write_byte(0xe8)?;
+        self.write_relocation(
+            RelocationKind::Branch32,
+            RelocationTarget::Symbol(symbol),
+            -4,
+        )
+    }
+
+    /// Jumps to the external `symbol` with `jmp rel32`, the displacement is left for whoever loads the code.
+    pub fn jmp_symbol(&mut self, symbol: Symbol) -> Result<()> {
+        self.write_byte(0xe9)?;
+        self.write_relocation(
+            RelocationKind::Branch32,
+            RelocationTarget::Symbol(symbol),
+            -4,
+        )
+    }
+
+    /// Jumps to `label` if `condition` holds, see [`jmp_label`](Self::jmp_label) for how the size gets picked.
+    pub fn jcc_label(&mut self, condition: Condition, label: Label) -> Result<()> {
+        self.write_branch(BranchKind::Jcc(condition.value()), label)
+    }
+
+    /// Sets `reg` to 1 if `condition` holds, and 0 otherwise.
+    pub fn setcc_reg<R: GeneralRegister<W8>>(
+        &mut self,
+        condition: Condition,
+        reg: R,
+    ) -> Result<()> {
+        let op = 0x90 | condition.value();
+        self.op_reg(reg, op, op, None, Some(0x0f))
+    }
+
+    /// Sets `mem` to 1 if `condition` holds, and 0 otherwise.
+    pub fn setcc_mem<M: Memory<W8>>(&mut self, condition: Condition, mem: M) -> Result<()> {
+        let op = 0x90 | condition.value();
+        self.op_mem(mem, op, op, None, Some(0x0f))
+    }
+
+    /// Sets the high byte register `reg` to 1 if `condition` holds, and 0 otherwise.
+    pub fn setcc_hi8(&mut self, condition: Condition, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x90 | condition.value(), None, Some(0x0f), None)
+    }
+
+    /// Moves `reg2` into `reg1` if `condition` holds.
+    pub fn cmovcc_reg_reg<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
+        &mut self,
+        condition: Condition,
+        reg1: R,
+        reg2: R,
+    ) -> Result<()> {
+        let op = 0x40 | condition.value();
+        self.op_reg_reg(reg1, reg2, op, op, Some(0x0f))
+    }
+
+    /// Moves `mem` into `reg` if `condition` holds.
+    pub fn cmovcc_reg_mem<Width: WidthAtLeast16, R, M>(
+        &mut self,
+        condition: Condition,
+        reg: R,
+        mem: M,
+    ) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        let op = 0x40 | condition.value();
+        self.op_rm_mr(reg, mem, op, op, Some(0x0f))
+    }
+
+    /// Writes `imul reg, r/m, imm`, `op` is `6B` for a sign extended imm8 and `69` for a full sized one.
+    fn op_imul_imm<Width: WidthAtLeast16>(
+        &mut self,
+        reg: Register,
+        mod_bytes: (ModRM, Option<SIB>, Option<Displacement>),
+        rex: u8,
+        op: u8,
+        imm: WritableImmediate,
+    ) -> Result<()> {
+        let rex = if reg.needs_rex() { rex | REXR } else { rex };
+        let (mod_rm, sib, displacement) = mod_bytes;
+
+        self.op_rm::<Width>(
+            (mod_rm.with_reg(reg.writable()), sib, displacement),
+            Some(imm),
+            op,
+            op,
+            None,
+            rex,
+        )
+    }
+
+    fn op_imul_reg_imm<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: R,
+        src: R,
+        op: u8,
+        imm: WritableImmediate,
+    ) -> Result<()> {
+        let src = src.into();
+        let rex = if src.needs_rex() { REXB } else { 0 };
+        let mod_bytes = (ModRM::new(0b11, 0, src.writable()), None, None);
+        self.op_imul_imm::<Width>(dst.into(), mod_bytes, rex, op, imm)
+    }
+
+    fn op_imul_mem_imm<Width: WidthAtLeast16, R, M>(
+        &mut self,
+        dst: R,
+        mem: M,
+        op: u8,
+        imm: WritableImmediate,
+    ) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        let mem = mem.into();
+
+        if let Some(prefix) = mem.address_prefix() {
+            self.write_byte(prefix)?;
+        }
+
+        self.op_imul_imm::<Width>(dst.into(), mem.encoded(), mem.rex_byte(), op, imm)
+    }
+
+    /// Signed multiply of `src` by `imm`, storing the truncated result in `dst`.
+    ///
+    /// Unlike the one operand form this doesn't touch `zdx`.
+    pub fn imul_reg_reg_imm<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: R,
+        src: R,
+        imm: impl Immediate<Width>,
+    ) -> Result<()> {
+        self.op_imul_reg_imm(dst, src, 0x69, imm.as_writable())
+    }
+
+    /// Signed multiply of `src` by the sign extended `imm`, storing the truncated result in `dst`.
+    pub fn imul_reg_reg_sximm8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: R,
+        src: R,
+        imm: i8,
+    ) -> Result<()> {
+        self.op_imul_reg_imm(dst, src, 0x6b, WritableImmediate::W8(imm as u8))
+    }
+
+    /// Signed multiply of `mem` by `imm`, storing the truncated result in `dst`.
+    pub fn imul_reg_mem_imm<Width: WidthAtLeast16, R, M>(
+        &mut self,
+        dst: R,
+        mem: M,
+        imm: impl Immediate<Width>,
+    ) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_imul_mem_imm(dst, mem, 0x69, imm.as_writable())
+    }
+
+    /// Signed multiply of `mem` by the sign extended `imm`, storing the truncated result in `dst`.
+    pub fn imul_reg_mem_sximm8<Width: WidthAtLeast16, R, M>(
+        &mut self,
+        dst: R,
+        mem: M,
+        imm: i8,
+    ) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_imul_mem_imm(dst, mem, 0x6b, WritableImmediate::W8(imm as u8))
+    }
+
+    /// Writes a `movzx` like instruction, where `reg` is `Width` sized and `src` is `Src` sized.
+    fn op_extend_reg<Width: WWidth, Src: WWidth, R, S>(
+        &mut self,
+        reg: R,
+        src: S,
+        op: u8,
+        mm: Option<u8>,
+    ) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        S: GeneralRegister<Src>,
+    {
+        let reg = reg.into();
+        let src = src.into();
+
+        let mut rex = 0;
+
+        if reg.needs_rex() {
+            rex |= REXR;
+        }
+
+        if src.needs_rex() {
+            rex |= REXB;
+        }
+
+        if Src::IS_W8 {
+            rex |= Self::byte_rex(src);
+        }
+
+        self.op_rm::<Width>(
+            (ModRM::new(0b11, reg.writable(), src.writable()), None, None),
+            None,
+            op,
+            op,
+            mm,
+            rex,
+        )
+    }
+
+    /// Like [`op_extend_reg`](Self::op_extend_reg), but with a `Src` sized memory operand.
+    fn op_extend_mem<Width: WWidth, Src: WWidth, R, M>(
+        &mut self,
+        reg: R,
+        mem: M,
+        op: u8,
+        mm: Option<u8>,
+    ) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Src>,
+    {
+        let reg = reg.into();
+        let mem = mem.into();
+
+        if let Some(prefix) = mem.address_prefix() {
+            self.write_byte(prefix)?;
+        }
+
+        let mut rex = mem.rex_byte();
+
+        if reg.needs_rex() {
+            rex |= REXR;
+        }
+
+        let (mod_rm, sib, displacement) = mem.encoded();
+
+        self.op_rm::<Width>(
+            (mod_rm.with_reg(reg.writable()), sib, displacement),
+            None,
+            op,
+            op,
+            mm,
+            rex,
+        )
+    }
+
+    /// Writes a `movzx` like instruction with a high byte register as the source.
+    ///
+    /// Any REX prefix would turn the source into SPL, BPL, SIL or DIL, so `reg` can't be 64 bits or need one.
+    fn op_extend_hi8<Width: WWidth, R: GeneralRegister<Width>>(
+        &mut self,
+        reg: R,
+        src: Hi8,
+        op: u8,
+        mm: Option<u8>,
+    ) -> Result<()> {
+        let reg = reg.into();
+
+        if reg.needs_rex() || Width::IS_W64 {
+            return Err(Error::Hi8RexConflict(src));
+        }
+
+        self.op_rm::<Width>(
+            (ModRM::new(0b11, reg.writable(), src.value()), None, None),
+            None,
+            op,
+            op,
+            mm,
+            0,
+        )
+    }
+
+    /// Writes an 8 bit instruction that has a high byte register as an operand.
+    ///
+    /// `rex` is the REX prefix the other operand needs, with one the high byte register would be SPL, BPL, SIL or DIL instead.
+    fn op_hi8(
+        &mut self,
+        hi8: Hi8,
+        mod_bytes: (ModRM, Option<SIB>, Option<Displacement>),
+        op8: u8,
+        mm: Option<u8>,
+        rex: u8,
+        imm: Option<WritableImmediate>,
+    ) -> Result<()> {
+        if rex != 0 {
+            return Err(Error::Hi8RexConflict(hi8));
+        }
+
+        self.op_rm::<W8>(mod_bytes, imm, op8, op8, mm, 0)
+    }
+
+    fn op_hi8_rm_bits(
+        &mut self,
+        reg: Hi8,
+        op8: u8,
+        rm_bits: Option<u8>,
+        mm: Option<u8>,
+        imm: Option<WritableImmediate>,
+    ) -> Result<()> {
+        let mod_rm = ModRM::new(0b11, rm_bits.unwrap_or(0), reg.value());
+        self.op_hi8(reg, (mod_rm, None, None), op8, mm, 0, imm)
+    }
+
+    fn op_hi8_imm(
+        &mut self,
+        reg: Hi8,
+        imm: impl Immediate<W8>,
+        op8: u8,
+        rm_bits: u8,
+    ) -> Result<()> {
+        self.op_hi8_rm_bits(reg, op8, Some(rm_bits), None, Some(imm.as_writable()))
+    }
+
+    /// `hi8` goes in ModRM.reg, like `reg1` in [`op_reg_reg`](Self::op_reg_reg).
+    fn op_hi8_reg(&mut self, hi8: Hi8, reg: Register, op8: u8, mm: Option<u8>) -> Result<()> {
+        let mod_rm = ModRM::new(0b11, hi8.value(), reg.writable());
+        self.op_hi8(
+            hi8,
+            (mod_rm, None, None),
+            op8,
+            mm,
+            Self::byte_rex(reg),
+            None,
+        )
+    }
+
+    /// `hi8` goes in ModRM.rm, like `reg2` in [`op_reg_reg`](Self::op_reg_reg).
+    fn op_reg_hi8(&mut self, reg: Register, hi8: Hi8, op8: u8, mm: Option<u8>) -> Result<()> {
+        let mod_rm = ModRM::new(0b11, reg.writable(), hi8.value());
+        self.op_hi8(
+            hi8,
+            (mod_rm, None, None),
+            op8,
+            mm,
+            Self::byte_rex(reg),
+            None,
+        )
+    }
+
+    fn op_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8, op8: u8, mm: Option<u8>) -> Result<()> {
+        let mod_rm = ModRM::new(0b11, reg1.value(), reg2.value());
+        self.op_hi8(reg1, (mod_rm, None, None), op8, mm, 0, None)
+    }
+
+    /// `lock` writes a `LOCK` prefix, like [`lock`](Self::lock).
+    fn op_hi8_mem(
+        &mut self,
+        reg: Hi8,
+        mem: Mem,
+        op8: u8,
+        mm: Option<u8>,
+        lock: bool,
+    ) -> Result<()> {
+        // this has to be checked before any prefixes get written.
+        if mem.rex_byte() != 0 {
+            return Err(Error::Hi8RexConflict(reg));
+        }
+
+        if lock {
+            self.lock()?;
+        }
+
+        if let Some(prefix) = mem.address_prefix() {
+            self.write_byte(prefix)?;
+        }
+
+        let (mod_rm, sib, displacement) = mem.encoded();
+        let mod_bytes = (mod_rm.with_reg(reg.value()), sib, displacement);
+        self.op_hi8(reg, mod_bytes, op8, mm, 0, None)
+    }
+
+    /// The REX prefix `reg` needs as an 8 bit register.
+    fn byte_rex(reg: Register) -> u8 {
+        // SPL, BPL, SIL, DIL need an empty REX, R8B-R15B need REX.B (or REX.R).
+        if reg.value() >= 4 { 0b0100_0000 } else { 0 }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::params::imm::{Imm8, Imm16, Imm32, Imm64};
+    use crate::params::mem::{Mem8, Mem16, Mem32, Mem64, Mem128, Mem256, Mem512};
+    use crate::params::reg::{Reg8, Reg16, Reg32, Reg64};
+    use crate::params::{
+        Condition, Hi8, Masking, Mem, Opmask, Register, Rounding, W16, W32, W64, Xmm, Ymm, Zmm,
+    };
+    use crate::{Assembler, Error, Result};
+    use std::io::Cursor;
+
+    fn create_writer(size: usize) -> Cursor<Box<[u8]>> {
+        let inner = vec![0; size].into_boxed_slice();
+        Cursor::new(inner)
+    }
+
+    #[test]
+    fn vex_shlx_encodes() -> Result<()> {
+        let mut writer = create_writer(5);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.op_reg_reg_reg(Reg32::ZAX, Reg32::R15, Reg32::R8, 0b10, 0xf7, 0b01)?;
 
         assert_eq!(assembler.start_offset(), 0);
         assert_eq!(assembler.current_offset(), 5);
@@ -715,4 +1401,692 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn setcc_encodes_rex() -> Result<()> {
+        let mut writer = create_writer(12);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.setcc_reg(Condition::Z, Reg8::R8)?;
+        assembler.setcc_reg(Condition::Equal, Reg8::ZSI)?;
+        assembler.setcc_mem(Condition::E, Mem::base(Register::R9))?;
+
+        assembler.finish()?;
+
+        assert_eq!(
+            &*writer.into_inner(),
+            &[
+                0x41, 0x0f, 0x94, 0xc0, 0x40, 0x0f, 0x94, 0xc6, 0x41, 0x0f, 0x94, 0x01
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn cmovcc_encodes() -> Result<()> {
+        let mut writer = create_writer(9);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.cmovcc_reg_reg(Condition::Less, Reg64::ZAX, Reg64::R9)?;
+        assembler.cmovcc_reg_mem(
+            Condition::Less.negate(),
+            Reg32::ZAX,
+            Mem::base(Register::R12),
+        )?;
+
+        assembler.finish()?;
+
+        assert_eq!(
+            &*writer.into_inner(),
+            &[0x49, 0x0f, 0x4c, 0xc1, 0x41, 0x0f, 0x4d, 0x04, 0x24]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn bt_reg_imm8_puts_rex_before_escape() -> Result<()> {
+        let mut writer = create_writer(5);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.bt_reg_imm8(Reg64::R10, 3)?;
+
+        assembler.finish()?;
+
+        assert_eq!(&*writer.into_inner(), &[0x49, 0x0f, 0xba, 0xe2, 0x03]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mem_label_is_relative_to_instruction_end() -> Result<()> {
+        let mut writer = create_writer(24);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        let behind = assembler.make_label_attached();
+        let ahead = assembler.make_label();
+
+        assembler.mov_reg_mem(Reg64::ZAX, Mem::label(behind))?;
+        assembler.mov_mem_imm(Mem32(Mem::label(ahead)), Imm32(0x1234_5678))?;
+        assembler.nop()?;
+        assembler.attach_label(ahead)?;
+        assembler.mov_reg_mem(Reg32::ZCX, Mem::base_displacement(Register::Zax, 200))?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        // mov rax, [rip - 7]
+        assert_eq!(&code[..7], &[0x48, 0x8b, 0x05, 0xf9, 0xff, 0xff, 0xff]);
+        // mov dword [rip + 1], 0x12345678
+        assert_eq!(
+            &code[7..17],
+            &[0xc7, 0x05, 0x01, 0x00, 0x00, 0x00, 0x78, 0x56, 0x34, 0x12]
+        );
+        // nop
+        assert_eq!(&code[17..18], &[0x90]);
+        // mov ecx, [rax + 200]
+        assert_eq!(&code[18..], &[0x8b, 0x88, 0xc8, 0x00, 0x00, 0x00]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn push_pop_encode() -> Result<()> {
+        let mut writer = create_writer(33);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.push_reg(Reg64::ZBX)?;
+        assembler.push_reg(Reg64::R12)?;
+        assembler.pop_reg(Reg64::R15)?;
+        assembler.push_reg(Reg16::ZAX)?;
+        assembler.push_mem(Mem64(Mem::base_displacement(Register::Zax, 8)))?;
+        assembler.pop_mem(Mem64(Mem::base(Register::R9)))?;
+        assembler.push_mem(Mem16(Mem::base(Register::Zax)))?;
+        assembler.push_imm8(Imm8(0x7f))?;
+        assembler.push_imm32(Imm32(0x1234_5678))?;
+        assembler.mov_reg_imm64(Reg64::R9, 0x1122_3344_5566_7788)?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..5], &[0x53, 0x41, 0x54, 0x41, 0x5f]);
+        assert_eq!(&code[5..7], &[0x66, 0x50]);
+        assert_eq!(&code[7..10], &[0xff, 0x70, 0x08]);
+        assert_eq!(&code[10..13], &[0x41, 0x8f, 0x01]);
+        assert_eq!(&code[13..16], &[0x66, 0xff, 0x30]);
+        assert_eq!(&code[16..18], &[0x6a, 0x7f]);
+        assert_eq!(&code[18..23], &[0x68, 0x78, 0x56, 0x34, 0x12]);
+        assert_eq!(&code[23..25], &[0x49, 0xb9]);
+        assert_eq!(&code[25..], &0x1122_3344_5566_7788_u64.to_le_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn sse_scalar_encodes() -> Result<()> {
+        let mut writer = create_writer(0x2c);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.addsd_xmm_xmm(Xmm::Xmm1, Xmm::Xmm9)?;
+        assembler.movsd_xmm_mem(Xmm::Xmm8, Mem64(Mem::base_displacement(Register::Zax, 8)))?;
+        assembler.movss_mem_xmm(Mem32(Mem::base(Register::R9)), Xmm::Xmm2)?;
+        assembler.ucomisd_xmm_xmm(Xmm::Xmm0, Xmm::Xmm1)?;
+        assembler.cvtsi2sd_xmm_reg(Xmm::Xmm3, Reg64::ZAX)?;
+        assembler.cvttsd2si_reg_xmm(Reg32::R10, Xmm::Xmm15)?;
+        assembler.movq_xmm_reg(Xmm::Xmm0, Reg64::ZDI)?;
+        assembler.movd_reg_xmm(Reg32::ZAX, Xmm::Xmm12)?;
+        assembler.cvtsi2ss_xmm_mem(Xmm::Xmm1, Mem32(Mem::base(Register::Zbx)))?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..5], &[0xf2, 0x41, 0x0f, 0x58, 0xc9]);
+        assert_eq!(&code[5..0xb], &[0xf2, 0x44, 0x0f, 0x10, 0x40, 0x08]);
+        assert_eq!(&code[0xb..0x10], &[0xf3, 0x41, 0x0f, 0x11, 0x11]);
+        assert_eq!(&code[0x10..0x14], &[0x66, 0x0f, 0x2e, 0xc1]);
+        assert_eq!(&code[0x14..0x19], &[0xf2, 0x48, 0x0f, 0x2a, 0xd8]);
+        assert_eq!(&code[0x19..0x1e], &[0xf2, 0x45, 0x0f, 0x2c, 0xd7]);
+        assert_eq!(&code[0x1e..0x23], &[0x66, 0x48, 0x0f, 0x6e, 0xc7]);
+        assert_eq!(&code[0x23..0x28], &[0x66, 0x44, 0x0f, 0x7e, 0xe0]);
+        assert_eq!(&code[0x28..], &[0xf3, 0x0f, 0x2a, 0x0b]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sse_packed_encodes() -> Result<()> {
+        let mut writer = create_writer(0x2f);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.paddd_xmm_xmm(Xmm::Xmm1, Xmm::Xmm9)?;
+        assembler.pshufb_xmm_mem(Xmm::Xmm10, Mem128(Mem::base(Register::Zax)))?;
+        assembler.shufps_xmm_xmm_imm8(Xmm::Xmm2, Xmm::Xmm3, 0x1b)?;
+        assembler.pinsrq_xmm_reg_imm8(Xmm::Xmm1, Reg64::R8, 1)?;
+        assembler.pextrd_mem_xmm_imm8(
+            Mem32(Mem::base_displacement(Register::Zsp, 8)),
+            Xmm::Xmm12,
+            2,
+        )?;
+        assembler.pmovmskb_reg_xmm(Reg32::ZAX, Xmm::Xmm3)?;
+        assembler.movdqu_mem_xmm(Mem128(Mem::base(Register::Zdi)), Xmm::Xmm11)?;
+        assembler.pcmpistri_xmm_mem_imm8(
+            Xmm::Xmm0,
+            Mem128(Mem::base_displacement(Register::Zsi, 0x10)),
+            0x0c,
+        )?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..5], &[0x66, 0x41, 0x0f, 0xfe, 0xc9]);
+        assert_eq!(&code[5..0xb], &[0x66, 0x44, 0x0f, 0x38, 0x00, 0x10]);
+        assert_eq!(&code[0xb..0xf], &[0x0f, 0xc6, 0xd3, 0x1b]);
+        assert_eq!(
+            &code[0xf..0x16],
+            &[0x66, 0x49, 0x0f, 0x3a, 0x22, 0xc8, 0x01]
+        );
+        assert_eq!(
+            &code[0x16..0x1f],
+            &[0x66, 0x44, 0x0f, 0x3a, 0x16, 0x64, 0x24, 0x08, 0x02]
+        );
+        assert_eq!(&code[0x1f..0x23], &[0x66, 0x0f, 0xd7, 0xc3]);
+        assert_eq!(&code[0x23..0x28], &[0xf3, 0x44, 0x0f, 0x7f, 0x1f]);
+        assert_eq!(&code[0x28..], &[0x66, 0x0f, 0x3a, 0x63, 0x46, 0x10, 0x0c]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn avx_encodes() -> Result<()> {
+        let mut writer = create_writer(0x35);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.vaddps_vec_vec_vec(Ymm::Ymm0, Ymm::Ymm1, Ymm::Ymm2)?;
+        assembler.vpaddd_vec_vec_mem(Xmm::Xmm10, Xmm::Xmm9, Mem128(Mem::base(Register::Zax)))?;
+        assembler.vpxor_vec_vec_vec(Ymm::Ymm1, Ymm::Ymm3, Ymm::Ymm12)?;
+        assembler.vshufps_vec_vec_vec_imm8(Xmm::Xmm1, Xmm::Xmm2, Xmm::Xmm3, 0x1b)?;
+        assembler.vblendvps_vec_vec_vec_vec(Ymm::Ymm1, Ymm::Ymm2, Ymm::Ymm3, Ymm::Ymm4)?;
+        assembler
+            .vpbroadcastd_vec_mem(Ymm::Ymm5, Mem32(Mem::base_displacement(Register::R9, 8)))?;
+        assembler.vbroadcastss_vec_xmm(Ymm::Ymm1, Xmm::Xmm0)?;
+        assembler.vpermq_ymm_ymm_imm8(Ymm::Ymm2, Ymm::Ymm1, 0x4e)?;
+        assembler.vmovdqu_mem_vec(Mem256(Mem::base(Register::Zdi)), Ymm::Ymm8)?;
+        assembler.vpsllvq_vec_vec_vec(Xmm::Xmm0, Xmm::Xmm1, Xmm::Xmm2)?;
+        assembler.vzeroupper()?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..4], &[0xc5, 0xf4, 0x58, 0xc2]);
+        assert_eq!(&code[4..8], &[0xc5, 0x31, 0xfe, 0x10]);
+        assert_eq!(&code[8..0xd], &[0xc4, 0xc1, 0x65, 0xef, 0xcc]);
+        assert_eq!(&code[0xd..0x12], &[0xc5, 0xe8, 0xc6, 0xcb, 0x1b]);
+        assert_eq!(&code[0x12..0x18], &[0xc4, 0xe3, 0x6d, 0x4a, 0xcb, 0x40]);
+        assert_eq!(&code[0x18..0x1e], &[0xc4, 0xc2, 0x7d, 0x58, 0x69, 0x08]);
+        assert_eq!(&code[0x1e..0x23], &[0xc4, 0xe2, 0x7d, 0x18, 0xc8]);
+        assert_eq!(&code[0x23..0x29], &[0xc4, 0xe3, 0xfd, 0x00, 0xd1, 0x4e]);
+        assert_eq!(&code[0x29..0x2d], &[0xc5, 0x7e, 0x7f, 0x07]);
+        assert_eq!(&code[0x2d..0x32], &[0xc4, 0xe2, 0xf1, 0x47, 0xc2]);
+        assert_eq!(&code[0x32..], &[0xc5, 0xf8, 0x77]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn avx512_encodes() -> Result<()> {
+        let mut writer = create_writer(0x65);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.vaddps_zmm_zmm_zmm(Zmm::Zmm0, Zmm::Zmm1, Zmm::Zmm2, Masking::None)?;
+        assembler.vaddpd_zmm_zmm_zmm(
+            Zmm::Zmm8,
+            Zmm::Zmm17,
+            Zmm::Zmm29,
+            Masking::Zero(Opmask::K1),
+        )?;
+        assembler.vaddps_zmm_zmm_zmm_round(
+            Zmm::Zmm0,
+            Zmm::Zmm1,
+            Zmm::Zmm2,
+            Masking::None,
+            Rounding::Down,
+        )?;
+
+        // 128 is a multiple of 64, so it's a compressed disp8, 100 isn't.
+        assembler.vpaddd_zmm_zmm_mem(
+            Zmm::Zmm2,
+            Zmm::Zmm1,
+            Mem512(Mem::base_displacement(Register::Zax, 128)),
+            Masking::Merge(Opmask::K3),
+        )?;
+        assembler.vpaddq_zmm_zmm_bcst(
+            Zmm::Zmm2,
+            Zmm::Zmm1,
+            Mem64(Mem::base_displacement(Register::R9, 16)),
+            Masking::None,
+        )?;
+        assembler.vpaddd_zmm_zmm_mem(
+            Zmm::Zmm2,
+            Zmm::Zmm1,
+            Mem512(Mem::base_displacement(Register::Zax, 100)),
+            Masking::None,
+        )?;
+
+        assembler.vpternlogd_zmm_zmm_zmm_imm8(
+            Zmm::Zmm3,
+            Zmm::Zmm3,
+            Zmm::Zmm3,
+            Masking::None,
+            0xff,
+        )?;
+        assembler.vpcmpeqd_k_zmm_zmm(Opmask::K1, Zmm::Zmm2, Zmm::Zmm1, Some(Opmask::K2))?;
+        assembler.vcmpps_k_zmm_bcst_imm8(
+            Opmask::K5,
+            Zmm::Zmm4,
+            Mem32(Mem::base(Register::Zdi)),
+            None,
+            1,
+        )?;
+        assembler.vmovdqu64_mem_zmm(
+            Mem512(Mem::base_displacement(Register::Zsp, -64)),
+            Zmm::Zmm31,
+            Some(Opmask::K1),
+        )?;
+        assembler.vbroadcastss_zmm_mem(
+            Zmm::Zmm16,
+            Mem32(Mem::base_displacement(Register::Zax, 8)),
+            Masking::None,
+        )?;
+        assembler.vpbroadcastq_zmm_xmm(Zmm::Zmm1, Xmm::Xmm3, Masking::Merge(Opmask::K7))?;
+        assembler.vsqrtpd_zmm_zmm_round(Zmm::Zmm2, Zmm::Zmm1, Masking::None, Rounding::Zero)?;
+
+        assembler.kmovw_k_reg(Opmask::K1, Reg32::ZAX)?;
+        assembler.kmovw_reg_k(Reg32::R9, Opmask::K2)?;
+        assembler.kortestw_k_k(Opmask::K1, Opmask::K1)?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..6], &[0x62, 0xf1, 0x74, 0x48, 0x58, 0xc2]);
+        assert_eq!(&code[6..0xc], &[0x62, 0x11, 0xf5, 0xc1, 0x58, 0xc5]);
+        assert_eq!(&code[0xc..0x12], &[0x62, 0xf1, 0x74, 0x38, 0x58, 0xc2]);
+        assert_eq!(
+            &code[0x12..0x19],
+            &[0x62, 0xf1, 0x75, 0x4b, 0xfe, 0x50, 0x02]
+        );
+        assert_eq!(
+            &code[0x19..0x20],
+            &[0x62, 0xd1, 0xf5, 0x58, 0xd4, 0x51, 0x02]
+        );
+        assert_eq!(
+            &code[0x20..0x2a],
+            &[0x62, 0xf1, 0x75, 0x48, 0xfe, 0x90, 0x64, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            &code[0x2a..0x31],
+            &[0x62, 0xf3, 0x65, 0x48, 0x25, 0xdb, 0xff]
+        );
+        assert_eq!(&code[0x31..0x37], &[0x62, 0xf1, 0x6d, 0x4a, 0x76, 0xc9]);
+        assert_eq!(
+            &code[0x37..0x3e],
+            &[0x62, 0xf1, 0x5c, 0x58, 0xc2, 0x2f, 0x01]
+        );
+        assert_eq!(
+            &code[0x3e..0x46],
+            &[0x62, 0x61, 0xfe, 0x49, 0x7f, 0x7c, 0x24, 0xff]
+        );
+        assert_eq!(
+            &code[0x46..0x4d],
+            &[0x62, 0xe2, 0x7d, 0x48, 0x18, 0x40, 0x02]
+        );
+        assert_eq!(&code[0x4d..0x53], &[0x62, 0xf2, 0xfd, 0x4f, 0x59, 0xcb]);
+        assert_eq!(&code[0x53..0x59], &[0x62, 0xf1, 0xfd, 0x78, 0x51, 0xd1]);
+        assert_eq!(&code[0x59..0x5d], &[0xc5, 0xf8, 0x92, 0xc8]);
+        assert_eq!(&code[0x5d..0x61], &[0xc5, 0x78, 0x93, 0xca]);
+        assert_eq!(&code[0x61..], &[0xc5, 0xf8, 0x98, 0xc9]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn avx512_rejects_k0_writemask() -> Result<()> {
+        // nothing should get written for either of these.
+        let mut writer = create_writer(0);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assert!(matches!(
+            assembler.vaddps_zmm_zmm_zmm(
+                Zmm::Zmm0,
+                Zmm::Zmm1,
+                Zmm::Zmm2,
+                Masking::Zero(Opmask::K0)
+            ),
+            Err(Error::InvalidWritemask(Masking::Zero(Opmask::K0)))
+        ));
+
+        assert!(matches!(
+            assembler.vaddps_zmm_zmm_mem(
+                Zmm::Zmm0,
+                Zmm::Zmm1,
+                Mem512(Mem::base(Register::R9).x32()),
+                Masking::Merge(Opmask::K0)
+            ),
+            Err(Error::InvalidWritemask(Masking::Merge(Opmask::K0)))
+        ));
+
+        assert_eq!(assembler.current_offset(), 0);
+
+        assembler.finish()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn hi8_encodes() -> Result<()> {
+        let mut writer = create_writer(0x16);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.xor_hi8_imm(Hi8::Ah, Imm8(0x12))?;
+        assembler.xor_mem_hi8(Mem8(Mem::base(Register::Zax)), Hi8::Bh)?;
+        assembler.xor_hi8_mem(Hi8::Ch, Mem8(Mem::base_displacement(Register::Zbx, 8)))?;
+        assembler.add_hi8_reg(Hi8::Ah, Reg8::ZBX)?;
+        assembler.add_reg_hi8(Reg8::ZAX, Hi8::Dh)?;
+        assembler.mov_hi8_hi8(Hi8::Bh, Hi8::Ah)?;
+        assembler.inc_hi8(Hi8::Dh)?;
+        assembler.shl_hi8_imm8(Hi8::Ch, 3)?;
+        assembler.sete_hi8(Hi8::Bh)?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..3], &[0x80, 0xf4, 0x12]);
+        assert_eq!(&code[3..5], &[0x30, 0x38]);
+        assert_eq!(&code[5..8], &[0x32, 0x6b, 0x08]);
+        assert_eq!(&code[8..0xa], &[0x02, 0xe3]);
+        assert_eq!(&code[0xa..0xc], &[0x02, 0xc6]);
+        assert_eq!(&code[0xc..0xe], &[0x8a, 0xfc]);
+        assert_eq!(&code[0xe..0x10], &[0xfe, 0xc6]);
+        assert_eq!(&code[0x10..0x13], &[0xc0, 0xe5, 0x03]);
+        assert_eq!(&code[0x13..], &[0x0f, 0x94, 0xc7]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hi8_source_encodes() -> Result<()> {
+        let mut writer = create_writer(0xd);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.movzx_reg_hi8(Reg32::ZAX, Hi8::Ah)?;
+        assembler.movsx_reg_hi8(Reg16::ZCX, Hi8::Bh)?;
+        assembler.setcc_hi8(Condition::E, Hi8::Ah)?;
+        assembler.setcc_hi8(Condition::NE, Hi8::Bh)?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..3], &[0x0f, 0xb6, 0xc4]);
+        assert_eq!(&code[3..7], &[0x66, 0x0f, 0xbe, 0xcf]);
+        assert_eq!(&code[7..0xa], &[0x0f, 0x94, 0xc4]);
+        assert_eq!(&code[0xa..], &[0x0f, 0x95, 0xc7]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hi8_rejects_rex() -> Result<()> {
+        // nothing should get written for any of these.
+        let mut writer = create_writer(0);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assert!(matches!(
+            assembler.add_hi8_reg(Hi8::Ah, Reg8::ZSI),
+            Err(Error::Hi8RexConflict(Hi8::Ah))
+        ));
+
+        assert!(matches!(
+            assembler.mov_reg_hi8(Reg8::R8, Hi8::Bh),
+            Err(Error::Hi8RexConflict(Hi8::Bh))
+        ));
+
+        assert!(matches!(
+            assembler.xor_hi8_mem(Hi8::Ch, Mem8(Mem::base(Register::R9).x32())),
+            Err(Error::Hi8RexConflict(Hi8::Ch))
+        ));
+
+        assert!(matches!(
+            assembler.lock_add_mem_hi8(Mem8(Mem::base(Register::R9)), Hi8::Ah),
+            Err(Error::Hi8RexConflict(Hi8::Ah))
+        ));
+
+        assert!(matches!(
+            assembler.movzx_reg_hi8(Reg32::R8, Hi8::Dh),
+            Err(Error::Hi8RexConflict(Hi8::Dh))
+        ));
+
+        // REX.W turns AH into SPL all the same.
+        assert!(matches!(
+            assembler.movsx_reg_hi8(Reg64::ZAX, Hi8::Ah),
+            Err(Error::Hi8RexConflict(Hi8::Ah))
+        ));
+
+        assert_eq!(assembler.current_offset(), 0);
+
+        assembler.finish()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn string_encodes() -> Result<()> {
+        let mut writer = create_writer(0xe);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.rep_stosq()?;
+        assembler.repne_scasb()?;
+        assembler.movsw()?;
+        assembler.cmpsd()?;
+        assembler.lodsq()?;
+        assembler.repe_cmpsb()?;
+        assembler.rep_movsb()?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..3], &[0xf3, 0x48, 0xab]);
+        assert_eq!(&code[3..5], &[0xf2, 0xae]);
+        assert_eq!(&code[5..7], &[0x66, 0xa5]);
+        assert_eq!(&code[7..8], &[0xa7]);
+        assert_eq!(&code[8..0xa], &[0x48, 0xad]);
+        assert_eq!(&code[0xa..0xc], &[0xf3, 0xa6]);
+        assert_eq!(&code[0xc..], &[0xf3, 0xa4]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn lock_encodes() -> Result<()> {
+        let mut writer = create_writer(0x24);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.lock_add_mem_reg(Mem32(Mem::base(Register::Zdi)), Reg32::ZAX)?;
+        assembler.lock_cmpxchg_mem_reg(Mem64(Mem::base(Register::Zdx)), Reg64::ZCX)?;
+        assembler.lock_cmpxchg16b_mem(Mem128(Mem::base(Register::Zdi)))?;
+        assembler.lock_cmpxchg8b_mem(Mem64(Mem::base(Register::R9)))?;
+        assembler.lock_inc_mem(Mem32(Mem::base(Register::Zax)))?;
+        assembler.lock_bts_mem_imm8(Mem64(Mem::base(Register::Zax)), 3)?;
+        assembler.lock_sub_mem_sximm8(Mem16(Mem::base(Register::Zbx)), -2)?;
+        assembler.lock_xadd_mem_hi8(Mem8(Mem::base(Register::Zcx)), Hi8::Ah)?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..3], &[0xf0, 0x01, 0x07]);
+        assert_eq!(&code[3..8], &[0xf0, 0x48, 0x0f, 0xb1, 0x0a]);
+        assert_eq!(&code[8..0xd], &[0xf0, 0x48, 0x0f, 0xc7, 0x0f]);
+        assert_eq!(&code[0xd..0x12], &[0xf0, 0x41, 0x0f, 0xc7, 0x09]);
+        assert_eq!(&code[0x12..0x15], &[0xf0, 0xff, 0x00]);
+        assert_eq!(&code[0x15..0x1b], &[0xf0, 0x48, 0x0f, 0xba, 0x28, 0x03]);
+        assert_eq!(&code[0x1b..0x20], &[0xf0, 0x66, 0x83, 0x2b, 0xfe]);
+        assert_eq!(&code[0x20..], &[0xf0, 0x0f, 0xc0, 0x21]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mul_div_encodes() -> Result<()> {
+        let mut writer = create_writer(0x20);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.mul_reg(Reg64::ZCX)?;
+        assembler.div_mem(Mem32(Mem::base(Register::Zax)))?;
+        assembler.idiv_reg(Reg8::R9)?;
+        assembler.imul_hi8(Hi8::Bh)?;
+        assembler.imul_reg_reg_imm(Reg64::ZAX, Reg64::R8, Imm64(0x1234_5678))?;
+        assembler.imul_reg_reg_sximm8(Reg32::R10, Reg32::ZCX, -3)?;
+        assembler.imul_reg_mem_imm(Reg16::ZDX, Mem16(Mem::base(Register::Zbx)), Imm16(0x1234))?;
+        assembler.imul_reg_mem_sximm8(
+            Reg64::ZSI,
+            Mem64(Mem::base_displacement(Register::R12, 8)),
+            5,
+        )?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..3], &[0x48, 0xf7, 0xe1]);
+        assert_eq!(&code[3..5], &[0xf7, 0x30]);
+        assert_eq!(&code[5..8], &[0x41, 0xf6, 0xf9]);
+        assert_eq!(&code[8..0xa], &[0xf6, 0xef]);
+        assert_eq!(
+            &code[0xa..0x11],
+            &[0x49, 0x69, 0xc0, 0x78, 0x56, 0x34, 0x12]
+        );
+        assert_eq!(&code[0x11..0x15], &[0x44, 0x6b, 0xd1, 0xfd]);
+        assert_eq!(&code[0x15..0x1a], &[0x66, 0x69, 0x13, 0x34, 0x12]);
+        assert_eq!(&code[0x1a..], &[0x49, 0x6b, 0x74, 0x24, 0x08, 0x05]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn shift_encodes() -> Result<()> {
+        let mut writer = create_writer(0x18);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.shl_reg_cl(Reg64::ZAX)?;
+        assembler.sar_mem_cl(Mem32(Mem::base(Register::Zbx)))?;
+        assembler.rol_reg_1(Reg16::R8)?;
+        assembler.shr_reg_imm8(Reg8::ZSI, 1)?;
+        assembler.rcr_hi8_imm8(Hi8::Ch, 1)?;
+        assembler.sar_mem_imm8(Mem64(Mem::base_displacement(Register::Zdx, 8)), 1)?;
+        assembler.shl_reg_imm8(Reg8::ZDI, 3)?;
+        assembler.ror_hi8_cl(Hi8::Ah)?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..3], &[0x48, 0xd3, 0xe0]);
+        assert_eq!(&code[3..5], &[0xd3, 0x3b]);
+        assert_eq!(&code[5..9], &[0x66, 0x41, 0xd1, 0xc0]);
+        assert_eq!(&code[9..0xc], &[0x40, 0xd0, 0xee]);
+        assert_eq!(&code[0xc..0xe], &[0xd0, 0xdd]);
+        assert_eq!(&code[0xe..0x12], &[0x48, 0xd1, 0x7a, 0x08]);
+        assert_eq!(&code[0x12..0x16], &[0x40, 0xc0, 0xe7, 0x03]);
+        assert_eq!(&code[0x16..], &[0xd2, 0xcc]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bmi_encodes() -> Result<()> {
+        let mut writer = create_writer(0x3e);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.andn_reg_reg_reg(Reg64::ZAX, Reg64::ZBX, Reg64::ZCX)?;
+        assembler.pdep_reg_reg_mem(Reg32::ZAX, Reg32::R9, Mem32(Mem::base(Register::Zdi)))?;
+        assembler.pext_reg_reg_reg(Reg64::R12, Reg64::R11, Reg64::R10)?;
+        assembler.mulx_reg_reg_reg(Reg64::ZDI, Reg64::ZSI, Reg64::ZCX)?;
+        assembler.blsi_reg_reg(Reg64::ZAX, Reg64::R8)?;
+        assembler.blsr_reg_mem(Reg32::ZCX, Mem32(Mem::base(Register::Zbx)))?;
+        assembler.blsmsk_reg_reg(Reg32::R15, Reg32::ZAX)?;
+        assembler.rorx_reg_reg_imm8(Reg64::ZDX, Reg64::R9, 7)?;
+        assembler.rorx_reg_mem_imm8(Reg32::ZCX, Mem32(Mem::base(Register::Zax)), 3)?;
+        assembler.tzcnt_reg_reg(Reg64::ZAX, Reg64::ZBX)?;
+        assembler.lzcnt_reg_mem(Reg32::R8, Mem32(Mem::base(Register::Zdi)))?;
+        assembler.popcnt_reg_reg(Reg16::ZBX, Reg16::ZAX)?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..5], &[0xc4, 0xe2, 0xe0, 0xf2, 0xc1]);
+        assert_eq!(&code[5..0xa], &[0xc4, 0xe2, 0x33, 0xf5, 0x07]);
+        assert_eq!(&code[0xa..0xf], &[0xc4, 0x42, 0xa2, 0xf5, 0xe2]);
+        assert_eq!(&code[0xf..0x14], &[0xc4, 0xe2, 0xcb, 0xf6, 0xf9]);
+        assert_eq!(&code[0x14..0x19], &[0xc4, 0xc2, 0xf8, 0xf3, 0xd8]);
+        assert_eq!(&code[0x19..0x1e], &[0xc4, 0xe2, 0x70, 0xf3, 0x0b]);
+        assert_eq!(&code[0x1e..0x23], &[0xc4, 0xe2, 0x00, 0xf3, 0xd0]);
+        assert_eq!(&code[0x23..0x29], &[0xc4, 0xc3, 0xfb, 0xf0, 0xd1, 0x07]);
+        assert_eq!(&code[0x29..0x2f], &[0xc4, 0xe3, 0x7b, 0xf0, 0x08, 0x03]);
+        assert_eq!(&code[0x2f..0x34], &[0xf3, 0x48, 0x0f, 0xbc, 0xc3]);
+        assert_eq!(&code[0x34..0x39], &[0xf3, 0x44, 0x0f, 0xbd, 0x07]);
+        assert_eq!(&code[0x39..], &[0xf3, 0x66, 0x0f, 0xb8, 0xd8]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn extend_encodes() -> Result<()> {
+        let mut writer = create_writer(0x1f);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.movzx_reg_reg8(Reg32::ZAX, Reg8::ZSI)?;
+        assembler.movzx_reg_reg8(Reg16::R10, Reg8::R9)?;
+        assembler.movzx_reg_reg16(Reg64::ZCX, Reg16::ZAX)?;
+        assembler.movsx_reg_reg8(Reg64::R8, Reg8::ZDI)?;
+        assembler.movsx_reg_mem16(Reg32::ZDX, Mem16(Mem::base(Register::Zbx)))?;
+        assembler.movsxd_reg_reg32(Reg64::ZAX, Reg32::R11)?;
+        assembler.movsxd_reg_mem32(Reg64::R12, Mem32(Mem::base_displacement(Register::Zdi, 8)))?;
+        assembler.movzx_reg_mem8(Reg64::ZAX, Mem8(Mem::base(Register::Zax)))?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..4], &[0x40, 0x0f, 0xb6, 0xc6]);
+        assert_eq!(&code[4..9], &[0x66, 0x45, 0x0f, 0xb6, 0xd1]);
+        assert_eq!(&code[9..0xd], &[0x48, 0x0f, 0xb7, 0xc8]);
+        assert_eq!(&code[0xd..0x11], &[0x4c, 0x0f, 0xbe, 0xc7]);
+        assert_eq!(&code[0x11..0x14], &[0x0f, 0xbf, 0x13]);
+        assert_eq!(&code[0x14..0x17], &[0x49, 0x63, 0xc3]);
+        assert_eq!(&code[0x17..0x1b], &[0x4c, 0x63, 0x67, 0x08]);
+        assert_eq!(&code[0x1b..], &[0x48, 0x0f, 0xb6, 0x00]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sign_extend_encodes() -> Result<()> {
+        let mut writer = create_writer(0xa);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.sign_extend_zax::<W16>()?;
+        assembler.sign_extend_zax::<W32>()?;
+        assembler.sign_extend_zax::<W64>()?;
+        assembler.sign_extend_zax_into_zdx::<W16>()?;
+        assembler.sign_extend_zax_into_zdx::<W32>()?;
+        assembler.sign_extend_zax_into_zdx::<W64>()?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..2], &[0x66, 0x98]);
+        assert_eq!(&code[2..3], &[0x98]);
+        assert_eq!(&code[3..5], &[0x48, 0x98]);
+        assert_eq!(&code[5..7], &[0x66, 0x99]);
+        assert_eq!(&code[7..8], &[0x99]);
+        assert_eq!(&code[8..], &[0x48, 0x99]);
+
+        Ok(())
+    }
 }
@@ -1,52 +1,67 @@
-use crate::Assembler;
 use crate::params::{
-    GeneralRegister, Immediate, W8, W16, W64, WWidth, WidthAtLeast16, WidthAtLeast32,
-    WidthAtMost32, mem::Memory,
+    GeneralRegister, Hi8, Immediate, Masking, Opmask, Register, Rounding, VectorRegister,
+    VectorWidth, W8, W16, W32, W64, W128, W256, W512, WWidth, WidthAtLeast16, WidthAtLeast32,
+    WidthAtMost32, Xmm, Ymm, Zmm,
+    imm::Imm8,
+    mem::{Memory, VectorMemory},
 };
-use std::io;
+use crate::{Assembler, CodeSink, Label, Result};
 
-impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
-    pub fn adc_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> io::Result<()> {
+impl<'a, T: CodeSink> Assembler<'a, T> {
+    pub fn adc_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> Result<()> {
         self.op_zax_imm(imm, 0x14, 0x15)
     }
 
-    pub fn add_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> io::Result<()> {
+    pub fn add_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> Result<()> {
         self.op_zax_imm(imm, 0x4, 0x5)
     }
 
-    pub fn and_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> io::Result<()> {
+    pub fn and_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> Result<()> {
         self.op_zax_imm(imm, 0x24, 0x25)
     }
 
-    pub fn cmp_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> io::Result<()> {
+    pub fn cmp_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> Result<()> {
         self.op_zax_imm(imm, 0x3c, 0x3d)
     }
 
-    pub fn or_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> io::Result<()> {
+    pub fn or_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> Result<()> {
         self.op_zax_imm(imm, 0xc, 0xd)
     }
 
-    pub fn sbb_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> io::Result<()> {
+    pub fn sbb_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> Result<()> {
         self.op_zax_imm(imm, 0x1c, 0x1d)
     }
 
-    pub fn sub_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> io::Result<()> {
+    pub fn sub_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> Result<()> {
         self.op_zax_imm(imm, 0x2c, 0x2d)
     }
 
-    pub fn test_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> io::Result<()> {
+    pub fn test_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> Result<()> {
         self.op_zax_imm(imm, 0xa8, 0xa9)
     }
 
-    pub fn xor_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> io::Result<()> {
+    pub fn xor_zax_imm<Width: WWidth>(&mut self, imm: impl Immediate<Width>) -> Result<()> {
         self.op_zax_imm(imm, 0x34, 0x35)
     }
 
+    pub fn adc_hi8_imm(&mut self, reg: Hi8, imm: impl Immediate<W8>) -> Result<()> {
+        self.op_hi8_imm(reg, imm, 0x80, 2)
+    }
+
+    pub fn lock_adc_mem_imm<Width: WWidth, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: impl Immediate<Width>,
+    ) -> Result<()> {
+        self.lock()?;
+        self.adc_mem_imm(mem, imm)
+    }
+
     pub fn adc_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm(reg, imm, 0x80, 0x81, 2)
     }
 
@@ -54,15 +69,28 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm(mem, imm, 0x80, 0x81, 2)
     }
 
+    pub fn add_hi8_imm(&mut self, reg: Hi8, imm: impl Immediate<W8>) -> Result<()> {
+        self.op_hi8_imm(reg, imm, 0x80, 0)
+    }
+
+    pub fn lock_add_mem_imm<Width: WWidth, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: impl Immediate<Width>,
+    ) -> Result<()> {
+        self.lock()?;
+        self.add_mem_imm(mem, imm)
+    }
+
     pub fn add_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm(reg, imm, 0x80, 0x81, 0)
     }
 
@@ -70,15 +98,28 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm(mem, imm, 0x80, 0x81, 0)
     }
 
+    pub fn and_hi8_imm(&mut self, reg: Hi8, imm: impl Immediate<W8>) -> Result<()> {
+        self.op_hi8_imm(reg, imm, 0x80, 4)
+    }
+
+    pub fn lock_and_mem_imm<Width: WWidth, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: impl Immediate<Width>,
+    ) -> Result<()> {
+        self.lock()?;
+        self.and_mem_imm(mem, imm)
+    }
+
     pub fn and_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm(reg, imm, 0x80, 0x81, 4)
     }
 
@@ -86,15 +127,19 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm(mem, imm, 0x80, 0x81, 4)
     }
 
+    pub fn cmp_hi8_imm(&mut self, reg: Hi8, imm: impl Immediate<W8>) -> Result<()> {
+        self.op_hi8_imm(reg, imm, 0x80, 7)
+    }
+
     pub fn cmp_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm(reg, imm, 0x80, 0x81, 7)
     }
 
@@ -102,15 +147,19 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm(mem, imm, 0x80, 0x81, 7)
     }
 
+    pub fn mov_hi8_imm(&mut self, reg: Hi8, imm: impl Immediate<W8>) -> Result<()> {
+        self.op_hi8_imm(reg, imm, 0xc6, 0)
+    }
+
     pub fn mov_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm(reg, imm, 0xc6, 0xc7, 0)
     }
 
@@ -118,15 +167,28 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm(mem, imm, 0xc6, 0xc7, 0)
     }
 
+    pub fn or_hi8_imm(&mut self, reg: Hi8, imm: impl Immediate<W8>) -> Result<()> {
+        self.op_hi8_imm(reg, imm, 0x80, 1)
+    }
+
+    pub fn lock_or_mem_imm<Width: WWidth, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: impl Immediate<Width>,
+    ) -> Result<()> {
+        self.lock()?;
+        self.or_mem_imm(mem, imm)
+    }
+
     pub fn or_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm(reg, imm, 0x80, 0x81, 1)
     }
 
@@ -134,15 +196,28 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm(mem, imm, 0x80, 0x81, 1)
     }
 
+    pub fn sbb_hi8_imm(&mut self, reg: Hi8, imm: impl Immediate<W8>) -> Result<()> {
+        self.op_hi8_imm(reg, imm, 0x80, 3)
+    }
+
+    pub fn lock_sbb_mem_imm<Width: WWidth, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: impl Immediate<Width>,
+    ) -> Result<()> {
+        self.lock()?;
+        self.sbb_mem_imm(mem, imm)
+    }
+
     pub fn sbb_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm(reg, imm, 0x80, 0x81, 3)
     }
 
@@ -150,15 +225,28 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm(mem, imm, 0x80, 0x81, 3)
     }
 
+    pub fn sub_hi8_imm(&mut self, reg: Hi8, imm: impl Immediate<W8>) -> Result<()> {
+        self.op_hi8_imm(reg, imm, 0x80, 5)
+    }
+
+    pub fn lock_sub_mem_imm<Width: WWidth, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: impl Immediate<Width>,
+    ) -> Result<()> {
+        self.lock()?;
+        self.sub_mem_imm(mem, imm)
+    }
+
     pub fn sub_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm(reg, imm, 0x80, 0x81, 5)
     }
 
@@ -166,15 +254,19 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm(mem, imm, 0x80, 0x81, 5)
     }
 
+    pub fn test_hi8_imm(&mut self, reg: Hi8, imm: impl Immediate<W8>) -> Result<()> {
+        self.op_hi8_imm(reg, imm, 0xf6, 0)
+    }
+
     pub fn test_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm(reg, imm, 0xf6, 0xf7, 0)
     }
 
@@ -182,15 +274,28 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm(mem, imm, 0xf6, 0xf7, 0)
     }
 
+    pub fn xor_hi8_imm(&mut self, reg: Hi8, imm: impl Immediate<W8>) -> Result<()> {
+        self.op_hi8_imm(reg, imm, 0x80, 6)
+    }
+
+    pub fn lock_xor_mem_imm<Width: WWidth, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: impl Immediate<Width>,
+    ) -> Result<()> {
+        self.lock()?;
+        self.xor_mem_imm(mem, imm)
+    }
+
     pub fn xor_reg_imm<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm(reg, imm, 0x80, 0x81, 6)
     }
 
@@ -198,7 +303,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: impl Immediate<Width>,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm(mem, imm, 0x80, 0x81, 6)
     }
 
@@ -206,199 +311,315 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm, 0xba, 0xba, 4, Some(0xf))
     }
 
-    pub fn bt_mem_imm8<Width: WWidth, M: Memory<Width>>(
+    pub fn bt_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
+        self.op_mem_imm8(mem, imm, 0xba, 0xba, 4, Some(0xf))
+    }
+
+    pub fn lock_btc_mem_imm8<Width: WWidth, M: Memory<Width>>(
         &mut self,
         mem: M,
         imm: u8,
-    ) -> io::Result<()> {
-        self.op_mem_imm8(mem, imm, 0xba, 0xba, 4, Some(0xf))
+    ) -> Result<()> {
+        self.lock()?;
+        self.btc_mem_imm8(mem, imm)
     }
 
     pub fn btc_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm, 0xba, 0xba, 7, Some(0xf))
     }
 
-    pub fn btc_mem_imm8<Width: WWidth, M: Memory<Width>>(
+    pub fn btc_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
+        self.op_mem_imm8(mem, imm, 0xba, 0xba, 7, Some(0xf))
+    }
+
+    pub fn lock_btr_mem_imm8<Width: WWidth, M: Memory<Width>>(
         &mut self,
         mem: M,
         imm: u8,
-    ) -> io::Result<()> {
-        self.op_mem_imm8(mem, imm, 0xba, 0xba, 7, Some(0xf))
+    ) -> Result<()> {
+        self.lock()?;
+        self.btr_mem_imm8(mem, imm)
     }
 
     pub fn btr_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm, 0xba, 0xba, 6, Some(0xf))
     }
 
-    pub fn btr_mem_imm8<Width: WWidth, M: Memory<Width>>(
+    pub fn btr_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
+        self.op_mem_imm8(mem, imm, 0xba, 0xba, 6, Some(0xf))
+    }
+
+    pub fn lock_bts_mem_imm8<Width: WWidth, M: Memory<Width>>(
         &mut self,
         mem: M,
         imm: u8,
-    ) -> io::Result<()> {
-        self.op_mem_imm8(mem, imm, 0xba, 0xba, 6, Some(0xf))
+    ) -> Result<()> {
+        self.lock()?;
+        self.bts_mem_imm8(mem, imm)
     }
 
     pub fn bts_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm, 0xba, 0xba, 5, Some(0xf))
     }
 
-    pub fn bts_mem_imm8<Width: WWidth, M: Memory<Width>>(
-        &mut self,
-        mem: M,
-        imm: u8,
-    ) -> io::Result<()> {
+    pub fn bts_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
         self.op_mem_imm8(mem, imm, 0xba, 0xba, 5, Some(0xf))
     }
 
+    pub fn rcl_hi8_imm8(&mut self, reg: Hi8, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.rcl_hi8_1(reg);
+        }
+
+        self.op_hi8_imm(reg, Imm8(imm), 0xc0, 2)
+    }
+
     pub fn rcl_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
+        if imm == 1 {
+            return self.rcl_reg_1(reg);
+        }
+
         self.op_reg_imm8(reg, imm, 0xc0, 0xc1, 2, None)
     }
 
-    pub fn rcl_mem_imm8<Width: WWidth, M: Memory<Width>>(
-        &mut self,
-        mem: M,
-        imm: u8,
-    ) -> io::Result<()> {
+    pub fn rcl_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.rcl_mem_1(mem);
+        }
+
         self.op_mem_imm8(mem, imm, 0xc0, 0xc1, 2, None)
     }
 
+    pub fn rcr_hi8_imm8(&mut self, reg: Hi8, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.rcr_hi8_1(reg);
+        }
+
+        self.op_hi8_imm(reg, Imm8(imm), 0xc0, 3)
+    }
+
     pub fn rcr_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
+        if imm == 1 {
+            return self.rcr_reg_1(reg);
+        }
+
         self.op_reg_imm8(reg, imm, 0xc0, 0xc1, 3, None)
     }
 
-    pub fn rcr_mem_imm8<Width: WWidth, M: Memory<Width>>(
-        &mut self,
-        mem: M,
-        imm: u8,
-    ) -> io::Result<()> {
+    pub fn rcr_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.rcr_mem_1(mem);
+        }
+
         self.op_mem_imm8(mem, imm, 0xc0, 0xc1, 3, None)
     }
 
+    pub fn rol_hi8_imm8(&mut self, reg: Hi8, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.rol_hi8_1(reg);
+        }
+
+        self.op_hi8_imm(reg, Imm8(imm), 0xc0, 0)
+    }
+
     pub fn rol_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
+        if imm == 1 {
+            return self.rol_reg_1(reg);
+        }
+
         self.op_reg_imm8(reg, imm, 0xc0, 0xc1, 0, None)
     }
 
-    pub fn rol_mem_imm8<Width: WWidth, M: Memory<Width>>(
-        &mut self,
-        mem: M,
-        imm: u8,
-    ) -> io::Result<()> {
+    pub fn rol_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.rol_mem_1(mem);
+        }
+
         self.op_mem_imm8(mem, imm, 0xc0, 0xc1, 0, None)
     }
 
+    pub fn ror_hi8_imm8(&mut self, reg: Hi8, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.ror_hi8_1(reg);
+        }
+
+        self.op_hi8_imm(reg, Imm8(imm), 0xc0, 1)
+    }
+
     pub fn ror_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
+        if imm == 1 {
+            return self.ror_reg_1(reg);
+        }
+
         self.op_reg_imm8(reg, imm, 0xc0, 0xc1, 1, None)
     }
 
-    pub fn ror_mem_imm8<Width: WWidth, M: Memory<Width>>(
-        &mut self,
-        mem: M,
-        imm: u8,
-    ) -> io::Result<()> {
+    pub fn ror_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.ror_mem_1(mem);
+        }
+
         self.op_mem_imm8(mem, imm, 0xc0, 0xc1, 1, None)
     }
 
+    pub fn sal_hi8_imm8(&mut self, reg: Hi8, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.sal_hi8_1(reg);
+        }
+
+        self.op_hi8_imm(reg, Imm8(imm), 0xc0, 4)
+    }
+
     pub fn sal_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
+        if imm == 1 {
+            return self.sal_reg_1(reg);
+        }
+
         self.op_reg_imm8(reg, imm, 0xc0, 0xc1, 4, None)
     }
 
-    pub fn sal_mem_imm8<Width: WWidth, M: Memory<Width>>(
-        &mut self,
-        mem: M,
-        imm: u8,
-    ) -> io::Result<()> {
+    pub fn sal_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.sal_mem_1(mem);
+        }
+
         self.op_mem_imm8(mem, imm, 0xc0, 0xc1, 4, None)
     }
 
+    pub fn sar_hi8_imm8(&mut self, reg: Hi8, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.sar_hi8_1(reg);
+        }
+
+        self.op_hi8_imm(reg, Imm8(imm), 0xc0, 7)
+    }
+
     pub fn sar_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
+        if imm == 1 {
+            return self.sar_reg_1(reg);
+        }
+
         self.op_reg_imm8(reg, imm, 0xc0, 0xc1, 7, None)
     }
 
-    pub fn sar_mem_imm8<Width: WWidth, M: Memory<Width>>(
-        &mut self,
-        mem: M,
-        imm: u8,
-    ) -> io::Result<()> {
+    pub fn sar_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.sar_mem_1(mem);
+        }
+
         self.op_mem_imm8(mem, imm, 0xc0, 0xc1, 7, None)
     }
 
+    pub fn shl_hi8_imm8(&mut self, reg: Hi8, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.shl_hi8_1(reg);
+        }
+
+        self.op_hi8_imm(reg, Imm8(imm), 0xc0, 4)
+    }
+
     pub fn shl_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
+        if imm == 1 {
+            return self.shl_reg_1(reg);
+        }
+
         self.op_reg_imm8(reg, imm, 0xc0, 0xc1, 4, None)
     }
 
-    pub fn shl_mem_imm8<Width: WWidth, M: Memory<Width>>(
-        &mut self,
-        mem: M,
-        imm: u8,
-    ) -> io::Result<()> {
+    pub fn shl_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.shl_mem_1(mem);
+        }
+
         self.op_mem_imm8(mem, imm, 0xc0, 0xc1, 4, None)
     }
 
+    pub fn shr_hi8_imm8(&mut self, reg: Hi8, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.shr_hi8_1(reg);
+        }
+
+        self.op_hi8_imm(reg, Imm8(imm), 0xc0, 5)
+    }
+
     pub fn shr_reg_imm8<Width: WWidth, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: u8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
+        if imm == 1 {
+            return self.shr_reg_1(reg);
+        }
+
         self.op_reg_imm8(reg, imm, 0xc0, 0xc1, 5, None)
     }
 
-    pub fn shr_mem_imm8<Width: WWidth, M: Memory<Width>>(
+    pub fn shr_mem_imm8<Width: WWidth, M: Memory<Width>>(&mut self, mem: M, imm: u8) -> Result<()> {
+        if imm == 1 {
+            return self.shr_mem_1(mem);
+        }
+
+        self.op_mem_imm8(mem, imm, 0xc0, 0xc1, 5, None)
+    }
+
+    pub fn lock_adc_mem_sximm8<Width: WidthAtLeast16, M: Memory<Width>>(
         &mut self,
         mem: M,
-        imm: u8,
-    ) -> io::Result<()> {
-        self.op_mem_imm8(mem, imm, 0xc0, 0xc1, 5, None)
+        imm: i8,
+    ) -> Result<()> {
+        self.lock()?;
+        self.adc_mem_sximm8(mem, imm)
     }
 
     pub fn adc_reg_sximm8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm as u8, 0x83, 0x83, 2, None)
     }
 
@@ -406,15 +627,24 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm8(mem, imm as u8, 0x83, 0x83, 2, None)
     }
 
+    pub fn lock_add_mem_sximm8<Width: WidthAtLeast16, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: i8,
+    ) -> Result<()> {
+        self.lock()?;
+        self.add_mem_sximm8(mem, imm)
+    }
+
     pub fn add_reg_sximm8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm as u8, 0x83, 0x83, 0, None)
     }
 
@@ -422,15 +652,24 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm8(mem, imm as u8, 0x83, 0x83, 0, None)
     }
 
+    pub fn lock_and_mem_sximm8<Width: WidthAtLeast16, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: i8,
+    ) -> Result<()> {
+        self.lock()?;
+        self.and_mem_sximm8(mem, imm)
+    }
+
     pub fn and_reg_sximm8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm as u8, 0x83, 0x83, 4, None)
     }
 
@@ -438,7 +677,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm8(mem, imm as u8, 0x83, 0x83, 4, None)
     }
 
@@ -446,7 +685,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         reg: R,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm as u8, 0x83, 0x83, 7, None)
     }
 
@@ -454,15 +693,24 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm8(mem, imm as u8, 0x83, 0x83, 7, None)
     }
 
+    pub fn lock_or_mem_sximm8<Width: WidthAtLeast16, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: i8,
+    ) -> Result<()> {
+        self.lock()?;
+        self.or_mem_sximm8(mem, imm)
+    }
+
     pub fn or_reg_sximm8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm as u8, 0x83, 0x83, 1, None)
     }
 
@@ -470,15 +718,24 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm8(mem, imm as u8, 0x83, 0x83, 1, None)
     }
 
+    pub fn lock_sbb_mem_sximm8<Width: WidthAtLeast16, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: i8,
+    ) -> Result<()> {
+        self.lock()?;
+        self.sbb_mem_sximm8(mem, imm)
+    }
+
     pub fn sbb_reg_sximm8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm as u8, 0x83, 0x83, 3, None)
     }
 
@@ -486,15 +743,24 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm8(mem, imm as u8, 0x83, 0x83, 3, None)
     }
 
+    pub fn lock_sub_mem_sximm8<Width: WidthAtLeast16, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: i8,
+    ) -> Result<()> {
+        self.lock()?;
+        self.sub_mem_sximm8(mem, imm)
+    }
+
     pub fn sub_reg_sximm8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm as u8, 0x83, 0x83, 5, None)
     }
 
@@ -502,15 +768,24 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm8(mem, imm as u8, 0x83, 0x83, 5, None)
     }
 
+    pub fn lock_xor_mem_sximm8<Width: WidthAtLeast16, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        imm: i8,
+    ) -> Result<()> {
+        self.lock()?;
+        self.xor_mem_sximm8(mem, imm)
+    }
+
     pub fn xor_reg_sximm8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_imm8(reg, imm as u8, 0x83, 0x83, 6, None)
     }
 
@@ -518,11 +793,27 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         mem: M,
         imm: i8,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem_imm8(mem, imm as u8, 0x83, 0x83, 6, None)
     }
 
-    pub fn adc_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn adc_hi8_mem<M: Memory<W8>>(&mut self, reg: Hi8, mem: M) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x12, None, false)
+    }
+
+    pub fn adc_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0x12, None)
+    }
+
+    pub fn adc_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0x12, None)
+    }
+
+    pub fn adc_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0x12, None)
+    }
+
+    pub fn adc_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -530,14 +821,30 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x12, 0x13, None)
     }
 
-    pub fn adc_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn adc_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x12, 0x13, None)
     }
 
-    pub fn add_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn add_hi8_mem<M: Memory<W8>>(&mut self, reg: Hi8, mem: M) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x2, None, false)
+    }
+
+    pub fn add_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0x2, None)
+    }
+
+    pub fn add_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0x2, None)
+    }
+
+    pub fn add_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0x2, None)
+    }
+
+    pub fn add_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -545,14 +852,30 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x2, 0x3, None)
     }
 
-    pub fn add_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn add_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x2, 0x3, None)
     }
 
-    pub fn and_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn and_hi8_mem<M: Memory<W8>>(&mut self, reg: Hi8, mem: M) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x22, None, false)
+    }
+
+    pub fn and_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0x22, None)
+    }
+
+    pub fn and_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0x22, None)
+    }
+
+    pub fn and_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0x22, None)
+    }
+
+    pub fn and_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -560,14 +883,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x22, 0x23, None)
     }
 
-    pub fn and_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn and_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x22, 0x23, None)
     }
 
-    pub fn bsf_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn bsf_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -575,14 +898,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0xbc, 0xbc, Some(0xf))
     }
 
-    pub fn bsf_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn bsf_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0xbc, 0xbc, Some(0xf))
     }
 
-    pub fn bsr_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn bsr_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -590,14 +913,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0xbd, 0xbd, Some(0xf))
     }
 
-    pub fn bsr_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn bsr_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0xbd, 0xbd, Some(0xf))
     }
 
-    pub fn cmova_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmova_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -605,14 +928,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x47, 0x47, Some(0xf))
     }
 
-    pub fn cmova_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmova_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x47, 0x47, Some(0xf))
     }
 
-    pub fn cmovae_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovae_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -620,14 +943,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x43, 0x43, Some(0xf))
     }
 
-    pub fn cmovae_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovae_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x43, 0x43, Some(0xf))
     }
 
-    pub fn cmovb_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovb_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -635,14 +958,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x42, 0x42, Some(0xf))
     }
 
-    pub fn cmovb_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovb_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x42, 0x42, Some(0xf))
     }
 
-    pub fn cmovbe_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovbe_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -650,14 +973,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x46, 0x46, Some(0xf))
     }
 
-    pub fn cmovbe_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovbe_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x46, 0x46, Some(0xf))
     }
 
-    pub fn cmovc_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovc_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -665,14 +988,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x42, 0x42, Some(0xf))
     }
 
-    pub fn cmovc_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovc_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x42, 0x42, Some(0xf))
     }
 
-    pub fn cmove_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmove_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -680,14 +1003,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x44, 0x44, Some(0xf))
     }
 
-    pub fn cmove_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmove_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x44, 0x44, Some(0xf))
     }
 
-    pub fn cmovg_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovg_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -695,14 +1018,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4f, 0x4f, Some(0xf))
     }
 
-    pub fn cmovg_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovg_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4f, 0x4f, Some(0xf))
     }
 
-    pub fn cmovge_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovge_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -710,14 +1033,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4d, 0x4d, Some(0xf))
     }
 
-    pub fn cmovge_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovge_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4d, 0x4d, Some(0xf))
     }
 
-    pub fn cmovl_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovl_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -725,14 +1048,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4c, 0x4c, Some(0xf))
     }
 
-    pub fn cmovl_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovl_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4c, 0x4c, Some(0xf))
     }
 
-    pub fn cmovle_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovle_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -740,14 +1063,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4e, 0x4e, Some(0xf))
     }
 
-    pub fn cmovle_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovle_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4e, 0x4e, Some(0xf))
     }
 
-    pub fn cmovna_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovna_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -755,14 +1078,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x46, 0x46, Some(0xf))
     }
 
-    pub fn cmovna_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovna_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x46, 0x46, Some(0xf))
     }
 
-    pub fn cmovnae_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovnae_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -770,14 +1093,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x42, 0x42, Some(0xf))
     }
 
-    pub fn cmovnae_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovnae_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x42, 0x42, Some(0xf))
     }
 
-    pub fn cmovnb_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovnb_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -785,14 +1108,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x43, 0x43, Some(0xf))
     }
 
-    pub fn cmovnb_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovnb_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x43, 0x43, Some(0xf))
     }
 
-    pub fn cmovnbe_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovnbe_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -800,14 +1123,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x47, 0x47, Some(0xf))
     }
 
-    pub fn cmovnbe_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovnbe_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x47, 0x47, Some(0xf))
     }
 
-    pub fn cmovnc_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovnc_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -815,14 +1138,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x43, 0x43, Some(0xf))
     }
 
-    pub fn cmovnc_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovnc_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x43, 0x43, Some(0xf))
     }
 
-    pub fn cmovne_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovne_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -830,14 +1153,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x45, 0x45, Some(0xf))
     }
 
-    pub fn cmovne_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovne_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x45, 0x45, Some(0xf))
     }
 
-    pub fn cmovng_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovng_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -845,14 +1168,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4e, 0x4e, Some(0xf))
     }
 
-    pub fn cmovng_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovng_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4e, 0x4e, Some(0xf))
     }
 
-    pub fn cmovnge_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovnge_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -860,14 +1183,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4c, 0x4c, Some(0xf))
     }
 
-    pub fn cmovnge_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovnge_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4c, 0x4c, Some(0xf))
     }
 
-    pub fn cmovnl_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovnl_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -875,14 +1198,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4d, 0x4d, Some(0xf))
     }
 
-    pub fn cmovnl_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovnl_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4d, 0x4d, Some(0xf))
     }
 
-    pub fn cmovnle_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovnle_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -890,14 +1213,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4f, 0x4f, Some(0xf))
     }
 
-    pub fn cmovnle_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovnle_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4f, 0x4f, Some(0xf))
     }
 
-    pub fn cmovno_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovno_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -905,14 +1228,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x41, 0x41, Some(0xf))
     }
 
-    pub fn cmovno_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovno_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x41, 0x41, Some(0xf))
     }
 
-    pub fn cmovnp_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovnp_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -920,14 +1243,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4b, 0x4b, Some(0xf))
     }
 
-    pub fn cmovnp_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovnp_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4b, 0x4b, Some(0xf))
     }
 
-    pub fn cmovns_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovns_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -935,14 +1258,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x49, 0x49, Some(0xf))
     }
 
-    pub fn cmovns_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovns_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x49, 0x49, Some(0xf))
     }
 
-    pub fn cmovnz_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovnz_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -950,14 +1273,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x45, 0x45, Some(0xf))
     }
 
-    pub fn cmovnz_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovnz_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x45, 0x45, Some(0xf))
     }
 
-    pub fn cmovo_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovo_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -965,14 +1288,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x40, 0x40, Some(0xf))
     }
 
-    pub fn cmovo_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovo_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x40, 0x40, Some(0xf))
     }
 
-    pub fn cmovp_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovp_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -980,14 +1303,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4a, 0x4a, Some(0xf))
     }
 
-    pub fn cmovp_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovp_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4a, 0x4a, Some(0xf))
     }
 
-    pub fn cmovpe_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovpe_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -995,14 +1318,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4a, 0x4a, Some(0xf))
     }
 
-    pub fn cmovpe_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovpe_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4a, 0x4a, Some(0xf))
     }
 
-    pub fn cmovpo_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovpo_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1010,14 +1333,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x4b, 0x4b, Some(0xf))
     }
 
-    pub fn cmovpo_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovpo_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x4b, 0x4b, Some(0xf))
     }
 
-    pub fn cmovs_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovs_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1025,14 +1348,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x48, 0x48, Some(0xf))
     }
 
-    pub fn cmovs_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovs_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x48, 0x48, Some(0xf))
     }
 
-    pub fn cmovz_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmovz_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1040,14 +1363,30 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x44, 0x44, Some(0xf))
     }
 
-    pub fn cmovz_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmovz_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x44, 0x44, Some(0xf))
     }
 
-    pub fn cmp_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn cmp_hi8_mem<M: Memory<W8>>(&mut self, reg: Hi8, mem: M) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x3a, None, false)
+    }
+
+    pub fn cmp_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0x3a, None)
+    }
+
+    pub fn cmp_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0x3a, None)
+    }
+
+    pub fn cmp_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0x3a, None)
+    }
+
+    pub fn cmp_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1055,14 +1394,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x3a, 0x3b, None)
     }
 
-    pub fn cmp_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn cmp_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x3a, 0x3b, None)
     }
 
-    pub fn imul_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn imul_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1070,7 +1409,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0xaf, 0xaf, Some(0xf))
     }
 
-    pub fn imul_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn imul_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
@@ -1081,7 +1420,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         reg: R,
         mem: M,
-    ) -> io::Result<()>
+    ) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1093,14 +1432,14 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         reg1: R,
         reg2: R,
-    ) -> io::Result<()>
+    ) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x2, 0x2, Some(0xf))
     }
 
-    pub fn lea_reg_mem<Width: WidthAtLeast32, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn lea_reg_mem<Width: WidthAtLeast32, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1108,7 +1447,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x8d, 0x8d, None)
     }
 
-    pub fn lea_reg_reg<Width: WidthAtLeast32, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn lea_reg_reg<Width: WidthAtLeast32, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
@@ -1119,7 +1458,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         reg: R,
         mem: M,
-    ) -> io::Result<()>
+    ) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1131,120 +1470,318 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         &mut self,
         reg1: R,
         reg2: R,
-    ) -> io::Result<()>
+    ) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x3, 0x3, Some(0xf))
     }
 
-    pub fn mov_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn lzcnt_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
     {
-        self.op_rm_mr(reg, mem, 0x8a, 0x8b, None)
+        self.write_byte(0xf3)?;
+        self.op_rm_mr(reg, mem, 0xbd, 0xbd, Some(0xf))
     }
 
-    pub fn mov_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn lzcnt_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
-        self.op_reg_reg(reg1, reg2, 0x8a, 0x8b, None)
+        self.write_byte(0xf3)?;
+        self.op_reg_reg(reg1, reg2, 0xbd, 0xbd, Some(0xf))
+    }
+
+    pub fn mov_hi8_mem<M: Memory<W8>>(&mut self, reg: Hi8, mem: M) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x8a, None, false)
+    }
+
+    pub fn mov_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0x8a, None)
+    }
+
+    pub fn mov_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0x8a, None)
+    }
+
+    pub fn mov_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0x8a, None)
     }
 
-    pub fn or_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn mov_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
     {
-        self.op_rm_mr(reg, mem, 0xa, 0xb, None)
+        self.op_rm_mr(reg, mem, 0x8a, 0x8b, None)
     }
 
-    pub fn or_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn mov_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
-        self.op_reg_reg(reg1, reg2, 0xa, 0xb, None)
+        self.op_reg_reg(reg1, reg2, 0x8a, 0x8b, None)
+    }
+
+    pub fn or_hi8_mem<M: Memory<W8>>(&mut self, reg: Hi8, mem: M) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0xa, None, false)
+    }
+
+    pub fn or_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0xa, None)
+    }
+
+    pub fn or_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0xa, None)
+    }
+
+    pub fn or_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0xa, None)
     }
 
-    pub fn sbb_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn or_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
     {
-        self.op_rm_mr(reg, mem, 0x1a, 0x1b, None)
+        self.op_rm_mr(reg, mem, 0xa, 0xb, None)
     }
 
-    pub fn sbb_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn or_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
-        self.op_reg_reg(reg1, reg2, 0x1a, 0x1b, None)
+        self.op_reg_reg(reg1, reg2, 0xa, 0xb, None)
     }
 
-    pub fn sub_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn popcnt_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
     {
-        self.op_rm_mr(reg, mem, 0x2a, 0x2b, None)
+        self.write_byte(0xf3)?;
+        self.op_rm_mr(reg, mem, 0xb8, 0xb8, Some(0xf))
     }
 
-    pub fn sub_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn popcnt_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
-        self.op_reg_reg(reg1, reg2, 0x2a, 0x2b, None)
+        self.write_byte(0xf3)?;
+        self.op_reg_reg(reg1, reg2, 0xb8, 0xb8, Some(0xf))
+    }
+
+    pub fn sbb_hi8_mem<M: Memory<W8>>(&mut self, reg: Hi8, mem: M) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x1a, None, false)
+    }
+
+    pub fn sbb_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0x1a, None)
+    }
+
+    pub fn sbb_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0x1a, None)
+    }
+
+    pub fn sbb_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0x1a, None)
     }
 
-    pub fn xchg_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn sbb_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
     {
-        self.op_rm_mr(reg, mem, 0x86, 0x87, None)
+        self.op_rm_mr(reg, mem, 0x1a, 0x1b, None)
     }
 
-    pub fn xchg_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn sbb_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
-        self.op_reg_reg(reg1, reg2, 0x86, 0x87, None)
+        self.op_reg_reg(reg1, reg2, 0x1a, 0x1b, None)
+    }
+
+    pub fn sub_hi8_mem<M: Memory<W8>>(&mut self, reg: Hi8, mem: M) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x2a, None, false)
+    }
+
+    pub fn sub_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0x2a, None)
+    }
+
+    pub fn sub_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0x2a, None)
+    }
+
+    pub fn sub_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0x2a, None)
     }
 
-    pub fn xor_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> io::Result<()>
+    pub fn sub_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
     {
-        self.op_rm_mr(reg, mem, 0x32, 0x33, None)
+        self.op_rm_mr(reg, mem, 0x2a, 0x2b, None)
     }
 
-    pub fn xor_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn sub_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
-        self.op_reg_reg(reg1, reg2, 0x32, 0x33, None)
+        self.op_reg_reg(reg1, reg2, 0x2a, 0x2b, None)
     }
 
-    pub fn adc_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn tzcnt_reg_mem<Width: WidthAtLeast16, R, M>(&mut self, reg: R, mem: M) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
     {
-        self.op_rm_mr(reg, mem, 0x10, 0x11, None)
+        self.write_byte(0xf3)?;
+        self.op_rm_mr(reg, mem, 0xbc, 0xbc, Some(0xf))
     }
 
-    pub fn add_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn tzcnt_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
-        M: Memory<Width>,
+    {
+        self.write_byte(0xf3)?;
+        self.op_reg_reg(reg1, reg2, 0xbc, 0xbc, Some(0xf))
+    }
+
+    pub fn xchg_hi8_mem<M: Memory<W8>>(&mut self, reg: Hi8, mem: M) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x86, None, false)
+    }
+
+    pub fn xchg_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0x86, None)
+    }
+
+    pub fn xchg_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0x86, None)
+    }
+
+    pub fn xchg_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0x86, None)
+    }
+
+    pub fn xchg_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_rm_mr(reg, mem, 0x86, 0x87, None)
+    }
+
+    pub fn xchg_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+    {
+        self.op_reg_reg(reg1, reg2, 0x86, 0x87, None)
+    }
+
+    pub fn xor_hi8_mem<M: Memory<W8>>(&mut self, reg: Hi8, mem: M) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x32, None, false)
+    }
+
+    pub fn xor_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0x32, None)
+    }
+
+    pub fn xor_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0x32, None)
+    }
+
+    pub fn xor_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0x32, None)
+    }
+
+    pub fn xor_reg_mem<Width: WWidth, R, M>(&mut self, reg: R, mem: M) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_rm_mr(reg, mem, 0x32, 0x33, None)
+    }
+
+    pub fn xor_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+    {
+        self.op_reg_reg(reg1, reg2, 0x32, 0x33, None)
+    }
+
+    pub fn adc_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x10, None, false)
+    }
+
+    pub fn lock_adc_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x10, None, true)
+    }
+
+    pub fn lock_adc_mem_reg<Width: WWidth, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.adc_mem_reg(mem, reg)
+    }
+
+    pub fn adc_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_rm_mr(reg, mem, 0x10, 0x11, None)
+    }
+
+    pub fn add_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x0, None, false)
+    }
+
+    pub fn lock_add_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x0, None, true)
+    }
+
+    pub fn lock_add_mem_reg<Width: WWidth, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.add_mem_reg(mem, reg)
+    }
+
+    pub fn add_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
     {
         self.op_rm_mr(reg, mem, 0x0, 0x1, None)
     }
 
-    pub fn and_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn and_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x20, None, false)
+    }
+
+    pub fn lock_and_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x20, None, true)
+    }
+
+    pub fn lock_and_mem_reg<Width: WWidth, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.and_mem_reg(mem, reg)
+    }
+
+    pub fn and_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1252,7 +1789,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x20, 0x21, None)
     }
 
-    pub fn bt_mem_reg<Width: WidthAtLeast16, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn bt_mem_reg<Width: WidthAtLeast16, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1260,14 +1797,23 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0xa3, 0xa3, Some(0xf))
     }
 
-    pub fn bt_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn bt_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0xa3, 0xa3, Some(0xf))
     }
 
-    pub fn btc_mem_reg<Width: WidthAtLeast16, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn lock_btc_mem_reg<Width: WidthAtLeast16, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.btc_mem_reg(mem, reg)
+    }
+
+    pub fn btc_mem_reg<Width: WidthAtLeast16, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1275,14 +1821,23 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0xbb, 0xbb, Some(0xf))
     }
 
-    pub fn btc_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn btc_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0xbb, 0xbb, Some(0xf))
     }
 
-    pub fn btr_mem_reg<Width: WidthAtLeast16, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn lock_btr_mem_reg<Width: WidthAtLeast16, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.btr_mem_reg(mem, reg)
+    }
+
+    pub fn btr_mem_reg<Width: WidthAtLeast16, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1290,14 +1845,23 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0xb3, 0xb3, Some(0xf))
     }
 
-    pub fn btr_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn btr_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0xb3, 0xb3, Some(0xf))
     }
 
-    pub fn bts_mem_reg<Width: WidthAtLeast16, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn lock_bts_mem_reg<Width: WidthAtLeast16, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.bts_mem_reg(mem, reg)
+    }
+
+    pub fn bts_mem_reg<Width: WidthAtLeast16, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1305,14 +1869,18 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0xab, 0xab, Some(0xf))
     }
 
-    pub fn bts_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn bts_reg_reg<Width: WidthAtLeast16, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0xab, 0xab, Some(0xf))
     }
 
-    pub fn cmp_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn cmp_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x38, None, false)
+    }
+
+    pub fn cmp_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1320,7 +1888,55 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x38, 0x39, None)
     }
 
-    pub fn mov_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn cmpxchg_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0xb0, Some(0xf), false)
+    }
+
+    pub fn lock_cmpxchg_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0xb0, Some(0xf), true)
+    }
+
+    pub fn lock_cmpxchg_mem_reg<Width: WWidth, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.cmpxchg_mem_reg(mem, reg)
+    }
+
+    pub fn cmpxchg_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_rm_mr(reg, mem, 0xb0, 0xb1, Some(0xf))
+    }
+
+    pub fn cmpxchg_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0xb0, Some(0xf))
+    }
+
+    pub fn cmpxchg_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0xb0, Some(0xf))
+    }
+
+    pub fn cmpxchg_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0xb0, Some(0xf))
+    }
+
+    pub fn cmpxchg_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+    {
+        self.op_reg_reg(reg1, reg2, 0xb0, 0xb1, Some(0xf))
+    }
+
+    pub fn mov_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x88, None, false)
+    }
+
+    pub fn mov_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1328,7 +1944,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x88, 0x89, None)
     }
 
-    pub fn movnti_mem_reg<Width: WidthAtLeast32, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn movnti_mem_reg<Width: WidthAtLeast32, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1336,14 +1952,31 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0xc3, 0xc3, Some(0xf))
     }
 
-    pub fn movnti_reg_reg<Width: WidthAtLeast32, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn movnti_reg_reg<Width: WidthAtLeast32, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0xc3, 0xc3, Some(0xf))
     }
 
-    pub fn or_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn or_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x8, None, false)
+    }
+
+    pub fn lock_or_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x8, None, true)
+    }
+
+    pub fn lock_or_mem_reg<Width: WWidth, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.or_mem_reg(mem, reg)
+    }
+
+    pub fn or_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1351,7 +1984,24 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x8, 0x9, None)
     }
 
-    pub fn sbb_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn sbb_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x18, None, false)
+    }
+
+    pub fn lock_sbb_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x18, None, true)
+    }
+
+    pub fn lock_sbb_mem_reg<Width: WWidth, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.sbb_mem_reg(mem, reg)
+    }
+
+    pub fn sbb_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1359,7 +2009,24 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x18, 0x19, None)
     }
 
-    pub fn sub_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn sub_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x28, None, false)
+    }
+
+    pub fn lock_sub_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x28, None, true)
+    }
+
+    pub fn lock_sub_mem_reg<Width: WWidth, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.sub_mem_reg(mem, reg)
+    }
+
+    pub fn sub_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1367,7 +2034,11 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x28, 0x29, None)
     }
 
-    pub fn test_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn test_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x84, None, false)
+    }
+
+    pub fn test_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1375,14 +2046,43 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x84, 0x85, None)
     }
 
-    pub fn test_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn test_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0x84, None)
+    }
+
+    pub fn test_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0x84, None)
+    }
+
+    pub fn test_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0x84, None)
+    }
+
+    pub fn test_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0x84, 0x85, None)
     }
 
-    pub fn xadd_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn xadd_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0xc0, Some(0xf), false)
+    }
+
+    pub fn lock_xadd_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0xc0, Some(0xf), true)
+    }
+
+    pub fn lock_xadd_mem_reg<Width: WWidth, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.xadd_mem_reg(mem, reg)
+    }
+
+    pub fn xadd_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1390,14 +2090,30 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0xc0, 0xc1, Some(0xf))
     }
 
-    pub fn xadd_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> io::Result<()>
+    pub fn xadd_hi8_reg<R: GeneralRegister<W8>>(&mut self, reg1: Hi8, reg2: R) -> Result<()> {
+        self.op_hi8_reg(reg1, reg2.into(), 0xc0, Some(0xf))
+    }
+
+    pub fn xadd_reg_hi8<R: GeneralRegister<W8>>(&mut self, reg1: R, reg2: Hi8) -> Result<()> {
+        self.op_reg_hi8(reg1.into(), reg2, 0xc0, Some(0xf))
+    }
+
+    pub fn xadd_hi8_hi8(&mut self, reg1: Hi8, reg2: Hi8) -> Result<()> {
+        self.op_hi8_hi8(reg1, reg2, 0xc0, Some(0xf))
+    }
+
+    pub fn xadd_reg_reg<Width: WWidth, R>(&mut self, reg1: R, reg2: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
     {
         self.op_reg_reg(reg1, reg2, 0xc0, 0xc1, Some(0xf))
     }
 
-    pub fn xchg_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn xchg_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x86, None, false)
+    }
+
+    pub fn xchg_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1405,7 +2121,24 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x86, 0x87, None)
     }
 
-    pub fn xor_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> io::Result<()>
+    pub fn xor_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x30, None, false)
+    }
+
+    pub fn lock_xor_mem_hi8<M: Memory<W8>>(&mut self, mem: M, reg: Hi8) -> Result<()> {
+        self.op_hi8_mem(reg, mem.into(), 0x30, None, true)
+    }
+
+    pub fn lock_xor_mem_reg<Width: WWidth, R: GeneralRegister<Width>, M: Memory<Width>>(
+        &mut self,
+        mem: M,
+        reg: R,
+    ) -> Result<()> {
+        self.lock()?;
+        self.xor_mem_reg(mem, reg)
+    }
+
+    pub fn xor_mem_reg<Width: WWidth, R, M>(&mut self, mem: M, reg: R) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1413,147 +2146,147 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         self.op_rm_mr(reg, mem, 0x30, 0x31, None)
     }
 
-    pub fn clc(&mut self) -> io::Result<()> {
+    pub fn clc(&mut self) -> Result<()> {
         self.op_no_operands(0xf8, None)
     }
 
-    pub fn cld(&mut self) -> io::Result<()> {
+    pub fn cld(&mut self) -> Result<()> {
         self.op_no_operands(0xfc, None)
     }
 
-    pub fn cli(&mut self) -> io::Result<()> {
+    pub fn cli(&mut self) -> Result<()> {
         self.op_no_operands(0xfa, None)
     }
 
-    pub fn clts(&mut self) -> io::Result<()> {
+    pub fn clts(&mut self) -> Result<()> {
         self.op_no_operands(0x6, Some(0xf))
     }
 
-    pub fn cmc(&mut self) -> io::Result<()> {
+    pub fn cmc(&mut self) -> Result<()> {
         self.op_no_operands(0xf5, None)
     }
 
-    pub fn emms(&mut self) -> io::Result<()> {
+    pub fn emms(&mut self) -> Result<()> {
         self.op_no_operands(0x77, Some(0xf))
     }
 
-    pub fn femms(&mut self) -> io::Result<()> {
+    pub fn femms(&mut self) -> Result<()> {
         self.op_no_operands(0xe, Some(0xf))
     }
 
-    pub fn fwait(&mut self) -> io::Result<()> {
+    pub fn fwait(&mut self) -> Result<()> {
         self.op_no_operands(0xdb, None)
     }
 
-    pub fn getsec(&mut self) -> io::Result<()> {
+    pub fn getsec(&mut self) -> Result<()> {
         self.op_no_operands(0x37, Some(0xf))
     }
 
-    pub fn hlt(&mut self) -> io::Result<()> {
+    pub fn hlt(&mut self) -> Result<()> {
         self.op_no_operands(0xf4, None)
     }
 
-    pub fn int3(&mut self) -> io::Result<()> {
+    pub fn int3(&mut self) -> Result<()> {
         self.op_no_operands(0xcc, None)
     }
 
-    pub fn invd(&mut self) -> io::Result<()> {
+    pub fn invd(&mut self) -> Result<()> {
         self.op_no_operands(0x8, Some(0xf))
     }
 
-    pub fn iret(&mut self) -> io::Result<()> {
+    pub fn iret(&mut self) -> Result<()> {
         self.op_no_operands(0xcf, None)
     }
 
-    pub fn iretd(&mut self) -> io::Result<()> {
+    pub fn iretd(&mut self) -> Result<()> {
         self.op_no_operands(0xcf, None)
     }
 
-    pub fn iretq(&mut self) -> io::Result<()> {
+    pub fn iretq(&mut self) -> Result<()> {
         self.op_no_operands(0xcf, None)
     }
 
-    pub fn iretw(&mut self) -> io::Result<()> {
+    pub fn iretw(&mut self) -> Result<()> {
         self.op_no_operands(0xcf, None)
     }
 
-    pub fn leave(&mut self) -> io::Result<()> {
+    pub fn leave(&mut self) -> Result<()> {
         self.op_no_operands(0xc9, None)
     }
 
-    pub fn nop(&mut self) -> io::Result<()> {
+    pub fn nop(&mut self) -> Result<()> {
         self.op_no_operands(0x90, None)
     }
 
-    pub fn popf(&mut self) -> io::Result<()> {
+    pub fn popf(&mut self) -> Result<()> {
         self.op_no_operands(0x9d, None)
     }
 
-    pub fn popfq(&mut self) -> io::Result<()> {
+    pub fn popfq(&mut self) -> Result<()> {
         self.op_no_operands(0x9d, None)
     }
 
-    pub fn pushf(&mut self) -> io::Result<()> {
+    pub fn pushf(&mut self) -> Result<()> {
         self.op_no_operands(0x9c, None)
     }
 
-    pub fn pushfq(&mut self) -> io::Result<()> {
+    pub fn pushfq(&mut self) -> Result<()> {
         self.op_no_operands(0x9c, None)
     }
 
-    pub fn ret(&mut self) -> io::Result<()> {
+    pub fn ret(&mut self) -> Result<()> {
         self.op_no_operands(0xc3, None)
     }
 
-    pub fn stc(&mut self) -> io::Result<()> {
+    pub fn stc(&mut self) -> Result<()> {
         self.op_no_operands(0xf9, None)
     }
 
-    pub fn std(&mut self) -> io::Result<()> {
+    pub fn std(&mut self) -> Result<()> {
         self.op_no_operands(0xfd, None)
     }
 
-    pub fn sti(&mut self) -> io::Result<()> {
+    pub fn sti(&mut self) -> Result<()> {
         self.op_no_operands(0xfb, None)
     }
 
-    pub fn syscall(&mut self) -> io::Result<()> {
+    pub fn syscall(&mut self) -> Result<()> {
         self.op_no_operands(0x5, Some(0xf))
     }
 
-    pub fn sysenter(&mut self) -> io::Result<()> {
+    pub fn sysenter(&mut self) -> Result<()> {
         self.op_no_operands(0x34, Some(0xf))
     }
 
-    pub fn sysexit(&mut self) -> io::Result<()> {
+    pub fn sysexit(&mut self) -> Result<()> {
         self.op_no_operands(0x35, Some(0xf))
     }
 
-    pub fn sysexit64(&mut self) -> io::Result<()> {
+    pub fn sysexit64(&mut self) -> Result<()> {
         self.op_no_operands(0x35, Some(0xf))
     }
 
-    pub fn sysret(&mut self) -> io::Result<()> {
+    pub fn sysret(&mut self) -> Result<()> {
         self.op_no_operands(0x7, Some(0xf))
     }
 
-    pub fn sysret64(&mut self) -> io::Result<()> {
+    pub fn sysret64(&mut self) -> Result<()> {
         self.op_no_operands(0x7, Some(0xf))
     }
 
-    pub fn ud2(&mut self) -> io::Result<()> {
+    pub fn ud2(&mut self) -> Result<()> {
         self.op_no_operands(0xb, Some(0xf))
     }
 
-    pub fn wait(&mut self) -> io::Result<()> {
+    pub fn wait(&mut self) -> Result<()> {
         self.op_no_operands(0xdb, None)
     }
 
-    pub fn wbinvd(&mut self) -> io::Result<()> {
+    pub fn wbinvd(&mut self) -> Result<()> {
         self.op_no_operands(0x9, Some(0xf))
     }
 
-    pub fn xlatb(&mut self) -> io::Result<()> {
+    pub fn xlatb(&mut self) -> Result<()> {
         self.op_no_operands(0xd7, None)
     }
 
@@ -1562,7 +2295,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         rd: R,
         mem: M,
         rs: R,
-    ) -> io::Result<()>
+    ) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1575,7 +2308,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         rd: R,
         rs1: R,
         rs2: R,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_reg_reg(rd, rs1, rs2, 0x2, 0xf7, 0x0)
     }
 
@@ -1584,7 +2317,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         rd: R,
         mem: M,
         rs: R,
-    ) -> io::Result<()>
+    ) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1597,7 +2330,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         rd: R,
         rs1: R,
         rs2: R,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_reg_reg(rd, rs1, rs2, 0x2, 0xf5, 0x0)
     }
 
@@ -1606,7 +2339,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         rd: R,
         mem: M,
         rs: R,
-    ) -> io::Result<()>
+    ) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1619,7 +2352,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         rd: R,
         rs1: R,
         rs2: R,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_reg_reg(rd, rs1, rs2, 0x2, 0xf7, 0x2)
     }
 
@@ -1628,7 +2361,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         rd: R,
         mem: M,
         rs: R,
-    ) -> io::Result<()>
+    ) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1641,7 +2374,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         rd: R,
         rs1: R,
         rs2: R,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_reg_reg(rd, rs1, rs2, 0x2, 0xf7, 0x1)
     }
 
@@ -1650,7 +2383,7 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         rd: R,
         mem: M,
         rs: R,
-    ) -> io::Result<()>
+    ) -> Result<()>
     where
         R: GeneralRegister<Width>,
         M: Memory<Width>,
@@ -1663,341 +2396,14147 @@ impl<'a, T: io::Write + io::Seek> Assembler<'a, T> {
         rd: R,
         rs1: R,
         rs2: R,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg_reg_reg(rd, rs1, rs2, 0x2, 0xf7, 0x3)
     }
 
-    pub fn call_reg64<R: GeneralRegister<W64>>(&mut self, reg: R) -> io::Result<()> {
-        self.op_reg(reg, 0xff, 0xff, Some(0x2), None)
+    /// Stores the inverted second operand, and-ed with the last operand, in `rd`.
+    pub fn andn_reg_reg_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        rd: R,
+        rs1: R,
+        rs2: R,
+    ) -> Result<()> {
+        self.op_reg_reg_reg(rd, rs2, rs1, 0x2, 0xf2, 0x0)
     }
 
-    pub fn call_mem64<M: Memory<W64>>(&mut self, mem: M) -> io::Result<()> {
-        self.op_mem(mem, 0xff, 0xff, Some(0x2), None)
+    /// Stores the inverted second operand, and-ed with the last operand, in `rd`.
+    pub fn andn_reg_reg_mem<Width: WidthAtLeast32, R, M>(
+        &mut self,
+        rd: R,
+        rs: R,
+        mem: M,
+    ) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_reg_mem_reg(rd, mem, rs, 0x2, 0xf2, 0x0)
     }
 
-    pub fn dec_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> io::Result<()> {
-        self.op_reg(reg, 0xfe, 0xff, Some(0x1), None)
+    /// Unsigned multiply of `edx` or `rdx` by the last operand without affecting flags, the high half goes in `rd` and the low half in the second operand.
+    pub fn mulx_reg_reg_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        rd: R,
+        rs1: R,
+        rs2: R,
+    ) -> Result<()> {
+        self.op_reg_reg_reg(rd, rs2, rs1, 0x2, 0xf6, 0x3)
     }
 
-    pub fn dec_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> io::Result<()> {
-        self.op_mem(mem, 0xfe, 0xff, Some(0x1), None)
+    /// Unsigned multiply of `edx` or `rdx` by the last operand without affecting flags, the high half goes in `rd` and the low half in the second operand.
+    pub fn mulx_reg_reg_mem<Width: WidthAtLeast32, R, M>(
+        &mut self,
+        rd: R,
+        rs: R,
+        mem: M,
+    ) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_reg_mem_reg(rd, mem, rs, 0x2, 0xf6, 0x3)
     }
 
-    pub fn inc_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> io::Result<()> {
-        self.op_reg(reg, 0xfe, 0xff, Some(0x0), None)
+    /// Deposits the low bits of the second operand into the bits of `rd` selected by the mask in the last operand, the rest are zeroed.
+    pub fn pdep_reg_reg_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        rd: R,
+        rs1: R,
+        rs2: R,
+    ) -> Result<()> {
+        self.op_reg_reg_reg(rd, rs2, rs1, 0x2, 0xf5, 0x3)
     }
 
-    pub fn inc_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> io::Result<()> {
-        self.op_mem(mem, 0xfe, 0xff, Some(0x0), None)
+    /// Deposits the low bits of the second operand into the bits of `rd` selected by the mask in the last operand, the rest are zeroed.
+    pub fn pdep_reg_reg_mem<Width: WidthAtLeast32, R, M>(
+        &mut self,
+        rd: R,
+        rs: R,
+        mem: M,
+    ) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_reg_mem_reg(rd, mem, rs, 0x2, 0xf5, 0x3)
     }
 
-    pub fn lldt_reg16<R: GeneralRegister<W16>>(&mut self, reg: R) -> io::Result<()> {
-        self.op_reg(reg, 0x0, 0x0, Some(0x2), Some(0xf))
+    /// Extracts the bits of the second operand selected by the mask in the last operand into the low bits of `rd`.
+    pub fn pext_reg_reg_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        rd: R,
+        rs1: R,
+        rs2: R,
+    ) -> Result<()> {
+        self.op_reg_reg_reg(rd, rs2, rs1, 0x2, 0xf5, 0x2)
     }
 
-    pub fn lldt_mem16<M: Memory<W16>>(&mut self, mem: M) -> io::Result<()> {
-        self.op_mem(mem, 0x0, 0x0, Some(0x2), Some(0xf))
+    /// Extracts the bits of the second operand selected by the mask in the last operand into the low bits of `rd`.
+    pub fn pext_reg_reg_mem<Width: WidthAtLeast32, R, M>(
+        &mut self,
+        rd: R,
+        rs: R,
+        mem: M,
+    ) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_reg_mem_reg(rd, mem, rs, 0x2, 0xf5, 0x2)
     }
 
-    pub fn lmsw_reg16<R: GeneralRegister<W16>>(&mut self, reg: R) -> io::Result<()> {
-        self.op_reg(reg, 0x1, 0x1, Some(0x6), Some(0xf))
+    /// Isolates the lowest set bit of the source into `rd`.
+    pub fn blsi_reg_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        rd: R,
+        rs: R,
+    ) -> Result<()> {
+        self.op_vex_reg_rm::<Width>(0, 2, 0xf3, Some(0x3), rd.into(), rs.into(), None)
     }
 
-    pub fn lmsw_mem16<M: Memory<W16>>(&mut self, mem: M) -> io::Result<()> {
-        self.op_mem(mem, 0x1, 0x1, Some(0x6), Some(0xf))
+    /// Isolates the lowest set bit of the source into `rd`.
+    pub fn blsi_reg_mem<Width: WidthAtLeast32, R, M>(&mut self, rd: R, mem: M) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_vex_reg_mem::<Width>(0, 2, 0xf3, Some(0x3), rd.into(), mem.into(), None)
     }
 
-    pub fn ltr_reg16<R: GeneralRegister<W16>>(&mut self, reg: R) -> io::Result<()> {
-        self.op_reg(reg, 0x0, 0x0, Some(0x3), Some(0xf))
-    }
+    /// Sets `rd` to a mask up to and including the lowest set bit of the source.
+    pub fn blsmsk_reg_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        rd: R,
+        rs: R,
+    ) -> Result<()> {
+        self.op_vex_reg_rm::<Width>(0, 2, 0xf3, Some(0x2), rd.into(), rs.into(), None)
+    }
+
+    /// Sets `rd` to a mask up to and including the lowest set bit of the source.
+    pub fn blsmsk_reg_mem<Width: WidthAtLeast32, R, M>(&mut self, rd: R, mem: M) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_vex_reg_mem::<Width>(0, 2, 0xf3, Some(0x2), rd.into(), mem.into(), None)
+    }
+
+    /// Stores the source with its lowest set bit cleared in `rd`.
+    pub fn blsr_reg_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        rd: R,
+        rs: R,
+    ) -> Result<()> {
+        self.op_vex_reg_rm::<Width>(0, 2, 0xf3, Some(0x1), rd.into(), rs.into(), None)
+    }
+
+    /// Stores the source with its lowest set bit cleared in `rd`.
+    pub fn blsr_reg_mem<Width: WidthAtLeast32, R, M>(&mut self, rd: R, mem: M) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_vex_reg_mem::<Width>(0, 2, 0xf3, Some(0x1), rd.into(), mem.into(), None)
+    }
+
+    /// Rotates the source right by `imm` into `rd` without affecting flags.
+    pub fn rorx_reg_reg_imm8<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        rd: R,
+        rs: R,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_vex_reg_rm::<Width>(3, 3, 0xf0, None, rd.into(), rs.into(), Some(imm))
+    }
+
+    /// Rotates the source right by `imm` into `rd` without affecting flags.
+    pub fn rorx_reg_mem_imm8<Width: WidthAtLeast32, R, M>(
+        &mut self,
+        rd: R,
+        mem: M,
+        imm: u8,
+    ) -> Result<()>
+    where
+        R: GeneralRegister<Width>,
+        M: Memory<Width>,
+    {
+        self.op_vex_reg_mem::<Width>(3, 3, 0xf0, None, rd.into(), mem.into(), Some(imm))
+    }
+
+    pub fn call_reg64<R: GeneralRegister<W64>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xff, 0xff, Some(0x2), None)
+    }
+
+    pub fn call_mem64<M: Memory<W64>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xff, 0xff, Some(0x2), None)
+    }
+
+    pub fn dec_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xfe, Some(0x1), None, None)
+    }
+
+    pub fn lock_dec_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.lock()?;
+        self.dec_mem(mem)
+    }
+
+    pub fn dec_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xfe, 0xff, Some(0x1), None)
+    }
+
+    pub fn dec_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xfe, 0xff, Some(0x1), None)
+    }
+
+    pub fn div_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xf6, Some(0x6), None, None)
+    }
+
+    /// Unsigned divide of `ax`, `dx:ax`, `edx:eax` or `rdx:rax` by the operand, the quotient goes in `al`, `ax`, `eax` or `rax` and the remainder in `ah`, `dx`, `edx` or `rdx`.
+    pub fn div_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xf6, 0xf7, Some(0x6), None)
+    }
+
+    /// Unsigned divide of `ax`, `dx:ax`, `edx:eax` or `rdx:rax` by the operand, the quotient goes in `al`, `ax`, `eax` or `rax` and the remainder in `ah`, `dx`, `edx` or `rdx`.
+    pub fn div_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xf6, 0xf7, Some(0x6), None)
+    }
+
+    pub fn idiv_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xf6, Some(0x7), None, None)
+    }
+
+    /// Signed divide of `ax`, `dx:ax`, `edx:eax` or `rdx:rax` by the operand, the quotient goes in `al`, `ax`, `eax` or `rax` and the remainder in `ah`, `dx`, `edx` or `rdx`.
+    pub fn idiv_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xf6, 0xf7, Some(0x7), None)
+    }
+
+    /// Signed divide of `ax`, `dx:ax`, `edx:eax` or `rdx:rax` by the operand, the quotient goes in `al`, `ax`, `eax` or `rax` and the remainder in `ah`, `dx`, `edx` or `rdx`.
+    pub fn idiv_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xf6, 0xf7, Some(0x7), None)
+    }
+
+    pub fn imul_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xf6, Some(0x5), None, None)
+    }
+
+    /// Signed multiply of `al`, `ax`, `eax` or `rax` by the operand, the result goes in `ax`, `dx:ax`, `edx:eax` or `rdx:rax`.
+    pub fn imul_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xf6, 0xf7, Some(0x5), None)
+    }
+
+    /// Signed multiply of `al`, `ax`, `eax` or `rax` by the operand, the result goes in `ax`, `dx:ax`, `edx:eax` or `rdx:rax`.
+    pub fn imul_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xf6, 0xf7, Some(0x5), None)
+    }
+
+    pub fn inc_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xfe, Some(0x0), None, None)
+    }
+
+    pub fn lock_inc_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.lock()?;
+        self.inc_mem(mem)
+    }
+
+    pub fn inc_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xfe, 0xff, Some(0x0), None)
+    }
+
+    pub fn inc_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xfe, 0xff, Some(0x0), None)
+    }
+
+    pub fn lldt_reg16<R: GeneralRegister<W16>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0x0, 0x0, Some(0x2), Some(0xf))
+    }
+
+    pub fn lldt_mem16<M: Memory<W16>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0x0, 0x0, Some(0x2), Some(0xf))
+    }
+
+    pub fn lmsw_reg16<R: GeneralRegister<W16>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0x1, 0x1, Some(0x6), Some(0xf))
+    }
+
+    pub fn lmsw_mem16<M: Memory<W16>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0x1, 0x1, Some(0x6), Some(0xf))
+    }
+
+    pub fn ltr_reg16<R: GeneralRegister<W16>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0x0, 0x0, Some(0x3), Some(0xf))
+    }
 
-    pub fn ltr_mem16<M: Memory<W16>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn ltr_mem16<M: Memory<W16>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x0, 0x0, Some(0x3), Some(0xf))
     }
 
-    pub fn neg_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn mul_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xf6, Some(0x4), None, None)
+    }
+
+    /// Unsigned multiply of `al`, `ax`, `eax` or `rax` by the operand, the result goes in `ax`, `dx:ax`, `edx:eax` or `rdx:rax`.
+    pub fn mul_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xf6, 0xf7, Some(0x4), None)
+    }
+
+    /// Unsigned multiply of `al`, `ax`, `eax` or `rax` by the operand, the result goes in `ax`, `dx:ax`, `edx:eax` or `rdx:rax`.
+    pub fn mul_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xf6, 0xf7, Some(0x4), None)
+    }
+
+    pub fn neg_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xf6, Some(0x3), None, None)
+    }
+
+    pub fn lock_neg_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.lock()?;
+        self.neg_mem(mem)
+    }
+
+    pub fn neg_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0xf6, 0xf7, Some(0x3), None)
     }
 
-    pub fn neg_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn neg_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0xf6, 0xf7, Some(0x3), None)
     }
 
     pub fn nop_reg<Width: WidthAtLeast16 + WidthAtMost32, R: GeneralRegister<Width>>(
         &mut self,
         reg: R,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_reg(reg, 0x1f, 0x1f, Some(0x0), Some(0xf))
     }
 
     pub fn nop_mem<Width: WidthAtLeast16 + WidthAtMost32, M: Memory<Width>>(
         &mut self,
         mem: M,
-    ) -> io::Result<()> {
+    ) -> Result<()> {
         self.op_mem(mem, 0x1f, 0x1f, Some(0x0), Some(0xf))
     }
 
-    pub fn not_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn not_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xf6, Some(0x2), None, None)
+    }
+
+    pub fn lock_not_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.lock()?;
+        self.not_mem(mem)
+    }
+
+    pub fn not_reg<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0xf6, 0xf7, Some(0x2), None)
     }
 
-    pub fn not_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn not_mem<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0xf6, 0xf7, Some(0x2), None)
     }
 
-    pub fn seta_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn seta_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x97, None, Some(0xf), None)
+    }
+
+    pub fn seta_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x97, 0x97, None, Some(0xf))
     }
 
-    pub fn seta_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn seta_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x97, 0x97, None, Some(0xf))
     }
 
-    pub fn setae_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setae_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x93, None, Some(0xf), None)
+    }
+
+    pub fn setae_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x93, 0x93, None, Some(0xf))
     }
 
-    pub fn setae_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setae_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x93, 0x93, None, Some(0xf))
     }
 
-    pub fn setb_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setb_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x92, None, Some(0xf), None)
+    }
+
+    pub fn setb_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x92, 0x92, None, Some(0xf))
     }
 
-    pub fn setb_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setb_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x92, 0x92, None, Some(0xf))
     }
 
-    pub fn setbe_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setbe_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x96, None, Some(0xf), None)
+    }
+
+    pub fn setbe_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x96, 0x96, None, Some(0xf))
     }
 
-    pub fn setbe_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setbe_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x96, 0x96, None, Some(0xf))
     }
 
-    pub fn setc_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setc_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x92, None, Some(0xf), None)
+    }
+
+    pub fn setc_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x92, 0x92, None, Some(0xf))
     }
 
-    pub fn setc_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setc_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x92, 0x92, None, Some(0xf))
     }
 
-    pub fn sete_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn sete_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x94, None, Some(0xf), None)
+    }
+
+    pub fn sete_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x94, 0x94, None, Some(0xf))
     }
 
-    pub fn sete_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn sete_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x94, 0x94, None, Some(0xf))
     }
 
-    pub fn setg_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setg_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9f, None, Some(0xf), None)
+    }
+
+    pub fn setg_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9f, 0x9f, None, Some(0xf))
     }
 
-    pub fn setg_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setg_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9f, 0x9f, None, Some(0xf))
     }
 
-    pub fn setge_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setge_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9d, None, Some(0xf), None)
+    }
+
+    pub fn setge_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9d, 0x9d, None, Some(0xf))
     }
 
-    pub fn setge_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setge_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9d, 0x9d, None, Some(0xf))
     }
 
-    pub fn setl_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setl_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9c, None, Some(0xf), None)
+    }
+
+    pub fn setl_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9c, 0x9c, None, Some(0xf))
     }
 
-    pub fn setl_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setl_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9c, 0x9c, None, Some(0xf))
     }
 
-    pub fn setle_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setle_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9e, None, Some(0xf), None)
+    }
+
+    pub fn setle_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9e, 0x9e, None, Some(0xf))
     }
 
-    pub fn setle_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setle_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9e, 0x9e, None, Some(0xf))
     }
 
-    pub fn setna_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setna_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x96, None, Some(0xf), None)
+    }
+
+    pub fn setna_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x96, 0x96, None, Some(0xf))
     }
 
-    pub fn setna_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setna_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x96, 0x96, None, Some(0xf))
     }
 
-    pub fn setnae_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setnae_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x92, None, Some(0xf), None)
+    }
+
+    pub fn setnae_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x92, 0x92, None, Some(0xf))
     }
 
-    pub fn setnae_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setnae_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x92, 0x92, None, Some(0xf))
     }
 
-    pub fn setnb_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setnb_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x93, None, Some(0xf), None)
+    }
+
+    pub fn setnb_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x93, 0x93, None, Some(0xf))
     }
 
-    pub fn setnb_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setnb_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x93, 0x93, None, Some(0xf))
     }
 
-    pub fn setnbe_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setnbe_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x97, None, Some(0xf), None)
+    }
+
+    pub fn setnbe_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x97, 0x97, None, Some(0xf))
     }
 
-    pub fn setnbe_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setnbe_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x97, 0x97, None, Some(0xf))
     }
 
-    pub fn setnc_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setnc_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x93, None, Some(0xf), None)
+    }
+
+    pub fn setnc_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x93, 0x93, None, Some(0xf))
     }
 
-    pub fn setnc_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setnc_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x93, 0x93, None, Some(0xf))
     }
 
-    pub fn setne_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setne_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x95, None, Some(0xf), None)
+    }
+
+    pub fn setne_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x95, 0x95, None, Some(0xf))
     }
 
-    pub fn setne_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setne_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x95, 0x95, None, Some(0xf))
     }
 
-    pub fn setng_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setng_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9e, None, Some(0xf), None)
+    }
+
+    pub fn setng_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9e, 0x9e, None, Some(0xf))
     }
 
-    pub fn setng_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setng_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9e, 0x9e, None, Some(0xf))
     }
 
-    pub fn setnge_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setnge_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9c, None, Some(0xf), None)
+    }
+
+    pub fn setnge_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9c, 0x9c, None, Some(0xf))
     }
 
-    pub fn setnge_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setnge_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9c, 0x9c, None, Some(0xf))
     }
 
-    pub fn setnl_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setnl_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9d, None, Some(0xf), None)
+    }
+
+    pub fn setnl_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9d, 0x9d, None, Some(0xf))
     }
 
-    pub fn setnl_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setnl_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9d, 0x9d, None, Some(0xf))
     }
 
-    pub fn setnle_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setnle_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9f, None, Some(0xf), None)
+    }
+
+    pub fn setnle_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9f, 0x9f, None, Some(0xf))
     }
 
-    pub fn setnle_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setnle_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9f, 0x9f, None, Some(0xf))
     }
 
-    pub fn setno_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setno_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x91, None, Some(0xf), None)
+    }
+
+    pub fn setno_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x91, 0x91, None, Some(0xf))
     }
 
-    pub fn setno_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setno_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x91, 0x91, None, Some(0xf))
     }
 
-    pub fn setnp_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setnp_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9b, None, Some(0xf), None)
+    }
+
+    pub fn setnp_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9b, 0x9b, None, Some(0xf))
     }
 
-    pub fn setnp_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setnp_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9b, 0x9b, None, Some(0xf))
     }
 
-    pub fn setns_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setns_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x99, None, Some(0xf), None)
+    }
+
+    pub fn setns_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x99, 0x99, None, Some(0xf))
     }
 
-    pub fn setns_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setns_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x99, 0x99, None, Some(0xf))
     }
 
-    pub fn setnz_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setnz_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x95, None, Some(0xf), None)
+    }
+
+    pub fn setnz_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x95, 0x95, None, Some(0xf))
     }
 
-    pub fn setnz_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setnz_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x95, 0x95, None, Some(0xf))
     }
 
-    pub fn seto_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn seto_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x90, None, Some(0xf), None)
+    }
+
+    pub fn seto_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x90, 0x90, None, Some(0xf))
     }
 
-    pub fn seto_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn seto_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x90, 0x90, None, Some(0xf))
     }
 
-    pub fn setp_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setp_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9a, None, Some(0xf), None)
+    }
+
+    pub fn setp_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9a, 0x9a, None, Some(0xf))
     }
 
-    pub fn setp_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setp_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9a, 0x9a, None, Some(0xf))
     }
 
-    pub fn setpe_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setpe_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9a, None, Some(0xf), None)
+    }
+
+    pub fn setpe_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9a, 0x9a, None, Some(0xf))
     }
 
-    pub fn setpe_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setpe_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9a, 0x9a, None, Some(0xf))
     }
 
-    pub fn setpo_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setpo_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x9b, None, Some(0xf), None)
+    }
+
+    pub fn setpo_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x9b, 0x9b, None, Some(0xf))
     }
 
-    pub fn setpo_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setpo_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x9b, 0x9b, None, Some(0xf))
     }
 
-    pub fn sets_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn sets_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x98, None, Some(0xf), None)
+    }
+
+    pub fn sets_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x98, 0x98, None, Some(0xf))
     }
 
-    pub fn sets_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn sets_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x98, 0x98, None, Some(0xf))
     }
 
-    pub fn setz_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn setz_hi8(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0x94, None, Some(0xf), None)
+    }
+
+    pub fn setz_reg8<R: GeneralRegister<W8>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x94, 0x94, None, Some(0xf))
     }
 
-    pub fn setz_mem8<M: Memory<W8>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn setz_mem8<M: Memory<W8>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x94, 0x94, None, Some(0xf))
     }
 
-    pub fn verr_reg16<R: GeneralRegister<W16>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn verr_reg16<R: GeneralRegister<W16>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x0, 0x0, Some(0x4), Some(0xf))
     }
 
-    pub fn verr_mem16<M: Memory<W16>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn verr_mem16<M: Memory<W16>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x0, 0x0, Some(0x4), Some(0xf))
     }
 
-    pub fn verw_reg16<R: GeneralRegister<W16>>(&mut self, reg: R) -> io::Result<()> {
+    pub fn verw_reg16<R: GeneralRegister<W16>>(&mut self, reg: R) -> Result<()> {
         self.op_reg(reg, 0x0, 0x0, Some(0x5), Some(0xf))
     }
 
-    pub fn verw_mem16<M: Memory<W16>>(&mut self, mem: M) -> io::Result<()> {
+    pub fn verw_mem16<M: Memory<W16>>(&mut self, mem: M) -> Result<()> {
         self.op_mem(mem, 0x0, 0x0, Some(0x5), Some(0xf))
     }
+
+    pub fn rcl_hi8_1(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd0, Some(0x2), None, None)
+    }
+
+    pub fn rcl_reg_1<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd0, 0xd1, Some(0x2), None)
+    }
+
+    pub fn rcl_mem_1<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd0, 0xd1, Some(0x2), None)
+    }
+
+    pub fn rcr_hi8_1(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd0, Some(0x3), None, None)
+    }
+
+    pub fn rcr_reg_1<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd0, 0xd1, Some(0x3), None)
+    }
+
+    pub fn rcr_mem_1<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd0, 0xd1, Some(0x3), None)
+    }
+
+    pub fn rol_hi8_1(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd0, Some(0x0), None, None)
+    }
+
+    pub fn rol_reg_1<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd0, 0xd1, Some(0x0), None)
+    }
+
+    pub fn rol_mem_1<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd0, 0xd1, Some(0x0), None)
+    }
+
+    pub fn ror_hi8_1(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd0, Some(0x1), None, None)
+    }
+
+    pub fn ror_reg_1<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd0, 0xd1, Some(0x1), None)
+    }
+
+    pub fn ror_mem_1<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd0, 0xd1, Some(0x1), None)
+    }
+
+    pub fn sal_hi8_1(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd0, Some(0x4), None, None)
+    }
+
+    pub fn sal_reg_1<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd0, 0xd1, Some(0x4), None)
+    }
+
+    pub fn sal_mem_1<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd0, 0xd1, Some(0x4), None)
+    }
+
+    pub fn sar_hi8_1(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd0, Some(0x7), None, None)
+    }
+
+    pub fn sar_reg_1<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd0, 0xd1, Some(0x7), None)
+    }
+
+    pub fn sar_mem_1<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd0, 0xd1, Some(0x7), None)
+    }
+
+    pub fn shl_hi8_1(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd0, Some(0x4), None, None)
+    }
+
+    pub fn shl_reg_1<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd0, 0xd1, Some(0x4), None)
+    }
+
+    pub fn shl_mem_1<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd0, 0xd1, Some(0x4), None)
+    }
+
+    pub fn shr_hi8_1(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd0, Some(0x5), None, None)
+    }
+
+    pub fn shr_reg_1<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd0, 0xd1, Some(0x5), None)
+    }
+
+    pub fn shr_mem_1<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd0, 0xd1, Some(0x5), None)
+    }
+
+    pub fn rcl_hi8_cl(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd2, Some(0x2), None, None)
+    }
+
+    pub fn rcl_reg_cl<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd2, 0xd3, Some(0x2), None)
+    }
+
+    pub fn rcl_mem_cl<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd2, 0xd3, Some(0x2), None)
+    }
+
+    pub fn rcr_hi8_cl(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd2, Some(0x3), None, None)
+    }
+
+    pub fn rcr_reg_cl<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd2, 0xd3, Some(0x3), None)
+    }
+
+    pub fn rcr_mem_cl<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd2, 0xd3, Some(0x3), None)
+    }
+
+    pub fn rol_hi8_cl(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd2, Some(0x0), None, None)
+    }
+
+    pub fn rol_reg_cl<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd2, 0xd3, Some(0x0), None)
+    }
+
+    pub fn rol_mem_cl<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd2, 0xd3, Some(0x0), None)
+    }
+
+    pub fn ror_hi8_cl(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd2, Some(0x1), None, None)
+    }
+
+    pub fn ror_reg_cl<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd2, 0xd3, Some(0x1), None)
+    }
+
+    pub fn ror_mem_cl<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd2, 0xd3, Some(0x1), None)
+    }
+
+    pub fn sal_hi8_cl(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd2, Some(0x4), None, None)
+    }
+
+    pub fn sal_reg_cl<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd2, 0xd3, Some(0x4), None)
+    }
+
+    pub fn sal_mem_cl<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd2, 0xd3, Some(0x4), None)
+    }
+
+    pub fn sar_hi8_cl(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd2, Some(0x7), None, None)
+    }
+
+    pub fn sar_reg_cl<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd2, 0xd3, Some(0x7), None)
+    }
+
+    pub fn sar_mem_cl<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd2, 0xd3, Some(0x7), None)
+    }
+
+    pub fn shl_hi8_cl(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd2, Some(0x4), None, None)
+    }
+
+    pub fn shl_reg_cl<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd2, 0xd3, Some(0x4), None)
+    }
+
+    pub fn shl_mem_cl<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd2, 0xd3, Some(0x4), None)
+    }
+
+    pub fn shr_hi8_cl(&mut self, reg: Hi8) -> Result<()> {
+        self.op_hi8_rm_bits(reg, 0xd2, Some(0x5), None, None)
+    }
+
+    pub fn shr_reg_cl<Width: WWidth, R: GeneralRegister<Width>>(&mut self, reg: R) -> Result<()> {
+        self.op_reg(reg, 0xd2, 0xd3, Some(0x5), None)
+    }
+
+    pub fn shr_mem_cl<Width: WWidth, M: Memory<Width>>(&mut self, mem: M) -> Result<()> {
+        self.op_mem(mem, 0xd2, 0xd3, Some(0x5), None)
+    }
+
+    /// Moves the 8 bit source into `reg`, sign extending it.
+    pub fn movsx_reg_reg8<
+        Width: WidthAtLeast16,
+        R: GeneralRegister<Width>,
+        S: GeneralRegister<W8>,
+    >(
+        &mut self,
+        reg: R,
+        src: S,
+    ) -> Result<()> {
+        self.op_extend_reg(reg, src, 0xbe, Some(0xf))
+    }
+
+    /// Moves the 8 bit source into `reg`, sign extending it.
+    pub fn movsx_reg_mem8<Width: WidthAtLeast16, R: GeneralRegister<Width>, M: Memory<W8>>(
+        &mut self,
+        reg: R,
+        mem: M,
+    ) -> Result<()> {
+        self.op_extend_mem(reg, mem, 0xbe, Some(0xf))
+    }
+
+    /// Moves the 8 bit source into `reg`, sign extending it.
+    pub fn movsx_reg_hi8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
+        &mut self,
+        reg: R,
+        src: Hi8,
+    ) -> Result<()> {
+        self.op_extend_hi8(reg, src, 0xbe, Some(0xf))
+    }
+
+    /// Moves the 16 bit source into `reg`, sign extending it.
+    pub fn movsx_reg_reg16<
+        Width: WidthAtLeast32,
+        R: GeneralRegister<Width>,
+        S: GeneralRegister<W16>,
+    >(
+        &mut self,
+        reg: R,
+        src: S,
+    ) -> Result<()> {
+        self.op_extend_reg(reg, src, 0xbf, Some(0xf))
+    }
+
+    /// Moves the 16 bit source into `reg`, sign extending it.
+    pub fn movsx_reg_mem16<Width: WidthAtLeast32, R: GeneralRegister<Width>, M: Memory<W16>>(
+        &mut self,
+        reg: R,
+        mem: M,
+    ) -> Result<()> {
+        self.op_extend_mem(reg, mem, 0xbf, Some(0xf))
+    }
+
+    /// Moves the 32 bit source into `reg`, sign extending it.
+    pub fn movsxd_reg_reg32<R: GeneralRegister<W64>, S: GeneralRegister<W32>>(
+        &mut self,
+        reg: R,
+        src: S,
+    ) -> Result<()> {
+        self.op_extend_reg(reg, src, 0x63, None)
+    }
+
+    /// Moves the 32 bit source into `reg`, sign extending it.
+    pub fn movsxd_reg_mem32<R: GeneralRegister<W64>, M: Memory<W32>>(
+        &mut self,
+        reg: R,
+        mem: M,
+    ) -> Result<()> {
+        self.op_extend_mem(reg, mem, 0x63, None)
+    }
+
+    /// Moves the 8 bit source into `reg`, zero extending it.
+    pub fn movzx_reg_reg8<
+        Width: WidthAtLeast16,
+        R: GeneralRegister<Width>,
+        S: GeneralRegister<W8>,
+    >(
+        &mut self,
+        reg: R,
+        src: S,
+    ) -> Result<()> {
+        self.op_extend_reg(reg, src, 0xb6, Some(0xf))
+    }
+
+    /// Moves the 8 bit source into `reg`, zero extending it.
+    pub fn movzx_reg_mem8<Width: WidthAtLeast16, R: GeneralRegister<Width>, M: Memory<W8>>(
+        &mut self,
+        reg: R,
+        mem: M,
+    ) -> Result<()> {
+        self.op_extend_mem(reg, mem, 0xb6, Some(0xf))
+    }
+
+    /// Moves the 8 bit source into `reg`, zero extending it.
+    pub fn movzx_reg_hi8<Width: WidthAtLeast16, R: GeneralRegister<Width>>(
+        &mut self,
+        reg: R,
+        src: Hi8,
+    ) -> Result<()> {
+        self.op_extend_hi8(reg, src, 0xb6, Some(0xf))
+    }
+
+    /// Moves the 16 bit source into `reg`, zero extending it.
+    pub fn movzx_reg_reg16<
+        Width: WidthAtLeast32,
+        R: GeneralRegister<Width>,
+        S: GeneralRegister<W16>,
+    >(
+        &mut self,
+        reg: R,
+        src: S,
+    ) -> Result<()> {
+        self.op_extend_reg(reg, src, 0xb7, Some(0xf))
+    }
+
+    /// Moves the 16 bit source into `reg`, zero extending it.
+    pub fn movzx_reg_mem16<Width: WidthAtLeast32, R: GeneralRegister<Width>, M: Memory<W16>>(
+        &mut self,
+        reg: R,
+        mem: M,
+    ) -> Result<()> {
+        self.op_extend_mem(reg, mem, 0xb7, Some(0xf))
+    }
+
+    /// Copies from `[rsi]` to `[rdi]`, then steps both by the operand size (backwards if DF is set).
+    pub fn movsb(&mut self) -> Result<()> {
+        self.op_string::<W8>(None, 0xa4, 0xa5)
+    }
+
+    /// Repeats [`movsb`](Self::movsb) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_movsb(&mut self) -> Result<()> {
+        self.op_string::<W8>(Some(0xf3), 0xa4, 0xa5)
+    }
+
+    /// Copies from `[rsi]` to `[rdi]`, then steps both by the operand size (backwards if DF is set).
+    pub fn movsw(&mut self) -> Result<()> {
+        self.op_string::<W16>(None, 0xa4, 0xa5)
+    }
+
+    /// Repeats [`movsw`](Self::movsw) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_movsw(&mut self) -> Result<()> {
+        self.op_string::<W16>(Some(0xf3), 0xa4, 0xa5)
+    }
+
+    /// Copies from `[rsi]` to `[rdi]`, then steps both by the operand size (backwards if DF is set).
+    pub fn movsd(&mut self) -> Result<()> {
+        self.op_string::<W32>(None, 0xa4, 0xa5)
+    }
+
+    /// Repeats [`movsd`](Self::movsd) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_movsd(&mut self) -> Result<()> {
+        self.op_string::<W32>(Some(0xf3), 0xa4, 0xa5)
+    }
+
+    /// Copies from `[rsi]` to `[rdi]`, then steps both by the operand size (backwards if DF is set).
+    pub fn movsq(&mut self) -> Result<()> {
+        self.op_string::<W64>(None, 0xa4, 0xa5)
+    }
+
+    /// Repeats [`movsq`](Self::movsq) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_movsq(&mut self) -> Result<()> {
+        self.op_string::<W64>(Some(0xf3), 0xa4, 0xa5)
+    }
+
+    /// Stores `al`, `ax`, `eax` or `rax` to `[rdi]`, then steps it by the operand size (backwards if DF is set).
+    pub fn stosb(&mut self) -> Result<()> {
+        self.op_string::<W8>(None, 0xaa, 0xab)
+    }
+
+    /// Repeats [`stosb`](Self::stosb) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_stosb(&mut self) -> Result<()> {
+        self.op_string::<W8>(Some(0xf3), 0xaa, 0xab)
+    }
+
+    /// Stores `al`, `ax`, `eax` or `rax` to `[rdi]`, then steps it by the operand size (backwards if DF is set).
+    pub fn stosw(&mut self) -> Result<()> {
+        self.op_string::<W16>(None, 0xaa, 0xab)
+    }
+
+    /// Repeats [`stosw`](Self::stosw) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_stosw(&mut self) -> Result<()> {
+        self.op_string::<W16>(Some(0xf3), 0xaa, 0xab)
+    }
+
+    /// Stores `al`, `ax`, `eax` or `rax` to `[rdi]`, then steps it by the operand size (backwards if DF is set).
+    pub fn stosd(&mut self) -> Result<()> {
+        self.op_string::<W32>(None, 0xaa, 0xab)
+    }
+
+    /// Repeats [`stosd`](Self::stosd) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_stosd(&mut self) -> Result<()> {
+        self.op_string::<W32>(Some(0xf3), 0xaa, 0xab)
+    }
+
+    /// Stores `al`, `ax`, `eax` or `rax` to `[rdi]`, then steps it by the operand size (backwards if DF is set).
+    pub fn stosq(&mut self) -> Result<()> {
+        self.op_string::<W64>(None, 0xaa, 0xab)
+    }
+
+    /// Repeats [`stosq`](Self::stosq) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_stosq(&mut self) -> Result<()> {
+        self.op_string::<W64>(Some(0xf3), 0xaa, 0xab)
+    }
+
+    /// Loads `[rsi]` into `al`, `ax`, `eax` or `rax`, then steps it by the operand size (backwards if DF is set).
+    pub fn lodsb(&mut self) -> Result<()> {
+        self.op_string::<W8>(None, 0xac, 0xad)
+    }
+
+    /// Repeats [`lodsb`](Self::lodsb) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_lodsb(&mut self) -> Result<()> {
+        self.op_string::<W8>(Some(0xf3), 0xac, 0xad)
+    }
+
+    /// Loads `[rsi]` into `al`, `ax`, `eax` or `rax`, then steps it by the operand size (backwards if DF is set).
+    pub fn lodsw(&mut self) -> Result<()> {
+        self.op_string::<W16>(None, 0xac, 0xad)
+    }
+
+    /// Repeats [`lodsw`](Self::lodsw) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_lodsw(&mut self) -> Result<()> {
+        self.op_string::<W16>(Some(0xf3), 0xac, 0xad)
+    }
+
+    /// Loads `[rsi]` into `al`, `ax`, `eax` or `rax`, then steps it by the operand size (backwards if DF is set).
+    pub fn lodsd(&mut self) -> Result<()> {
+        self.op_string::<W32>(None, 0xac, 0xad)
+    }
+
+    /// Repeats [`lodsd`](Self::lodsd) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_lodsd(&mut self) -> Result<()> {
+        self.op_string::<W32>(Some(0xf3), 0xac, 0xad)
+    }
+
+    /// Loads `[rsi]` into `al`, `ax`, `eax` or `rax`, then steps it by the operand size (backwards if DF is set).
+    pub fn lodsq(&mut self) -> Result<()> {
+        self.op_string::<W64>(None, 0xac, 0xad)
+    }
+
+    /// Repeats [`lodsq`](Self::lodsq) `rcx` times, decrementing `rcx` every time.
+    pub fn rep_lodsq(&mut self) -> Result<()> {
+        self.op_string::<W64>(Some(0xf3), 0xac, 0xad)
+    }
+
+    /// Compares `[rsi]` with `[rdi]`, then steps both by the operand size (backwards if DF is set).
+    pub fn cmpsb(&mut self) -> Result<()> {
+        self.op_string::<W8>(None, 0xa6, 0xa7)
+    }
+
+    /// Repeats [`cmpsb`](Self::cmpsb) while `rcx` isn't zero and the elements are equal, decrementing `rcx` every time.
+    pub fn repe_cmpsb(&mut self) -> Result<()> {
+        self.op_string::<W8>(Some(0xf3), 0xa6, 0xa7)
+    }
+
+    /// Repeats [`cmpsb`](Self::cmpsb) while `rcx` isn't zero and the elements aren't equal, decrementing `rcx` every time.
+    pub fn repne_cmpsb(&mut self) -> Result<()> {
+        self.op_string::<W8>(Some(0xf2), 0xa6, 0xa7)
+    }
+
+    /// Compares `[rsi]` with `[rdi]`, then steps both by the operand size (backwards if DF is set).
+    pub fn cmpsw(&mut self) -> Result<()> {
+        self.op_string::<W16>(None, 0xa6, 0xa7)
+    }
+
+    /// Repeats [`cmpsw`](Self::cmpsw) while `rcx` isn't zero and the elements are equal, decrementing `rcx` every time.
+    pub fn repe_cmpsw(&mut self) -> Result<()> {
+        self.op_string::<W16>(Some(0xf3), 0xa6, 0xa7)
+    }
+
+    /// Repeats [`cmpsw`](Self::cmpsw) while `rcx` isn't zero and the elements aren't equal, decrementing `rcx` every time.
+    pub fn repne_cmpsw(&mut self) -> Result<()> {
+        self.op_string::<W16>(Some(0xf2), 0xa6, 0xa7)
+    }
+
+    /// Compares `[rsi]` with `[rdi]`, then steps both by the operand size (backwards if DF is set).
+    pub fn cmpsd(&mut self) -> Result<()> {
+        self.op_string::<W32>(None, 0xa6, 0xa7)
+    }
+
+    /// Repeats [`cmpsd`](Self::cmpsd) while `rcx` isn't zero and the elements are equal, decrementing `rcx` every time.
+    pub fn repe_cmpsd(&mut self) -> Result<()> {
+        self.op_string::<W32>(Some(0xf3), 0xa6, 0xa7)
+    }
+
+    /// Repeats [`cmpsd`](Self::cmpsd) while `rcx` isn't zero and the elements aren't equal, decrementing `rcx` every time.
+    pub fn repne_cmpsd(&mut self) -> Result<()> {
+        self.op_string::<W32>(Some(0xf2), 0xa6, 0xa7)
+    }
+
+    /// Compares `[rsi]` with `[rdi]`, then steps both by the operand size (backwards if DF is set).
+    pub fn cmpsq(&mut self) -> Result<()> {
+        self.op_string::<W64>(None, 0xa6, 0xa7)
+    }
+
+    /// Repeats [`cmpsq`](Self::cmpsq) while `rcx` isn't zero and the elements are equal, decrementing `rcx` every time.
+    pub fn repe_cmpsq(&mut self) -> Result<()> {
+        self.op_string::<W64>(Some(0xf3), 0xa6, 0xa7)
+    }
+
+    /// Repeats [`cmpsq`](Self::cmpsq) while `rcx` isn't zero and the elements aren't equal, decrementing `rcx` every time.
+    pub fn repne_cmpsq(&mut self) -> Result<()> {
+        self.op_string::<W64>(Some(0xf2), 0xa6, 0xa7)
+    }
+
+    /// Compares `al`, `ax`, `eax` or `rax` with `[rdi]`, then steps it by the operand size (backwards if DF is set).
+    pub fn scasb(&mut self) -> Result<()> {
+        self.op_string::<W8>(None, 0xae, 0xaf)
+    }
+
+    /// Repeats [`scasb`](Self::scasb) while `rcx` isn't zero and the elements are equal, decrementing `rcx` every time.
+    pub fn repe_scasb(&mut self) -> Result<()> {
+        self.op_string::<W8>(Some(0xf3), 0xae, 0xaf)
+    }
+
+    /// Repeats [`scasb`](Self::scasb) while `rcx` isn't zero and the elements aren't equal, decrementing `rcx` every time.
+    pub fn repne_scasb(&mut self) -> Result<()> {
+        self.op_string::<W8>(Some(0xf2), 0xae, 0xaf)
+    }
+
+    /// Compares `al`, `ax`, `eax` or `rax` with `[rdi]`, then steps it by the operand size (backwards if DF is set).
+    pub fn scasw(&mut self) -> Result<()> {
+        self.op_string::<W16>(None, 0xae, 0xaf)
+    }
+
+    /// Repeats [`scasw`](Self::scasw) while `rcx` isn't zero and the elements are equal, decrementing `rcx` every time.
+    pub fn repe_scasw(&mut self) -> Result<()> {
+        self.op_string::<W16>(Some(0xf3), 0xae, 0xaf)
+    }
+
+    /// Repeats [`scasw`](Self::scasw) while `rcx` isn't zero and the elements aren't equal, decrementing `rcx` every time.
+    pub fn repne_scasw(&mut self) -> Result<()> {
+        self.op_string::<W16>(Some(0xf2), 0xae, 0xaf)
+    }
+
+    /// Compares `al`, `ax`, `eax` or `rax` with `[rdi]`, then steps it by the operand size (backwards if DF is set).
+    pub fn scasd(&mut self) -> Result<()> {
+        self.op_string::<W32>(None, 0xae, 0xaf)
+    }
+
+    /// Repeats [`scasd`](Self::scasd) while `rcx` isn't zero and the elements are equal, decrementing `rcx` every time.
+    pub fn repe_scasd(&mut self) -> Result<()> {
+        self.op_string::<W32>(Some(0xf3), 0xae, 0xaf)
+    }
+
+    /// Repeats [`scasd`](Self::scasd) while `rcx` isn't zero and the elements aren't equal, decrementing `rcx` every time.
+    pub fn repne_scasd(&mut self) -> Result<()> {
+        self.op_string::<W32>(Some(0xf2), 0xae, 0xaf)
+    }
+
+    /// Compares `al`, `ax`, `eax` or `rax` with `[rdi]`, then steps it by the operand size (backwards if DF is set).
+    pub fn scasq(&mut self) -> Result<()> {
+        self.op_string::<W64>(None, 0xae, 0xaf)
+    }
+
+    /// Repeats [`scasq`](Self::scasq) while `rcx` isn't zero and the elements are equal, decrementing `rcx` every time.
+    pub fn repe_scasq(&mut self) -> Result<()> {
+        self.op_string::<W64>(Some(0xf3), 0xae, 0xaf)
+    }
+
+    /// Repeats [`scasq`](Self::scasq) while `rcx` isn't zero and the elements aren't equal, decrementing `rcx` every time.
+    pub fn repne_scasq(&mut self) -> Result<()> {
+        self.op_string::<W64>(Some(0xf2), 0xae, 0xaf)
+    }
+
+    pub fn ja_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x77, label)
+    }
+
+    pub fn jae_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x73, label)
+    }
+
+    pub fn jb_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x72, label)
+    }
+
+    pub fn jbe_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x76, label)
+    }
+
+    pub fn jc_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x72, label)
+    }
+
+    pub fn je_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x74, label)
+    }
+
+    pub fn jg_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7f, label)
+    }
+
+    pub fn jge_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7d, label)
+    }
+
+    pub fn jl_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7c, label)
+    }
+
+    pub fn jle_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7e, label)
+    }
+
+    pub fn jna_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x76, label)
+    }
+
+    pub fn jnae_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x72, label)
+    }
+
+    pub fn jnb_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x73, label)
+    }
+
+    pub fn jnbe_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x77, label)
+    }
+
+    pub fn jnc_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x73, label)
+    }
+
+    pub fn jne_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x75, label)
+    }
+
+    pub fn jng_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7e, label)
+    }
+
+    pub fn jnge_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7c, label)
+    }
+
+    pub fn jnl_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7d, label)
+    }
+
+    pub fn jnle_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7f, label)
+    }
+
+    pub fn jno_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x71, label)
+    }
+
+    pub fn jnp_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7b, label)
+    }
+
+    pub fn jns_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x79, label)
+    }
+
+    pub fn jnz_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x75, label)
+    }
+
+    pub fn jo_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x70, label)
+    }
+
+    pub fn jp_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7a, label)
+    }
+
+    pub fn jpe_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7a, label)
+    }
+
+    pub fn jpo_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x7b, label)
+    }
+
+    pub fn js_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x78, label)
+    }
+
+    pub fn jz_label(&mut self, label: Label) -> Result<()> {
+        self.op_jcc_label(0x74, label)
+    }
+
+    pub fn movss_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x10, dst.value(), src.value(), None)
+    }
+
+    pub fn movss_xmm_mem<M: Memory<W32>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x10, dst.value(), mem.into(), None)
+    }
+
+    pub fn movsd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf2), 1, 0x10, dst.value(), src.value(), None)
+    }
+
+    pub fn movsd_xmm_mem<M: Memory<W64>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf2), 1, 0x10, dst.value(), mem.into(), None)
+    }
+
+    pub fn addss_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x58, dst.value(), src.value(), None)
+    }
+
+    pub fn addss_xmm_mem<M: Memory<W32>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x58, dst.value(), mem.into(), None)
+    }
+
+    pub fn addsd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf2), 1, 0x58, dst.value(), src.value(), None)
+    }
+
+    pub fn addsd_xmm_mem<M: Memory<W64>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf2), 1, 0x58, dst.value(), mem.into(), None)
+    }
+
+    pub fn subss_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x5c, dst.value(), src.value(), None)
+    }
+
+    pub fn subss_xmm_mem<M: Memory<W32>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x5c, dst.value(), mem.into(), None)
+    }
+
+    pub fn subsd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf2), 1, 0x5c, dst.value(), src.value(), None)
+    }
+
+    pub fn subsd_xmm_mem<M: Memory<W64>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf2), 1, 0x5c, dst.value(), mem.into(), None)
+    }
+
+    pub fn mulss_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x59, dst.value(), src.value(), None)
+    }
+
+    pub fn mulss_xmm_mem<M: Memory<W32>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x59, dst.value(), mem.into(), None)
+    }
+
+    pub fn mulsd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf2), 1, 0x59, dst.value(), src.value(), None)
+    }
+
+    pub fn mulsd_xmm_mem<M: Memory<W64>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf2), 1, 0x59, dst.value(), mem.into(), None)
+    }
+
+    pub fn divss_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x5e, dst.value(), src.value(), None)
+    }
+
+    pub fn divss_xmm_mem<M: Memory<W32>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x5e, dst.value(), mem.into(), None)
+    }
+
+    pub fn divsd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf2), 1, 0x5e, dst.value(), src.value(), None)
+    }
+
+    pub fn divsd_xmm_mem<M: Memory<W64>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf2), 1, 0x5e, dst.value(), mem.into(), None)
+    }
+
+    pub fn sqrtss_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x51, dst.value(), src.value(), None)
+    }
+
+    pub fn sqrtss_xmm_mem<M: Memory<W32>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x51, dst.value(), mem.into(), None)
+    }
+
+    pub fn sqrtsd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf2), 1, 0x51, dst.value(), src.value(), None)
+    }
+
+    pub fn sqrtsd_xmm_mem<M: Memory<W64>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf2), 1, 0x51, dst.value(), mem.into(), None)
+    }
+
+    pub fn minss_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x5d, dst.value(), src.value(), None)
+    }
+
+    pub fn minss_xmm_mem<M: Memory<W32>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x5d, dst.value(), mem.into(), None)
+    }
+
+    pub fn minsd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf2), 1, 0x5d, dst.value(), src.value(), None)
+    }
+
+    pub fn minsd_xmm_mem<M: Memory<W64>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf2), 1, 0x5d, dst.value(), mem.into(), None)
+    }
+
+    pub fn maxss_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x5f, dst.value(), src.value(), None)
+    }
+
+    pub fn maxss_xmm_mem<M: Memory<W32>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x5f, dst.value(), mem.into(), None)
+    }
+
+    pub fn maxsd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf2), 1, 0x5f, dst.value(), src.value(), None)
+    }
+
+    pub fn maxsd_xmm_mem<M: Memory<W64>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf2), 1, 0x5f, dst.value(), mem.into(), None)
+    }
+
+    pub fn ucomiss_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x2e, dst.value(), src.value(), None)
+    }
+
+    pub fn ucomiss_xmm_mem<M: Memory<W32>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x2e, dst.value(), mem.into(), None)
+    }
+
+    pub fn ucomisd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x2e, dst.value(), src.value(), None)
+    }
+
+    pub fn ucomisd_xmm_mem<M: Memory<W64>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x2e, dst.value(), mem.into(), None)
+    }
+
+    pub fn comiss_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x2f, dst.value(), src.value(), None)
+    }
+
+    pub fn comiss_xmm_mem<M: Memory<W32>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x2f, dst.value(), mem.into(), None)
+    }
+
+    pub fn comisd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x2f, dst.value(), src.value(), None)
+    }
+
+    pub fn comisd_xmm_mem<M: Memory<W64>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x2f, dst.value(), mem.into(), None)
+    }
+
+    pub fn cvtss2sd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x5a, dst.value(), src.value(), None)
+    }
+
+    pub fn cvtss2sd_xmm_mem<M: Memory<W32>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x5a, dst.value(), mem.into(), None)
+    }
+
+    pub fn cvtsd2ss_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf2), 1, 0x5a, dst.value(), src.value(), None)
+    }
+
+    pub fn cvtsd2ss_xmm_mem<M: Memory<W64>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf2), 1, 0x5a, dst.value(), mem.into(), None)
+    }
+
+    pub fn paddb_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xfc, dst.value(), src.value(), None)
+    }
+
+    pub fn paddb_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xfc, dst.value(), mem.into(), None)
+    }
+
+    pub fn paddw_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xfd, dst.value(), src.value(), None)
+    }
+
+    pub fn paddw_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xfd, dst.value(), mem.into(), None)
+    }
+
+    pub fn paddd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xfe, dst.value(), src.value(), None)
+    }
+
+    pub fn paddd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xfe, dst.value(), mem.into(), None)
+    }
+
+    pub fn paddq_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xd4, dst.value(), src.value(), None)
+    }
+
+    pub fn paddq_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xd4, dst.value(), mem.into(), None)
+    }
+
+    pub fn psubb_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xf8, dst.value(), src.value(), None)
+    }
+
+    pub fn psubb_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xf8, dst.value(), mem.into(), None)
+    }
+
+    pub fn psubw_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xf9, dst.value(), src.value(), None)
+    }
+
+    pub fn psubw_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xf9, dst.value(), mem.into(), None)
+    }
+
+    pub fn psubd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xfa, dst.value(), src.value(), None)
+    }
+
+    pub fn psubd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xfa, dst.value(), mem.into(), None)
+    }
+
+    pub fn psubq_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xfb, dst.value(), src.value(), None)
+    }
+
+    pub fn psubq_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xfb, dst.value(), mem.into(), None)
+    }
+
+    pub fn pmullw_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xd5, dst.value(), src.value(), None)
+    }
+
+    pub fn pmullw_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xd5, dst.value(), mem.into(), None)
+    }
+
+    pub fn pmuludq_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xf4, dst.value(), src.value(), None)
+    }
+
+    pub fn pmuludq_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xf4, dst.value(), mem.into(), None)
+    }
+
+    pub fn pand_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xdb, dst.value(), src.value(), None)
+    }
+
+    pub fn pand_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xdb, dst.value(), mem.into(), None)
+    }
+
+    pub fn pandn_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xdf, dst.value(), src.value(), None)
+    }
+
+    pub fn pandn_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xdf, dst.value(), mem.into(), None)
+    }
+
+    pub fn por_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xeb, dst.value(), src.value(), None)
+    }
+
+    pub fn por_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xeb, dst.value(), mem.into(), None)
+    }
+
+    pub fn pxor_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xef, dst.value(), src.value(), None)
+    }
+
+    pub fn pxor_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xef, dst.value(), mem.into(), None)
+    }
+
+    pub fn pcmpeqb_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x74, dst.value(), src.value(), None)
+    }
+
+    pub fn pcmpeqb_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x74, dst.value(), mem.into(), None)
+    }
+
+    pub fn pcmpeqw_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x75, dst.value(), src.value(), None)
+    }
+
+    pub fn pcmpeqw_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x75, dst.value(), mem.into(), None)
+    }
+
+    pub fn pcmpeqd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x76, dst.value(), src.value(), None)
+    }
+
+    pub fn pcmpeqd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x76, dst.value(), mem.into(), None)
+    }
+
+    pub fn pcmpgtb_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x64, dst.value(), src.value(), None)
+    }
+
+    pub fn pcmpgtb_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x64, dst.value(), mem.into(), None)
+    }
+
+    pub fn pcmpgtw_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x65, dst.value(), src.value(), None)
+    }
+
+    pub fn pcmpgtw_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x65, dst.value(), mem.into(), None)
+    }
+
+    pub fn pcmpgtd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x66, dst.value(), src.value(), None)
+    }
+
+    pub fn pcmpgtd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x66, dst.value(), mem.into(), None)
+    }
+
+    pub fn punpcklbw_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x60, dst.value(), src.value(), None)
+    }
+
+    pub fn punpcklbw_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x60, dst.value(), mem.into(), None)
+    }
+
+    pub fn punpckldq_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x62, dst.value(), src.value(), None)
+    }
+
+    pub fn punpckldq_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x62, dst.value(), mem.into(), None)
+    }
+
+    pub fn punpcklqdq_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x6c, dst.value(), src.value(), None)
+    }
+
+    pub fn punpcklqdq_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x6c, dst.value(), mem.into(), None)
+    }
+
+    pub fn punpckhqdq_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x6d, dst.value(), src.value(), None)
+    }
+
+    pub fn punpckhqdq_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x6d, dst.value(), mem.into(), None)
+    }
+
+    pub fn packsswb_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x63, dst.value(), src.value(), None)
+    }
+
+    pub fn packsswb_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x63, dst.value(), mem.into(), None)
+    }
+
+    pub fn packuswb_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x67, dst.value(), src.value(), None)
+    }
+
+    pub fn packuswb_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x67, dst.value(), mem.into(), None)
+    }
+
+    pub fn pshufb_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x0, dst.value(), src.value(), None)
+    }
+
+    pub fn pshufb_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x0, dst.value(), mem.into(), None)
+    }
+
+    pub fn pmulld_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x40, dst.value(), src.value(), None)
+    }
+
+    pub fn pmulld_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x40, dst.value(), mem.into(), None)
+    }
+
+    pub fn pcmpeqq_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x29, dst.value(), src.value(), None)
+    }
+
+    pub fn pcmpeqq_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x29, dst.value(), mem.into(), None)
+    }
+
+    pub fn pcmpgtq_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x37, dst.value(), src.value(), None)
+    }
+
+    pub fn pcmpgtq_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x37, dst.value(), mem.into(), None)
+    }
+
+    pub fn pminsd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x39, dst.value(), src.value(), None)
+    }
+
+    pub fn pminsd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x39, dst.value(), mem.into(), None)
+    }
+
+    pub fn pmaxsd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x3d, dst.value(), src.value(), None)
+    }
+
+    pub fn pmaxsd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x3d, dst.value(), mem.into(), None)
+    }
+
+    pub fn pminud_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x3b, dst.value(), src.value(), None)
+    }
+
+    pub fn pminud_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x3b, dst.value(), mem.into(), None)
+    }
+
+    pub fn pmaxud_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x3f, dst.value(), src.value(), None)
+    }
+
+    pub fn pmaxud_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x3f, dst.value(), mem.into(), None)
+    }
+
+    pub fn ptest_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x17, dst.value(), src.value(), None)
+    }
+
+    pub fn ptest_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x17, dst.value(), mem.into(), None)
+    }
+
+    /// The mask is implicitly `xmm0`.
+    pub fn blendvps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x14, dst.value(), src.value(), None)
+    }
+
+    /// The mask is implicitly `xmm0`.
+    pub fn blendvps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x14, dst.value(), mem.into(), None)
+    }
+
+    /// The mask is implicitly `xmm0`.
+    pub fn blendvpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x15, dst.value(), src.value(), None)
+    }
+
+    /// The mask is implicitly `xmm0`.
+    pub fn blendvpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x15, dst.value(), mem.into(), None)
+    }
+
+    /// The mask is implicitly `xmm0`.
+    pub fn pblendvb_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 2, 0x10, dst.value(), src.value(), None)
+    }
+
+    /// The mask is implicitly `xmm0`.
+    pub fn pblendvb_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 2, 0x10, dst.value(), mem.into(), None)
+    }
+
+    pub fn movdqa_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x6f, dst.value(), src.value(), None)
+    }
+
+    pub fn movdqa_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x6f, dst.value(), mem.into(), None)
+    }
+
+    pub fn movdqu_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x6f, dst.value(), src.value(), None)
+    }
+
+    pub fn movdqu_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x6f, dst.value(), mem.into(), None)
+    }
+
+    pub fn movaps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x28, dst.value(), src.value(), None)
+    }
+
+    pub fn movaps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x28, dst.value(), mem.into(), None)
+    }
+
+    pub fn movups_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x10, dst.value(), src.value(), None)
+    }
+
+    pub fn movups_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x10, dst.value(), mem.into(), None)
+    }
+
+    pub fn movapd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x28, dst.value(), src.value(), None)
+    }
+
+    pub fn movapd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x28, dst.value(), mem.into(), None)
+    }
+
+    pub fn movupd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x10, dst.value(), src.value(), None)
+    }
+
+    pub fn movupd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x10, dst.value(), mem.into(), None)
+    }
+
+    pub fn addps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x58, dst.value(), src.value(), None)
+    }
+
+    pub fn addps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x58, dst.value(), mem.into(), None)
+    }
+
+    pub fn addpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x58, dst.value(), src.value(), None)
+    }
+
+    pub fn addpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x58, dst.value(), mem.into(), None)
+    }
+
+    pub fn subps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x5c, dst.value(), src.value(), None)
+    }
+
+    pub fn subps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x5c, dst.value(), mem.into(), None)
+    }
+
+    pub fn subpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x5c, dst.value(), src.value(), None)
+    }
+
+    pub fn subpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x5c, dst.value(), mem.into(), None)
+    }
+
+    pub fn mulps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x59, dst.value(), src.value(), None)
+    }
+
+    pub fn mulps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x59, dst.value(), mem.into(), None)
+    }
+
+    pub fn mulpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x59, dst.value(), src.value(), None)
+    }
+
+    pub fn mulpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x59, dst.value(), mem.into(), None)
+    }
+
+    pub fn divps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x5e, dst.value(), src.value(), None)
+    }
+
+    pub fn divps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x5e, dst.value(), mem.into(), None)
+    }
+
+    pub fn divpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x5e, dst.value(), src.value(), None)
+    }
+
+    pub fn divpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x5e, dst.value(), mem.into(), None)
+    }
+
+    pub fn sqrtps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x51, dst.value(), src.value(), None)
+    }
+
+    pub fn sqrtps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x51, dst.value(), mem.into(), None)
+    }
+
+    pub fn sqrtpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x51, dst.value(), src.value(), None)
+    }
+
+    pub fn sqrtpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x51, dst.value(), mem.into(), None)
+    }
+
+    pub fn minps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x5d, dst.value(), src.value(), None)
+    }
+
+    pub fn minps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x5d, dst.value(), mem.into(), None)
+    }
+
+    pub fn minpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x5d, dst.value(), src.value(), None)
+    }
+
+    pub fn minpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x5d, dst.value(), mem.into(), None)
+    }
+
+    pub fn maxps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x5f, dst.value(), src.value(), None)
+    }
+
+    pub fn maxps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x5f, dst.value(), mem.into(), None)
+    }
+
+    pub fn maxpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x5f, dst.value(), src.value(), None)
+    }
+
+    pub fn maxpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x5f, dst.value(), mem.into(), None)
+    }
+
+    pub fn andps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x54, dst.value(), src.value(), None)
+    }
+
+    pub fn andps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x54, dst.value(), mem.into(), None)
+    }
+
+    pub fn andpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x54, dst.value(), src.value(), None)
+    }
+
+    pub fn andpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x54, dst.value(), mem.into(), None)
+    }
+
+    pub fn andnps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x55, dst.value(), src.value(), None)
+    }
+
+    pub fn andnps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x55, dst.value(), mem.into(), None)
+    }
+
+    pub fn andnpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x55, dst.value(), src.value(), None)
+    }
+
+    pub fn andnpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x55, dst.value(), mem.into(), None)
+    }
+
+    pub fn orps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x56, dst.value(), src.value(), None)
+    }
+
+    pub fn orps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x56, dst.value(), mem.into(), None)
+    }
+
+    pub fn orpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x56, dst.value(), src.value(), None)
+    }
+
+    pub fn orpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x56, dst.value(), mem.into(), None)
+    }
+
+    pub fn xorps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x57, dst.value(), src.value(), None)
+    }
+
+    pub fn xorps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x57, dst.value(), mem.into(), None)
+    }
+
+    pub fn xorpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x57, dst.value(), src.value(), None)
+    }
+
+    pub fn xorpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x57, dst.value(), mem.into(), None)
+    }
+
+    pub fn unpcklps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x14, dst.value(), src.value(), None)
+    }
+
+    pub fn unpcklps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x14, dst.value(), mem.into(), None)
+    }
+
+    pub fn unpcklpd_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x14, dst.value(), src.value(), None)
+    }
+
+    pub fn unpcklpd_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x14, dst.value(), mem.into(), None)
+    }
+
+    pub fn cvtdq2ps_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0x5b, dst.value(), src.value(), None)
+    }
+
+    pub fn cvtdq2ps_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x5b, dst.value(), mem.into(), None)
+    }
+
+    pub fn cvtps2dq_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x5b, dst.value(), src.value(), None)
+    }
+
+    pub fn cvtps2dq_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x5b, dst.value(), mem.into(), None)
+    }
+
+    pub fn cvttps2dq_xmm_xmm(&mut self, dst: Xmm, src: Xmm) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x5b, dst.value(), src.value(), None)
+    }
+
+    pub fn cvttps2dq_xmm_mem<M: VectorMemory<W128>>(&mut self, dst: Xmm, mem: M) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x5b, dst.value(), mem.into(), None)
+    }
+
+    pub fn shufps_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(None, 1, 0xc6, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn shufps_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0xc6, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn shufpd_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xc6, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn shufpd_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xc6, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn pshufd_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0x70, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn pshufd_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x70, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn pshuflw_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf2), 1, 0x70, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn pshuflw_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf2), 1, 0x70, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn pshufhw_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0xf3), 1, 0x70, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn pshufhw_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x70, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn blendps_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0xc, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn blendps_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0xc, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn blendpd_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0xd, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn blendpd_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0xd, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn pblendw_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0xe, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn pblendw_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0xe, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn palignr_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0xf, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn palignr_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0xf, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn dpps_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0x40, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn dpps_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0x40, dst.value(), mem.into(), Some(imm))
+    }
+
+    /// The index ends up in `ecx`.
+    pub fn pcmpistri_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0x63, dst.value(), src.value(), Some(imm))
+    }
+
+    /// The index ends up in `ecx`.
+    pub fn pcmpistri_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0x63, dst.value(), mem.into(), Some(imm))
+    }
+
+    /// The lengths are implicitly `eax` and `edx`, the index ends up in `ecx`.
+    pub fn pcmpestri_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0x61, dst.value(), src.value(), Some(imm))
+    }
+
+    /// The lengths are implicitly `eax` and `edx`, the index ends up in `ecx`.
+    pub fn pcmpestri_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0x61, dst.value(), mem.into(), Some(imm))
+    }
+
+    /// The mask ends up in `xmm0`.
+    pub fn pcmpistrm_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0x62, dst.value(), src.value(), Some(imm))
+    }
+
+    /// The mask ends up in `xmm0`.
+    pub fn pcmpistrm_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0x62, dst.value(), mem.into(), Some(imm))
+    }
+
+    /// The lengths are implicitly `eax` and `edx`, the mask ends up in `xmm0`.
+    pub fn pcmpestrm_xmm_xmm_imm8(&mut self, dst: Xmm, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0x60, dst.value(), src.value(), Some(imm))
+    }
+
+    /// The lengths are implicitly `eax` and `edx`, the mask ends up in `xmm0`.
+    pub fn pcmpestrm_xmm_mem_imm8<M: VectorMemory<W128>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0x60, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn movss_mem_xmm<M: Memory<W32>>(&mut self, mem: M, src: Xmm) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x11, src.value(), mem.into(), None)
+    }
+
+    pub fn movsd_mem_xmm<M: Memory<W64>>(&mut self, mem: M, src: Xmm) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf2), 1, 0x11, src.value(), mem.into(), None)
+    }
+
+    pub fn movdqa_mem_xmm<M: VectorMemory<W128>>(&mut self, mem: M, src: Xmm) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x7f, src.value(), mem.into(), None)
+    }
+
+    pub fn movdqu_mem_xmm<M: VectorMemory<W128>>(&mut self, mem: M, src: Xmm) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0xf3), 1, 0x7f, src.value(), mem.into(), None)
+    }
+
+    pub fn movaps_mem_xmm<M: VectorMemory<W128>>(&mut self, mem: M, src: Xmm) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x29, src.value(), mem.into(), None)
+    }
+
+    pub fn movups_mem_xmm<M: VectorMemory<W128>>(&mut self, mem: M, src: Xmm) -> Result<()> {
+        self.op_sse_rm::<W32>(None, 1, 0x11, src.value(), mem.into(), None)
+    }
+
+    pub fn movapd_mem_xmm<M: VectorMemory<W128>>(&mut self, mem: M, src: Xmm) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x29, src.value(), mem.into(), None)
+    }
+
+    pub fn movupd_mem_xmm<M: VectorMemory<W128>>(&mut self, mem: M, src: Xmm) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0x11, src.value(), mem.into(), None)
+    }
+
+    pub fn cvtsi2ss_xmm_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: Xmm,
+        src: R,
+    ) -> Result<()> {
+        let src: Register = src.into();
+        self.op_sse_rr::<Width>(Some(0xf3), 1, 0x2a, dst.value(), src.value(), None)
+    }
+
+    pub fn cvtsi2ss_xmm_mem<Width: WidthAtLeast32, M: Memory<Width>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+    ) -> Result<()> {
+        self.op_sse_rm::<Width>(Some(0xf3), 1, 0x2a, dst.value(), mem.into(), None)
+    }
+
+    pub fn cvtsi2sd_xmm_reg<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: Xmm,
+        src: R,
+    ) -> Result<()> {
+        let src: Register = src.into();
+        self.op_sse_rr::<Width>(Some(0xf2), 1, 0x2a, dst.value(), src.value(), None)
+    }
+
+    pub fn cvtsi2sd_xmm_mem<Width: WidthAtLeast32, M: Memory<Width>>(
+        &mut self,
+        dst: Xmm,
+        mem: M,
+    ) -> Result<()> {
+        self.op_sse_rm::<Width>(Some(0xf2), 1, 0x2a, dst.value(), mem.into(), None)
+    }
+
+    pub fn cvtss2si_reg_xmm<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: R,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<Width>(Some(0xf3), 1, 0x2d, dst.value(), src.value(), None)
+    }
+
+    pub fn cvtss2si_reg_mem<Width: WidthAtLeast32, R: GeneralRegister<Width>, M: Memory<W32>>(
+        &mut self,
+        dst: R,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rm::<Width>(Some(0xf3), 1, 0x2d, dst.value(), mem.into(), None)
+    }
+
+    pub fn cvttss2si_reg_xmm<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: R,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<Width>(Some(0xf3), 1, 0x2c, dst.value(), src.value(), None)
+    }
+
+    pub fn cvttss2si_reg_mem<Width: WidthAtLeast32, R: GeneralRegister<Width>, M: Memory<W32>>(
+        &mut self,
+        dst: R,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rm::<Width>(Some(0xf3), 1, 0x2c, dst.value(), mem.into(), None)
+    }
+
+    pub fn cvtsd2si_reg_xmm<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: R,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<Width>(Some(0xf2), 1, 0x2d, dst.value(), src.value(), None)
+    }
+
+    pub fn cvtsd2si_reg_mem<Width: WidthAtLeast32, R: GeneralRegister<Width>, M: Memory<W64>>(
+        &mut self,
+        dst: R,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rm::<Width>(Some(0xf2), 1, 0x2d, dst.value(), mem.into(), None)
+    }
+
+    pub fn cvttsd2si_reg_xmm<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: R,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<Width>(Some(0xf2), 1, 0x2c, dst.value(), src.value(), None)
+    }
+
+    pub fn cvttsd2si_reg_mem<Width: WidthAtLeast32, R: GeneralRegister<Width>, M: Memory<W64>>(
+        &mut self,
+        dst: R,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rm::<Width>(Some(0xf2), 1, 0x2c, dst.value(), mem.into(), None)
+    }
+
+    pub fn pmovmskb_reg_xmm<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: R,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<Width>(Some(0x66), 1, 0xd7, dst.value(), src.value(), None)
+    }
+
+    pub fn movmskps_reg_xmm<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: R,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<Width>(None, 1, 0x50, dst.value(), src.value(), None)
+    }
+
+    pub fn movmskpd_reg_xmm<Width: WidthAtLeast32, R: GeneralRegister<Width>>(
+        &mut self,
+        dst: R,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<Width>(Some(0x66), 1, 0x50, dst.value(), src.value(), None)
+    }
+
+    pub fn pinsrb_xmm_reg_imm8<R: GeneralRegister<W32>>(
+        &mut self,
+        dst: Xmm,
+        src: R,
+        imm: u8,
+    ) -> Result<()> {
+        let src: Register = src.into();
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0x20, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn pinsrb_xmm_mem_imm8<M: Memory<W8>>(&mut self, dst: Xmm, mem: M, imm: u8) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0x20, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn pinsrw_xmm_reg_imm8<R: GeneralRegister<W32>>(
+        &mut self,
+        dst: Xmm,
+        src: R,
+        imm: u8,
+    ) -> Result<()> {
+        let src: Register = src.into();
+        self.op_sse_rr::<W32>(Some(0x66), 1, 0xc4, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn pinsrw_xmm_mem_imm8<M: Memory<W16>>(&mut self, dst: Xmm, mem: M, imm: u8) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 1, 0xc4, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn pinsrd_xmm_reg_imm8<R: GeneralRegister<W32>>(
+        &mut self,
+        dst: Xmm,
+        src: R,
+        imm: u8,
+    ) -> Result<()> {
+        let src: Register = src.into();
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0x22, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn pinsrd_xmm_mem_imm8<M: Memory<W32>>(&mut self, dst: Xmm, mem: M, imm: u8) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0x22, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn pinsrq_xmm_reg_imm8<R: GeneralRegister<W64>>(
+        &mut self,
+        dst: Xmm,
+        src: R,
+        imm: u8,
+    ) -> Result<()> {
+        let src: Register = src.into();
+        self.op_sse_rr::<W64>(Some(0x66), 3, 0x22, dst.value(), src.value(), Some(imm))
+    }
+
+    pub fn pinsrq_xmm_mem_imm8<M: Memory<W64>>(&mut self, dst: Xmm, mem: M, imm: u8) -> Result<()> {
+        self.op_sse_rm::<W64>(Some(0x66), 3, 0x22, dst.value(), mem.into(), Some(imm))
+    }
+
+    pub fn pextrb_reg_xmm_imm8<R: GeneralRegister<W32>>(
+        &mut self,
+        dst: R,
+        src: Xmm,
+        imm: u8,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0x14, src.value(), dst.value(), Some(imm))
+    }
+
+    pub fn pextrb_mem_xmm_imm8<M: Memory<W8>>(&mut self, mem: M, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0x14, src.value(), mem.into(), Some(imm))
+    }
+
+    pub fn pextrw_reg_xmm_imm8<R: GeneralRegister<W32>>(
+        &mut self,
+        dst: R,
+        src: Xmm,
+        imm: u8,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0x15, src.value(), dst.value(), Some(imm))
+    }
+
+    pub fn pextrw_mem_xmm_imm8<M: Memory<W16>>(&mut self, mem: M, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0x15, src.value(), mem.into(), Some(imm))
+    }
+
+    pub fn pextrd_reg_xmm_imm8<R: GeneralRegister<W32>>(
+        &mut self,
+        dst: R,
+        src: Xmm,
+        imm: u8,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<W32>(Some(0x66), 3, 0x16, src.value(), dst.value(), Some(imm))
+    }
+
+    pub fn pextrd_mem_xmm_imm8<M: Memory<W32>>(&mut self, mem: M, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rm::<W32>(Some(0x66), 3, 0x16, src.value(), mem.into(), Some(imm))
+    }
+
+    pub fn pextrq_reg_xmm_imm8<R: GeneralRegister<W64>>(
+        &mut self,
+        dst: R,
+        src: Xmm,
+        imm: u8,
+    ) -> Result<()> {
+        let dst: Register = dst.into();
+        self.op_sse_rr::<W64>(Some(0x66), 3, 0x16, src.value(), dst.value(), Some(imm))
+    }
+
+    pub fn pextrq_mem_xmm_imm8<M: Memory<W64>>(&mut self, mem: M, src: Xmm, imm: u8) -> Result<()> {
+        self.op_sse_rm::<W64>(Some(0x66), 3, 0x16, src.value(), mem.into(), Some(imm))
+    }
+
+    pub fn vaddps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x58,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vaddps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x58,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vaddpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x58,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vaddpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x58,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vsubps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x5c,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vsubps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x5c,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vsubpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x5c,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vsubpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x5c,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vmulps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x59,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vmulps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x59,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vmulpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x59,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vmulpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x59,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vdivps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x5e,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vdivps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x5e,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vdivpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x5e,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vdivpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x5e,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vminps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x5d,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vminps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x5d,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vminpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x5d,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vminpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x5d,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vmaxps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x5f,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vmaxps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x5f,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vmaxpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x5f,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vmaxpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x5f,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vandps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x54,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vandps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x54,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vandpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x54,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vandpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x54,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vandnps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x55,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vandnps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x55,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vandnpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x55,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vandnpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x55,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vorps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x56,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vorps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x56,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vorpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x56,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vorpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x56,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vxorps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x57,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vxorps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x57,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vxorpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x57,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vxorpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x57,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vunpcklps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x14,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vunpcklps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x14,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vunpcklpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x14,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vunpcklpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x14,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vunpckhps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0x15,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vunpckhps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0x15,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vunpckhpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x15,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vunpckhpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x15,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vhaddps_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            3,
+            1,
+            0x7c,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vhaddps_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            3,
+            1,
+            0x7c,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vhaddpd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x7c,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vhaddpd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x7c,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpaddb_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xfc,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpaddb_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xfc,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpaddw_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xfd,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpaddw_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xfd,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpaddd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xfe,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpaddd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xfe,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpaddq_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xd4,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpaddq_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xd4,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpsubb_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xf8,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpsubb_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xf8,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpsubw_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xf9,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpsubw_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xf9,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpsubd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xfa,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpsubd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xfa,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpsubq_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xfb,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpsubq_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xfb,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpmullw_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xd5,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpmullw_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xd5,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpmuludq_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xf4,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpmuludq_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xf4,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpand_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xdb,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpand_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xdb,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpandn_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xdf,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpandn_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xdf,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpor_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xeb,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpor_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xeb,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpxor_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xef,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpxor_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xef,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpcmpeqb_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x74,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpcmpeqb_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x74,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpcmpeqw_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x75,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpcmpeqw_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x75,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpcmpeqd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x76,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpcmpeqd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x76,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpcmpgtb_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x64,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpcmpgtb_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x64,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpcmpgtw_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x65,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpcmpgtw_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x65,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpcmpgtd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x66,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpcmpgtd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x66,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpunpcklbw_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x60,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpunpcklbw_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x60,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpunpckldq_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x62,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpunpckldq_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x62,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpunpcklqdq_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x6c,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpunpcklqdq_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x6c,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpunpckhqdq_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x6d,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpunpckhqdq_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x6d,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpacksswb_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x63,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpacksswb_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x63,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpackuswb_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0x67,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpackuswb_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0x67,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpshufb_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x0,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpshufb_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x0,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpmulld_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x40,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpmulld_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x40,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpcmpeqq_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x29,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpcmpeqq_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x29,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpcmpgtq_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x37,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpcmpgtq_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x37,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpminsd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x39,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpminsd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x39,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpmaxsd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x3d,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpmaxsd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x3d,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpminud_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x3b,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpminud_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x3b,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpmaxud_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x3f,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpmaxud_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x3f,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpsllvd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x47,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpsllvd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x47,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpsllvq_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x47,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpsllvq_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x47,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpsrlvd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x45,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpsrlvd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x45,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpsrlvq_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x45,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpsrlvq_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x45,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vpsravd_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            2,
+            0x46,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    pub fn vpsravd_vec_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            2,
+            0x46,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermd_ymm_ymm_ymm(&mut self, dst: Ymm, src1: Ymm, src2: Ymm) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<W256>(
+            1,
+            2,
+            0x36,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermd_ymm_ymm_mem<M: VectorMemory<W256>>(
+        &mut self,
+        dst: Ymm,
+        src1: Ymm,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<W256>(
+            1,
+            2,
+            0x36,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermps_ymm_ymm_ymm(&mut self, dst: Ymm, src1: Ymm, src2: Ymm) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<W256>(
+            1,
+            2,
+            0x16,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermps_ymm_ymm_mem<M: VectorMemory<W256>>(
+        &mut self,
+        dst: Ymm,
+        src1: Ymm,
+        mem: M,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<W256>(
+            1,
+            2,
+            0x16,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            None,
+        )
+    }
+
+    pub fn vshufps_vec_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0xc6,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vshufps_vec_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0xc6,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vshufpd_vec_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xc6,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vshufpd_vec_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xc6,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vcmpps_vec_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            0,
+            1,
+            0xc2,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vcmpps_vec_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            0,
+            1,
+            0xc2,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vcmppd_vec_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            1,
+            0xc2,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vcmppd_vec_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            1,
+            0xc2,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vblendps_vec_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            3,
+            0xc,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vblendps_vec_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            3,
+            0xc,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vblendpd_vec_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            3,
+            0xd,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vblendpd_vec_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            3,
+            0xd,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vpblendw_vec_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            3,
+            0xe,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vpblendw_vec_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            3,
+            0xe,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vpblendd_vec_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            3,
+            0x2,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vpblendd_vec_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            3,
+            0x2,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vpalignr_vec_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            3,
+            0xf,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vpalignr_vec_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            3,
+            0xf,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vdpps_vec_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<Width>(
+            1,
+            3,
+            0x40,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vdpps_vec_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<Width>(
+            1,
+            3,
+            0x40,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vperm2f128_ymm_ymm_ymm_imm8(
+        &mut self,
+        dst: Ymm,
+        src1: Ymm,
+        src2: Ymm,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<W256>(
+            1,
+            3,
+            0x6,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vperm2f128_ymm_ymm_mem_imm8<M: VectorMemory<W256>>(
+        &mut self,
+        dst: Ymm,
+        src1: Ymm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<W256>(
+            1,
+            3,
+            0x6,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vperm2i128_ymm_ymm_ymm_imm8(
+        &mut self,
+        dst: Ymm,
+        src1: Ymm,
+        src2: Ymm,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1, src2): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), src2.into());
+        self.op_avx_rr::<W256>(
+            1,
+            3,
+            0x46,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(imm),
+        )
+    }
+
+    pub fn vperm2i128_ymm_ymm_mem_imm8<M: VectorMemory<W256>>(
+        &mut self,
+        dst: Ymm,
+        src1: Ymm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src1): (Xmm, Xmm) = (dst.into(), src1.into());
+        self.op_avx_rm::<W256>(
+            1,
+            3,
+            0x46,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(imm),
+        )
+    }
+
+    pub fn vblendvps_vec_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        mask: V,
+    ) -> Result<()> {
+        let (dst, src1, src2, mask): (Xmm, Xmm, Xmm, Xmm) =
+            (dst.into(), src1.into(), src2.into(), mask.into());
+        self.op_avx_rr::<Width>(
+            1,
+            3,
+            0x4a,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(mask.value() << 4),
+        )
+    }
+
+    pub fn vblendvps_vec_vec_mem_vec<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        mask: V,
+    ) -> Result<()> {
+        let (dst, src1, mask): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), mask.into());
+        self.op_avx_rm::<Width>(
+            1,
+            3,
+            0x4a,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(mask.value() << 4),
+        )
+    }
+
+    pub fn vblendvpd_vec_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        mask: V,
+    ) -> Result<()> {
+        let (dst, src1, src2, mask): (Xmm, Xmm, Xmm, Xmm) =
+            (dst.into(), src1.into(), src2.into(), mask.into());
+        self.op_avx_rr::<Width>(
+            1,
+            3,
+            0x4b,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(mask.value() << 4),
+        )
+    }
+
+    pub fn vblendvpd_vec_vec_mem_vec<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        mask: V,
+    ) -> Result<()> {
+        let (dst, src1, mask): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), mask.into());
+        self.op_avx_rm::<Width>(
+            1,
+            3,
+            0x4b,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(mask.value() << 4),
+        )
+    }
+
+    pub fn vpblendvb_vec_vec_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src1: V,
+        src2: V,
+        mask: V,
+    ) -> Result<()> {
+        let (dst, src1, src2, mask): (Xmm, Xmm, Xmm, Xmm) =
+            (dst.into(), src1.into(), src2.into(), mask.into());
+        self.op_avx_rr::<Width>(
+            1,
+            3,
+            0x4c,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            Some(mask.value() << 4),
+        )
+    }
+
+    pub fn vpblendvb_vec_vec_mem_vec<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        src1: V,
+        mem: M,
+        mask: V,
+    ) -> Result<()> {
+        let (dst, src1, mask): (Xmm, Xmm, Xmm) = (dst.into(), src1.into(), mask.into());
+        self.op_avx_rm::<Width>(
+            1,
+            3,
+            0x4c,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            Some(mask.value() << 4),
+        )
+    }
+
+    pub fn vmovaps_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(0, 1, 0x28, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vmovaps_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(0, 1, 0x28, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vmovups_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(0, 1, 0x10, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vmovups_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(0, 1, 0x10, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vmovapd_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(1, 1, 0x28, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vmovapd_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 1, 0x28, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vmovupd_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(1, 1, 0x10, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vmovupd_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 1, 0x10, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vmovdqa_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(1, 1, 0x6f, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vmovdqa_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 1, 0x6f, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vmovdqu_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(2, 1, 0x6f, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vmovdqu_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(2, 1, 0x6f, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vsqrtps_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(0, 1, 0x51, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vsqrtps_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(0, 1, 0x51, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vsqrtpd_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(1, 1, 0x51, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vsqrtpd_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 1, 0x51, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vcvtdq2ps_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(0, 1, 0x5b, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vcvtdq2ps_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(0, 1, 0x5b, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vcvtps2dq_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(1, 1, 0x5b, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vcvtps2dq_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 1, 0x5b, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vcvttps2dq_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(2, 1, 0x5b, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vcvttps2dq_vec_mem<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(2, 1, 0x5b, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vptest_vec_vec<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(1, 2, 0x17, false, dst.value(), 0, src.value(), None)
+    }
+
+    pub fn vptest_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 2, 0x17, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vpshufd_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(1, 1, 0x70, false, dst.value(), 0, src.value(), Some(imm))
+    }
+
+    pub fn vpshufd_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 1, 0x70, false, dst.value(), 0, mem.into(), Some(imm))
+    }
+
+    pub fn vroundps_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(1, 3, 0x8, false, dst.value(), 0, src.value(), Some(imm))
+    }
+
+    pub fn vroundps_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 3, 0x8, false, dst.value(), 0, mem.into(), Some(imm))
+    }
+
+    pub fn vroundpd_vec_vec_imm8<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: V,
+        imm: u8,
+    ) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<Width>(1, 3, 0x9, false, dst.value(), 0, src.value(), Some(imm))
+    }
+
+    pub fn vroundpd_vec_mem_imm8<
+        Width: VectorWidth,
+        V: VectorRegister<Width>,
+        M: VectorMemory<Width>,
+    >(
+        &mut self,
+        dst: V,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 3, 0x9, false, dst.value(), 0, mem.into(), Some(imm))
+    }
+
+    pub fn vpermq_ymm_ymm_imm8(&mut self, dst: Ymm, src: Ymm, imm: u8) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<W256>(1, 3, 0x0, true, dst.value(), 0, src.value(), Some(imm))
+    }
+
+    pub fn vpermq_ymm_mem_imm8<M: VectorMemory<W256>>(
+        &mut self,
+        dst: Ymm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<W256>(1, 3, 0x0, true, dst.value(), 0, mem.into(), Some(imm))
+    }
+
+    pub fn vpermpd_ymm_ymm_imm8(&mut self, dst: Ymm, src: Ymm, imm: u8) -> Result<()> {
+        let (dst, src): (Xmm, Xmm) = (dst.into(), src.into());
+        self.op_avx_rr::<W256>(1, 3, 0x1, true, dst.value(), 0, src.value(), Some(imm))
+    }
+
+    pub fn vpermpd_ymm_mem_imm8<M: VectorMemory<W256>>(
+        &mut self,
+        dst: Ymm,
+        mem: M,
+        imm: u8,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<W256>(1, 3, 0x1, true, dst.value(), 0, mem.into(), Some(imm))
+    }
+
+    /// Copies the low element of the source to every element of `dst`, the register form needs AVX2.
+    pub fn vbroadcastss_vec_xmm<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rr::<Width>(1, 2, 0x18, false, dst.value(), 0, src.value(), None)
+    }
+
+    /// Copies the low element of the source to every element of `dst`, the register form needs AVX2.
+    pub fn vbroadcastss_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: Memory<W32>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 2, 0x18, false, dst.value(), 0, mem.into(), None)
+    }
+
+    /// Copies the low element of the source to every element of `dst`, the register form needs AVX2.
+    pub fn vbroadcastsd_ymm_xmm(&mut self, dst: Ymm, src: Xmm) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rr::<W256>(1, 2, 0x19, false, dst.value(), 0, src.value(), None)
+    }
+
+    /// Copies the low element of the source to every element of `dst`, the register form needs AVX2.
+    pub fn vbroadcastsd_ymm_mem<M: Memory<W64>>(&mut self, dst: Ymm, mem: M) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<W256>(1, 2, 0x19, false, dst.value(), 0, mem.into(), None)
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastb_vec_xmm<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rr::<Width>(1, 2, 0x78, false, dst.value(), 0, src.value(), None)
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastb_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: Memory<W8>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 2, 0x78, false, dst.value(), 0, mem.into(), None)
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastw_vec_xmm<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rr::<Width>(1, 2, 0x79, false, dst.value(), 0, src.value(), None)
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastw_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: Memory<W16>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 2, 0x79, false, dst.value(), 0, mem.into(), None)
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastd_vec_xmm<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rr::<Width>(1, 2, 0x58, false, dst.value(), 0, src.value(), None)
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastd_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: Memory<W32>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 2, 0x58, false, dst.value(), 0, mem.into(), None)
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastq_vec_xmm<Width: VectorWidth, V: VectorRegister<Width>>(
+        &mut self,
+        dst: V,
+        src: Xmm,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rr::<Width>(1, 2, 0x59, false, dst.value(), 0, src.value(), None)
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastq_vec_mem<Width: VectorWidth, V: VectorRegister<Width>, M: Memory<W64>>(
+        &mut self,
+        dst: V,
+        mem: M,
+    ) -> Result<()> {
+        let dst: Xmm = dst.into();
+        self.op_avx_rm::<Width>(1, 2, 0x59, false, dst.value(), 0, mem.into(), None)
+    }
+
+    pub fn vmovaps_mem_vec<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        mem: M,
+        src: V,
+    ) -> Result<()> {
+        let src: Xmm = src.into();
+        self.op_avx_rm::<Width>(0, 1, 0x29, false, src.value(), 0, mem.into(), None)
+    }
+
+    pub fn vmovups_mem_vec<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        mem: M,
+        src: V,
+    ) -> Result<()> {
+        let src: Xmm = src.into();
+        self.op_avx_rm::<Width>(0, 1, 0x11, false, src.value(), 0, mem.into(), None)
+    }
+
+    pub fn vmovapd_mem_vec<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        mem: M,
+        src: V,
+    ) -> Result<()> {
+        let src: Xmm = src.into();
+        self.op_avx_rm::<Width>(1, 1, 0x29, false, src.value(), 0, mem.into(), None)
+    }
+
+    pub fn vmovupd_mem_vec<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        mem: M,
+        src: V,
+    ) -> Result<()> {
+        let src: Xmm = src.into();
+        self.op_avx_rm::<Width>(1, 1, 0x11, false, src.value(), 0, mem.into(), None)
+    }
+
+    pub fn vmovdqa_mem_vec<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        mem: M,
+        src: V,
+    ) -> Result<()> {
+        let src: Xmm = src.into();
+        self.op_avx_rm::<Width>(1, 1, 0x7f, false, src.value(), 0, mem.into(), None)
+    }
+
+    pub fn vmovdqu_mem_vec<Width: VectorWidth, V: VectorRegister<Width>, M: VectorMemory<Width>>(
+        &mut self,
+        mem: M,
+        src: V,
+    ) -> Result<()> {
+        let src: Xmm = src.into();
+        self.op_avx_rm::<Width>(2, 1, 0x7f, false, src.value(), 0, mem.into(), None)
+    }
+
+    pub fn vaddps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x58,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vaddps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x58,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vaddps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x58,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vaddps_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x58,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vaddpd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x58,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vaddpd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x58,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vaddpd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x58,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vaddpd_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x58,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vsubps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x5c,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vsubps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x5c,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vsubps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x5c,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vsubps_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x5c,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vsubpd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x5c,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vsubpd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x5c,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vsubpd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x5c,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vsubpd_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x5c,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vmulps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x59,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmulps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x59,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vmulps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x59,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vmulps_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x59,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vmulpd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x59,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmulpd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x59,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vmulpd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x59,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vmulpd_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x59,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vdivps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x5e,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vdivps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x5e,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vdivps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x5e,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vdivps_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x5e,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vdivpd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x5e,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vdivpd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x5e,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vdivpd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x5e,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vdivpd_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x5e,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vminps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x5d,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vminps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x5d,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vminps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x5d,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vminpd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x5d,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vminpd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x5d,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vminpd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x5d,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vmaxps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x5f,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmaxps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x5f,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vmaxps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x5f,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vmaxpd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x5f,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmaxpd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x5f,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vmaxpd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x5f,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vunpcklps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x14,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vunpcklps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x14,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vunpcklps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x14,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vunpcklpd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x14,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vunpcklpd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x14,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vunpcklpd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x14,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vunpckhps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x15,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vunpckhps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x15,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vunpckhps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x15,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vunpckhpd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x15,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vunpckhpd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x15,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vunpckhpd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x15,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vshufps_zmm_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0xc6,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vshufps_zmm_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0xc6,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vshufps_zmm_zmm_bcst_imm8<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0xc6,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vshufpd_zmm_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xc6,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vshufpd_zmm_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xc6,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vshufpd_zmm_zmm_bcst_imm8<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xc6,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vfmadd132ps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x98,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vfmadd132ps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x98,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vfmadd132ps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x98,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vfmadd132ps_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x98,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vfmadd132pd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x98,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vfmadd132pd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x98,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vfmadd132pd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x98,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vfmadd132pd_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x98,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vfmadd213ps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xa8,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vfmadd213ps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xa8,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vfmadd213ps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xa8,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vfmadd213ps_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xa8,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vfmadd213pd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xa8,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vfmadd213pd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xa8,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vfmadd213pd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xa8,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vfmadd213pd_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xa8,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vfmadd231ps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xb8,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vfmadd231ps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xb8,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vfmadd231ps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xb8,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vfmadd231ps_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xb8,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vfmadd231pd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xb8,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vfmadd231pd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xb8,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vfmadd231pd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xb8,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vfmadd231pd_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xb8,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vfmsub231ps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xba,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vfmsub231ps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xba,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vfmsub231ps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xba,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vfmsub231ps_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xba,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vfmsub231pd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xba,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vfmsub231pd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xba,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vfmsub231pd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xba,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vfmsub231pd_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xba,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vfnmadd231ps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xbc,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vfnmadd231ps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xbc,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vfnmadd231ps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xbc,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vfnmadd231ps_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xbc,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vfnmadd231pd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xbc,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vfnmadd231pd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xbc,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vfnmadd231pd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0xbc,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vfnmadd231pd_zmm_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0xbc,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vpaddd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xfe,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpaddd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xfe,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpaddd_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xfe,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpaddq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xd4,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpaddq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xd4,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpaddq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xd4,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpsubd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xfa,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpsubd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xfa,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpsubd_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xfa,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpsubq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xfb,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpsubq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xfb,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpsubq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xfb,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpmulld_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x40,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpmulld_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x40,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpmulld_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x40,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpmuludq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xf4,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpmuludq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xf4,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpmuludq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xf4,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpandd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xdb,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpandd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xdb,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpandd_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xdb,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpandq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xdb,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpandq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xdb,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpandq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xdb,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpandnd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xdf,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpandnd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xdf,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpandnd_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xdf,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpandnq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xdf,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpandnq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xdf,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpandnq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xdf,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpord_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xeb,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpord_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xeb,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpord_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xeb,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vporq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xeb,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vporq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xeb,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vporq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xeb,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpxord_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xef,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpxord_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xef,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpxord_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xef,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpxorq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xef,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpxorq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xef,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpxorq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xef,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpminsd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x39,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpminsd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x39,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpminsd_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x39,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpminsq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x39,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpminsq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x39,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpminsq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x39,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpmaxsd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x3d,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpmaxsd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3d,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpmaxsd_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3d,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpmaxsq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x3d,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpmaxsq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3d,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpmaxsq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3d,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpminud_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x3b,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpminud_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3b,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpminud_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3b,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpminuq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x3b,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpminuq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3b,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpminuq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3b,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpmaxud_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x3f,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpmaxud_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3f,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpmaxud_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3f,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpmaxuq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x3f,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpmaxuq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3f,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpmaxuq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x3f,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpsllvd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x47,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpsllvd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x47,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpsllvd_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x47,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpsllvq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x47,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpsllvq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x47,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpsllvq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x47,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpsrlvd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x45,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpsrlvd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x45,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpsrlvd_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x45,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpsrlvq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x45,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpsrlvq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x45,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpsrlvq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x45,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpsravd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x46,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpsravd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x46,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpsravd_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x46,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpsravq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x46,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpsravq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x46,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpsravq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x46,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x36,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x36,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpermd_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x36,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x36,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x36,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpermq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x36,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x16,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x16,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpermps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x16,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermpd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x16,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermpd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x16,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpermpd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x16,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    /// The indices are in `dst`, which gets overwritten.
+    pub fn vpermi2d_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x76,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// The indices are in `dst`, which gets overwritten.
+    pub fn vpermi2d_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x76,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpermi2d_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x76,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    /// The indices are in `dst`, which gets overwritten.
+    pub fn vpermi2q_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x76,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// The indices are in `dst`, which gets overwritten.
+    pub fn vpermi2q_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x76,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpermi2q_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x76,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermt2d_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x7e,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermt2d_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x7e,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpermt2d_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x7e,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermt2q_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x7e,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// The indices are in `src1`.
+    pub fn vpermt2q_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x7e,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpermt2q_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x7e,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    /// Elements with a set bit in the mask come from `src2`, the others from `src1`.
+    pub fn vpblendmd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x64,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// Elements with a set bit in the mask come from `src2`, the others from `src1`.
+    pub fn vpblendmd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x64,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpblendmd_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x64,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    /// Elements with a set bit in the mask come from `src2`, the others from `src1`.
+    pub fn vpblendmq_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x64,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// Elements with a set bit in the mask come from `src2`, the others from `src1`.
+    pub fn vpblendmq_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x64,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpblendmq_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x64,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    /// Elements with a set bit in the mask come from `src2`, the others from `src1`.
+    pub fn vblendmps_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x65,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// Elements with a set bit in the mask come from `src2`, the others from `src1`.
+    pub fn vblendmps_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x65,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vblendmps_zmm_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x65,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    /// Elements with a set bit in the mask come from `src2`, the others from `src1`.
+    pub fn vblendmpd_zmm_zmm_zmm(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x65,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// Elements with a set bit in the mask come from `src2`, the others from `src1`.
+    pub fn vblendmpd_zmm_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x65,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vblendmpd_zmm_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x65,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    /// `imm` is the truth table, indexed by the bits of `dst`, `src1` and `src2`.
+    pub fn vpternlogd_zmm_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x25,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    /// `imm` is the truth table, indexed by the bits of `dst`, `src1` and `src2`.
+    pub fn vpternlogd_zmm_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x25,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpternlogd_zmm_zmm_bcst_imm8<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x25,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            Some(imm),
+        )
+    }
+
+    /// `imm` is the truth table, indexed by the bits of `dst`, `src1` and `src2`.
+    pub fn vpternlogq_zmm_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x25,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    /// `imm` is the truth table, indexed by the bits of `dst`, `src1` and `src2`.
+    pub fn vpternlogq_zmm_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x25,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpternlogq_zmm_zmm_bcst_imm8<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x25,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn valignd_zmm_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x3,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn valignd_zmm_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x3,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn valignd_zmm_zmm_bcst_imm8<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x3,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn valignq_zmm_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x3,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn valignq_zmm_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x3,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn valignq_zmm_zmm_bcst_imm8<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x3,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vshuff32x4_zmm_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x23,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vshuff32x4_zmm_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x23,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vshuff32x4_zmm_zmm_bcst_imm8<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x23,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vshuff64x2_zmm_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x23,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vshuff64x2_zmm_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x23,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vshuff64x2_zmm_zmm_bcst_imm8<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x23,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vshufi32x4_zmm_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x43,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vshufi32x4_zmm_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x43,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vshufi32x4_zmm_zmm_bcst_imm8<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x43,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            4,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vshufi64x2_zmm_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        src2: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x43,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vshufi64x2_zmm_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x43,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vshufi64x2_zmm_zmm_bcst_imm8<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        src1: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x43,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            masking,
+            8,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vpcmpeqd_k_zmm_zmm(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x76,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            None,
+        )
+    }
+
+    pub fn vpcmpeqd_k_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x76,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpcmpeqd_k_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x76,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpcmpeqq_k_zmm_zmm(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x29,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            None,
+        )
+    }
+
+    pub fn vpcmpeqq_k_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x29,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpcmpeqq_k_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x29,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpcmpgtd_k_zmm_zmm(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x66,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            None,
+        )
+    }
+
+    pub fn vpcmpgtd_k_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x66,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpcmpgtd_k_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x66,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpcmpgtq_k_zmm_zmm(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x37,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            None,
+        )
+    }
+
+    pub fn vpcmpgtq_k_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x37,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpcmpgtq_k_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x37,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpcmpd_k_zmm_zmm_imm8(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x1f,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vpcmpd_k_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x1f,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpcmpd_k_zmm_bcst_imm8<M: Memory<W32>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x1f,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            4,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vpcmpq_k_zmm_zmm_imm8(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x1f,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vpcmpq_k_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x1f,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpcmpq_k_zmm_bcst_imm8<M: Memory<W64>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x1f,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            8,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vpcmpud_k_zmm_zmm_imm8(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x1e,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vpcmpud_k_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x1e,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpcmpud_k_zmm_bcst_imm8<M: Memory<W32>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x1e,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            4,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vpcmpuq_k_zmm_zmm_imm8(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x1e,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vpcmpuq_k_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x1e,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vpcmpuq_k_zmm_bcst_imm8<M: Memory<W64>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x1e,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            8,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vcmpps_k_zmm_zmm_imm8(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0xc2,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vcmpps_k_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0xc2,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vcmpps_k_zmm_bcst_imm8<M: Memory<W32>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0xc2,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            4,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vcmppd_k_zmm_zmm_imm8(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0xc2,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vcmppd_k_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xc2,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vcmppd_k_zmm_bcst_imm8<M: Memory<W64>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0xc2,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            8,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vptestmd_k_zmm_zmm(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x27,
+            false,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            None,
+        )
+    }
+
+    pub fn vptestmd_k_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x27,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vptestmd_k_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x27,
+            false,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vptestmq_k_zmm_zmm(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        src2: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x27,
+            true,
+            dst.value(),
+            src1.value(),
+            src2.value(),
+            mask.into(),
+            None,
+            None,
+        )
+    }
+
+    pub fn vptestmq_k_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x27,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the second source.
+    pub fn vptestmq_k_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Opmask,
+        src1: Zmm,
+        mem: M,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x27,
+            true,
+            dst.value(),
+            src1.value(),
+            mem.into(),
+            mask.into(),
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vmovaps_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x28,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmovaps_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x28,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovapd_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x28,
+            true,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmovapd_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x28,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovups_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x10,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmovups_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x10,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovupd_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x10,
+            true,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmovupd_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x10,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovdqa32_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x6f,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmovdqa32_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x6f,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovdqa64_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x6f,
+            true,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmovdqa64_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x6f,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovdqu32_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            2,
+            1,
+            0x6f,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmovdqu32_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            2,
+            1,
+            0x6f,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovdqu64_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            2,
+            1,
+            0x6f,
+            true,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vmovdqu64_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            2,
+            1,
+            0x6f,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vsqrtps_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x51,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vsqrtps_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x51,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the source.
+    pub fn vsqrtps_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x51,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vsqrtps_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x51,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vsqrtpd_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x51,
+            true,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vsqrtpd_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x51,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the source.
+    pub fn vsqrtpd_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x51,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vsqrtpd_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x51,
+            true,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vcvtdq2ps_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x5b,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vcvtdq2ps_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x5b,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the source.
+    pub fn vcvtdq2ps_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x5b,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vcvtdq2ps_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            0,
+            1,
+            0x5b,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vcvtps2dq_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x5b,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vcvtps2dq_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x5b,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the source.
+    pub fn vcvtps2dq_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x5b,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vcvtps2dq_zmm_zmm_round(
+        &mut self,
+        dst: Zmm,
+        src: Zmm,
+        masking: Masking,
+        rounding: Rounding,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x5b,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            Some(rounding),
+            None,
+        )
+    }
+
+    pub fn vcvttps2dq_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            2,
+            1,
+            0x5b,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vcvttps2dq_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            2,
+            1,
+            0x5b,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the source.
+    pub fn vcvttps2dq_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            2,
+            1,
+            0x5b,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpabsd_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x1e,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpabsd_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x1e,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the source.
+    pub fn vpabsd_zmm_bcst<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x1e,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            4,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpabsq_zmm_zmm(&mut self, dst: Zmm, src: Zmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x1f,
+            true,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    pub fn vpabsq_zmm_mem<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x1f,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            None,
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the source.
+    pub fn vpabsq_zmm_bcst<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x1f,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            8,
+            true,
+            None,
+        )
+    }
+
+    pub fn vpshufd_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            1,
+            0x70,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vpshufd_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x70,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the source.
+    pub fn vpshufd_zmm_bcst_imm8<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x70,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            4,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vpermq_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x0,
+            true,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vpermq_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x0,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the source.
+    pub fn vpermq_zmm_bcst_imm8<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x0,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            8,
+            true,
+            Some(imm),
+        )
+    }
+
+    pub fn vpermpd_zmm_zmm_imm8(
+        &mut self,
+        dst: Zmm,
+        src: Zmm,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            3,
+            0x1,
+            true,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            Some(imm),
+        )
+    }
+
+    pub fn vpermpd_zmm_mem_imm8<M: VectorMemory<W512>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x1,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            64,
+            false,
+            Some(imm),
+        )
+    }
+
+    /// Broadcasts the element at `mem` to every element of the source.
+    pub fn vpermpd_zmm_bcst_imm8<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+        imm: u8,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            3,
+            0x1,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            8,
+            true,
+            Some(imm),
+        )
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vbroadcastss_zmm_xmm(&mut self, dst: Zmm, src: Xmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x18,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vbroadcastss_zmm_mem<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x18,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            4,
+            false,
+            None,
+        )
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vbroadcastsd_zmm_xmm(&mut self, dst: Zmm, src: Xmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x19,
+            true,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vbroadcastsd_zmm_mem<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x19,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            8,
+            false,
+            None,
+        )
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastd_zmm_xmm(&mut self, dst: Zmm, src: Xmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x58,
+            false,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastd_zmm_mem<M: Memory<W32>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x58,
+            false,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            4,
+            false,
+            None,
+        )
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastq_zmm_xmm(&mut self, dst: Zmm, src: Xmm, masking: Masking) -> Result<()> {
+        self.op_evex_rr::<W512>(
+            1,
+            2,
+            0x59,
+            true,
+            dst.value(),
+            0,
+            src.value(),
+            masking,
+            None,
+            None,
+        )
+    }
+
+    /// Copies the low element of the source to every element of `dst`.
+    pub fn vpbroadcastq_zmm_mem<M: Memory<W64>>(
+        &mut self,
+        dst: Zmm,
+        mem: M,
+        masking: Masking,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            2,
+            0x59,
+            true,
+            dst.value(),
+            0,
+            mem.into(),
+            masking,
+            8,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovaps_mem_zmm<M: VectorMemory<W512>>(
+        &mut self,
+        mem: M,
+        src: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x29,
+            false,
+            src.value(),
+            0,
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovapd_mem_zmm<M: VectorMemory<W512>>(
+        &mut self,
+        mem: M,
+        src: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x29,
+            true,
+            src.value(),
+            0,
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovups_mem_zmm<M: VectorMemory<W512>>(
+        &mut self,
+        mem: M,
+        src: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            0,
+            1,
+            0x11,
+            false,
+            src.value(),
+            0,
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovupd_mem_zmm<M: VectorMemory<W512>>(
+        &mut self,
+        mem: M,
+        src: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x11,
+            true,
+            src.value(),
+            0,
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovdqa32_mem_zmm<M: VectorMemory<W512>>(
+        &mut self,
+        mem: M,
+        src: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x7f,
+            false,
+            src.value(),
+            0,
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovdqa64_mem_zmm<M: VectorMemory<W512>>(
+        &mut self,
+        mem: M,
+        src: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            1,
+            1,
+            0x7f,
+            true,
+            src.value(),
+            0,
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovdqu32_mem_zmm<M: VectorMemory<W512>>(
+        &mut self,
+        mem: M,
+        src: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            2,
+            1,
+            0x7f,
+            false,
+            src.value(),
+            0,
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
+
+    pub fn vmovdqu64_mem_zmm<M: VectorMemory<W512>>(
+        &mut self,
+        mem: M,
+        src: Zmm,
+        mask: Option<Opmask>,
+    ) -> Result<()> {
+        self.op_evex_rm::<W512>(
+            2,
+            1,
+            0x7f,
+            true,
+            src.value(),
+            0,
+            mem.into(),
+            mask.into(),
+            64,
+            false,
+            None,
+        )
+    }
 }
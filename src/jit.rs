@@ -0,0 +1,270 @@
+//! Running assembled code in the current process.
+//!
+//! Code is copied into a fresh mapping while it's writable, relocated to its final address,
+//! and only then made executable, so the mapping is never writable and executable at the same time.
+
+use crate::{Assembled, Error, Label, Result};
+
+use std::collections::HashMap;
+use std::ffi::{c_int, c_long, c_void};
+use std::io;
+use std::marker::PhantomData;
+use std::ptr::{self, NonNull};
+
+const PROT_READ: c_int = 0x1;
+const PROT_WRITE: c_int = 0x2;
+const PROT_EXEC: c_int = 0x4;
+const MAP_PRIVATE: c_int = 0x02;
+const MAP_ANONYMOUS: c_int = 0x20;
+const SC_PAGESIZE: c_int = 30;
+
+unsafe extern "C" {
+    fn mmap(
+        addr: *mut c_void,
+        len: usize,
+        prot: c_int,
+        flags: c_int,
+        fd: c_int,
+        offset: i64,
+    ) -> *mut c_void;
+    fn mprotect(addr: *mut c_void, len: usize, prot: c_int) -> c_int;
+    fn munmap(addr: *mut c_void, len: usize) -> c_int;
+    fn sysconf(name: c_int) -> c_long;
+}
+
+/// A function pointer type that code can be called through, this is every `extern "C" fn` with up to 6 arguments.
+pub trait JitFn: Copy + private::Sealed {
+    #[doc(hidden)]
+    unsafe fn from_ptr(ptr: *const u8) -> Self;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_jit_fn {
+    ($($arg:ident $name:ident),*) => {
+        impl<R, $($arg),*> private::Sealed for extern "C" fn($($arg),*) -> R {}
+        impl<R, $($arg),*> JitFn for extern "C" fn($($arg),*) -> R {
+            unsafe fn from_ptr(ptr: *const u8) -> Self {
+                unsafe { std::mem::transmute::<*const u8, Self>(ptr) }
+            }
+        }
+
+        impl<R, $($arg),*> private::Sealed for unsafe extern "C" fn($($arg),*) -> R {}
+        impl<R, $($arg),*> JitFn for unsafe extern "C" fn($($arg),*) -> R {
+            unsafe fn from_ptr(ptr: *const u8) -> Self {
+                unsafe { std::mem::transmute::<*const u8, Self>(ptr) }
+            }
+        }
+
+        impl<R, $($arg),*> Function<'_, extern "C" fn($($arg),*) -> R> {
+            /// Calls the function.
+            pub fn call(&self, $($name: $arg),*) -> R {
+                (self.f)($($name),*)
+            }
+        }
+
+        impl<R, $($arg),*> Function<'_, unsafe extern "C" fn($($arg),*) -> R> {
+            /// Calls the function.
+            ///
+            /// # Safety
+            /// Whatever the function itself requires.
+            pub unsafe fn call(&self, $($name: $arg),*) -> R {
+                unsafe { (self.f)($($name),*) }
+            }
+        }
+    };
+}
+
+impl_jit_fn!();
+impl_jit_fn!(A a);
+impl_jit_fn!(A a, B b);
+impl_jit_fn!(A a, B b, C c);
+impl_jit_fn!(A a, B b, C c, D d);
+impl_jit_fn!(A a, B b, C c, D d, E e);
+impl_jit_fn!(A a, B b, C c, D d, E e, F f);
+
+/// Executable memory holding a copy of assembled code, it gets unmapped on drop.
+pub struct JitMemory {
+    ptr: NonNull<u8>,
+    len: usize,
+    mapped_len: usize,
+}
+
+impl JitMemory {
+    /// Maps `code` as executable, applying every relocation in `assembled` with external symbols at the addresses in `symbols`.
+    ///
+    /// # Errors
+    /// If mapping fails, or relocating does (see [`Assembled::relocate`]).
+    ///
+    /// # Panics
+    /// If `code` isn't exactly as long as the assembled code.
+    pub fn new(code: &[u8], assembled: &Assembled, symbols: &HashMap<String, u64>) -> Result<Self> {
+        assert_eq!(code.len() as u64, assembled.code_len());
+
+        let page_size = unsafe { sysconf(SC_PAGESIZE) } as usize;
+
+        // zero sized mappings aren't allowed, so empty code still gets a page.
+        let mapped_len = code.len().max(1).next_multiple_of(page_size);
+
+        let ptr = unsafe {
+            mmap(
+                ptr::null_mut(),
+                mapped_len,
+                PROT_READ | PROT_WRITE,
+                MAP_PRIVATE | MAP_ANONYMOUS,
+                -1,
+                0,
+            )
+        };
+
+        // MAP_FAILED
+        if ptr as usize == usize::MAX {
+            return Err(io::Error::last_os_error().into());
+        }
+
+        // from here on, drop cleans up the mapping.
+        let memory = Self {
+            ptr: NonNull::new(ptr.cast()).expect("mmap never succeeds with null"),
+            len: code.len(),
+            mapped_len,
+        };
+
+        let buf = unsafe { std::slice::from_raw_parts_mut(memory.ptr.as_ptr(), code.len()) };
+        buf.copy_from_slice(code);
+        assembled.relocate(buf, memory.address(), symbols)?;
+
+        if unsafe { mprotect(ptr, mapped_len, PROT_READ | PROT_EXEC) } != 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+
+        Ok(memory)
+    }
+
+    /// The number of bytes of code.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The address the code starts at.
+    #[must_use]
+    pub fn address(&self) -> u64 {
+        self.ptr.as_ptr() as u64
+    }
+
+    /// Returns a function pointer to the code at `offset` bytes from the start.
+    ///
+    /// # Safety
+    /// There has to be a function with the signature `F` at `offset`, calling through the pointer is otherwise undefined behavior.
+    ///
+    /// # Panics
+    /// If `offset` is past the end of the code.
+    #[must_use]
+    pub unsafe fn function_at<F: JitFn>(&self, offset: u64) -> Function<'_, F> {
+        assert!(offset < self.len as u64);
+
+        Function {
+            f: unsafe { F::from_ptr(self.ptr.as_ptr().add(offset as usize)) },
+            _memory: PhantomData,
+        }
+    }
+
+    /// Returns a function pointer to the code at `label`.
+    ///
+    /// # Safety
+    /// See [`function_at`](Self::function_at).
+    ///
+    /// # Errors
+    /// If `label` was never attached.
+    pub unsafe fn function<F: JitFn>(
+        &self,
+        assembled: &Assembled,
+        label: Label,
+    ) -> Result<Function<'_, F>> {
+        let offset = assembled
+            .label_offset(label)
+            .ok_or(Error::UnresolvedLabel(label))?;
+
+        Ok(unsafe { self.function_at(offset) })
+    }
+}
+
+impl Drop for JitMemory {
+    fn drop(&mut self) {
+        unsafe {
+            munmap(self.ptr.as_ptr().cast(), self.mapped_len);
+        }
+    }
+}
+
+/// A function pointer into [`JitMemory`], it can't outlive the mapping it points into.
+///
+/// The pointer itself is never handed out, it's only reachable through `call`, so it can't be copied out of the borrow:
+///
+/// ```compile_fail
+/// # use rasen::{Assembled, Label, Result};
+/// # use rasen::jit::JitMemory;
+/// # fn escape(memory: JitMemory, assembled: &Assembled, entry: Label) -> Result<()> {
+/// let f: extern "C" fn() -> u64 = *unsafe { memory.function(assembled, entry)? };
+/// drop(memory);
+/// f();
+/// # Ok(())
+/// # }
+/// ```
+///
+/// And it can't be used once the memory is gone:
+///
+/// ```compile_fail
+/// # use rasen::{Assembled, Label, Result};
+/// # use rasen::jit::JitMemory;
+/// # fn escape(memory: JitMemory, assembled: &Assembled, entry: Label) -> Result<()> {
+/// let f = unsafe { memory.function::<extern "C" fn() -> u64>(assembled, entry)? };
+/// drop(memory);
+/// f.call();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone)]
+pub struct Function<'a, F> {
+    f: F,
+    _memory: PhantomData<&'a JitMemory>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::JitMemory;
+    use crate::params::imm::Imm64;
+    use crate::params::mem::Mem;
+    use crate::params::reg::Reg64;
+    use crate::{Assembler, CodeBuffer, Result};
+
+    use std::collections::HashMap;
+
+    #[test]
+    fn runs_code() -> Result<()> {
+        let mut buffer = CodeBuffer::new();
+        let mut assembler = Assembler::new(&mut buffer)?;
+
+        let constant = assembler.constant_u64(0x1000);
+        let entry = assembler.make_label_attached();
+        assembler.mov_reg_reg(Reg64::ZAX, Reg64::ZDI)?;
+        assembler.add_reg_imm(Reg64::ZAX, Imm64(5))?;
+        assembler.add_reg_mem(Reg64::ZAX, Mem::label(constant))?;
+        assembler.ret()?;
+
+        let assembled = assembler.finish()?;
+        let memory = JitMemory::new(&buffer, &assembled, &HashMap::new())?;
+
+        let add = unsafe { memory.function::<extern "C" fn(u64) -> u64>(&assembled, entry)? };
+        assert_eq!(add.call(10), 0x100f);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Label;
+use crate::{Error, Label, Result};
 
 use std::collections::HashMap;
 #[derive(Default)]
@@ -20,19 +20,36 @@ impl Labeler {
 
     pub fn create_attached_label(&mut self, address: u64) -> Label {
         let label = self.create_label();
-        self.attach_label(label, address);
+        // a fresh label can't have been attached yet.
+        self.label_values.insert(label, address);
         label
     }
 
-    pub fn attach_label(&mut self, label: Label, address: u64) {
+    pub fn attach_label(&mut self, label: Label, address: u64) -> Result<()> {
         if self.label_values.contains_key(&label) {
-            todo!("handle label with already existing label")
+            return Err(Error::DuplicateLabel(label));
         }
 
         self.label_values.insert(label, address);
+
+        Ok(())
     }
 
     pub fn resolve_label(&self, label: Label) -> Option<u64> {
         self.label_values.get(&label).copied()
     }
+
+    /// Every label that has been attached, along with its address.
+    pub fn attached(&self) -> impl Iterator<Item = (Label, u64)> + '_ {
+        self.label_values
+            .iter()
+            .map(|(label, address)| (*label, *address))
+    }
+
+    /// Moves an attached label, used when code before it changes size.
+    pub fn remap_label(&mut self, label: Label, f: impl Fn(u64) -> u64) {
+        if let Some(address) = self.label_values.get_mut(&label) {
+            *address = f(*address);
+        }
+    }
 }
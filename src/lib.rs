@@ -1,65 +1,138 @@
 #![allow(
     clippy::inline_always,
     clippy::cast_sign_loss,
-    clippy::cast_possible_truncation
+    clippy::cast_possible_truncation,
+    clippy::upper_case_acronyms
 )]
 
-use std::io::{self, Seek, SeekFrom, Write};
-
 use std::collections::HashMap;
 
+mod align;
+pub mod elf;
 mod emitter;
+mod error;
 mod fns;
+#[cfg(all(feature = "jit", target_os = "linux", target_arch = "x86_64"))]
+pub mod jit;
 mod labeler;
 pub mod params;
+mod pool;
+mod relaxer;
+mod relocation;
+mod sink;
 
 // This is for an x86 assembler for now, TODO: move into a specific module for x86?
 
 use crate::params::{
+    Masking,
     imm::WritableImmediate,
     mem::{Displacement, ModRM, SIB},
 };
+use align::Fill;
 use emitter::Emitter;
+pub use error::{Error, Result};
 use labeler::Labeler;
+use pool::ConstantPool;
+use relaxer::{BranchKind, Relaxer};
+pub use relocation::{Assembled, Relocation, RelocationKind, RelocationTarget};
+pub use sink::{CodeBuffer, CodeSink};
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
 pub struct Label(usize);
 
+/// A reference to something outside of the assembled code, such as a runtime helper function.
+///
+/// Uses of a symbol are never patched by the assembler, they show up as relocations when finishing.
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub struct Symbol(usize);
+
 // unresolved todo: should assembler impl Drop?
 pub struct Assembler<'a, T>
 where
-    T: Write + Seek,
+    T: CodeSink,
 {
     emitter: Emitter<'a, T>,
-    unresolved_labels: HashMap<u64, Label>,
+    /// Every relocation site so far, offsets here are _not_ relative to `start_offset` until `finish`.
+    relocations: Vec<Relocation>,
+    /// Indices into `relocations` that are still placeholders.
+    unresolved: Vec<usize>,
     labeler: Labeler,
+    symbols: Vec<String>,
+    symbol_lookup: HashMap<String, Symbol>,
+    label_names: Vec<(Label, String)>,
+    /// The currently open region of branches that haven't been sized yet, if any.
+    relaxer: Option<Relaxer>,
+    pool: ConstantPool,
 }
 
 impl<'a, T> Assembler<'a, T>
 where
-    T: Write + Seek,
+    T: CodeSink,
 {
-    pub fn new(writer: &'a mut T) -> io::Result<Self> {
+    pub fn new(writer: &'a mut T) -> Result<Self> {
         Ok(Self {
             emitter: Emitter::new(writer)?,
-            unresolved_labels: HashMap::new(),
+            relocations: Vec::new(),
+            unresolved: Vec::new(),
             labeler: Labeler::new(),
+            symbols: Vec::new(),
+            symbol_lookup: HashMap::new(),
+            label_names: Vec::new(),
+            relaxer: None,
+            pool: ConstantPool::new(),
         })
     }
 
-    pub fn finish(mut self) -> io::Result<()> {
-        for (use_addr, label) in self.unresolved_labels.drain() {
-            match self.labeler.resolve_label(label) {
-                Some(label_addr) => {
-                    self.emitter
-                        .write_qword_seek(SeekFrom::Start(use_addr), label_addr)?;
-                }
+    /// Writes out anything that's still pending (including the constant pool) and patches every use of a label that was attached late.
+    ///
+    /// Returns a summary of the code that was written, including every relocation in it.
+    ///
+    /// # Errors
+    /// If any label that was used never got attached, or writing fails.
+    pub fn finish(mut self) -> Result<Assembled> {
+        if let Some(label) = self.relaxer.as_ref().and_then(Relaxer::unattached) {
+            return Err(Error::UnresolvedLabel(label));
+        }
 
-                None => todo!("What should we do if we failed to resolve a label while finishing?"),
-            }
+        self.flush_pool()?;
+        self.flush_branches()?;
+
+        for idx in std::mem::take(&mut self.unresolved) {
+            let relocation = self.relocations[idx];
+            let RelocationTarget::Label(label) = relocation.target else {
+                unreachable!("symbols are never resolved by the assembler");
+            };
+
+            let Some(label_addr) = self.labeler.resolve_label(label) else {
+                return Err(Error::UnresolvedLabel(label));
+            };
+
+            let value = self.relocation_value(relocation, label_addr)?;
+            let size = usize::from(relocation.kind.size());
+            self.emitter
+                .patch(relocation.offset, &value.to_le_bytes()[..size])?;
         }
 
-        Ok(())
+        let start = self.start_offset();
+        let len = self.current_offset() - start;
+
+        for relocation in &mut self.relocations {
+            relocation.offset -= start;
+        }
+
+        let labels = self
+            .labeler
+            .attached()
+            .map(|(label, address)| (label, address - start))
+            .collect();
+
+        Ok(Assembled::new(
+            len,
+            self.relocations,
+            labels,
+            self.label_names,
+            self.symbols,
+        ))
     }
 
     /// Creates a label
@@ -67,37 +140,295 @@ where
         self.labeler.create_label()
     }
 
+    /// Creates a label with a name, the name is kept in [`Assembled`] for things like object file symbols.
+    pub fn make_named_label(&mut self, name: &str) -> Label {
+        let label = self.labeler.create_label();
+        self.label_names.push((label, name.to_owned()));
+        label
+    }
+
     /// Creates a label and attaches it at the current RIP
     pub fn make_label_attached(&mut self) -> Label {
-        self.labeler.create_attached_label(self.current_offset())
+        let label = self.labeler.create_attached_label(self.current_offset());
+
+        // a fresh label can't be the target of a branch, so this never finishes the region.
+        if let Some(relaxer) = &mut self.relaxer {
+            relaxer.label_attached(label);
+        }
+
+        label
+    }
+
+    /// Creates a reference to the external symbol `name`, asking for the same name twice gives the same symbol.
+    pub fn external_symbol(&mut self, name: &str) -> Symbol {
+        if let Some(symbol) = self.symbol_lookup.get(name) {
+            return *symbol;
+        }
+
+        let symbol = Symbol(self.symbols.len());
+        self.symbols.push(name.to_owned());
+        self.symbol_lookup.insert(name.to_owned(), symbol);
+        symbol
+    }
+
+    /// Returns a label for the 64-bit constant `value`, it gets written out 8 byte aligned with the rest of the constant pool.
+    pub fn constant_u64(&mut self, value: u64) -> Label {
+        self.constant_bytes(&value.to_le_bytes(), 8)
+    }
+
+    /// Returns a label for the double precision constant `value`, it gets written out 8 byte aligned with the rest of the constant pool.
+    ///
+    /// Constants are compared bitwise, so `0.0` and `-0.0` are different constants.
+    pub fn constant_f64(&mut self, value: f64) -> Label {
+        self.constant_u64(value.to_bits())
+    }
+
+    /// Returns a label for `bytes`, it gets written out `align` byte aligned with the rest of the constant pool.
+    ///
+    /// Asking for the same bytes again before the pool is flushed gives the same label.
+    ///
+    /// # Panics
+    /// If `align` isn't a power of two.
+    pub fn constant_bytes(&mut self, bytes: &[u8], align: u64) -> Label {
+        assert!(align.is_power_of_two(), "{align} isn't a power of two");
+
+        let labeler = &mut self.labeler;
+        self.pool.insert(bytes, align, || labeler.create_label())
+    }
+
+    /// Writes out every constant that's been asked for since the last flush at the current RIP.
+    ///
+    /// The pool isn't code, so this should be somewhere that can't be reached, like after a `ret`.
+    /// Alignment is relative to [`start_offset`](Self::start_offset), any padding is filled with `int3`.
+    ///
+    /// # Errors
+    /// If writing fails.
+    pub fn flush_pool(&mut self) -> Result<()> {
+        if self.pool.is_empty() {
+            return Ok(());
+        }
+
+        for constant in self.pool.take() {
+            self.write_padding(constant.align, Fill::Byte(0xcc))?;
+            self.attach_label(constant.label)?;
+            self.emitter.write_all(&constant.bytes)?;
+        }
+
+        Ok(())
+    }
+
+    /// Pads with NOPs until the current RIP is a multiple of `align` bytes past [`start_offset`](Self::start_offset).
+    ///
+    /// The padding uses the longest NOP forms available (up to 9 bytes each), so it's cheap to fall through.
+    /// A label attached right after aligning is always aligned, even if branches before it change size.
+    ///
+    /// # Errors
+    /// If writing fails.
+    ///
+    /// # Panics
+    /// If `align` isn't a power of two.
+    pub fn align(&mut self, align: u64) -> Result<()> {
+        assert!(align.is_power_of_two(), "{align} isn't a power of two");
+        self.write_padding(align, Fill::Nop)
+    }
+
+    /// Pads with `fill` until the current RIP is a multiple of `align` bytes past [`start_offset`](Self::start_offset).
+    ///
+    /// # Errors
+    /// If writing fails.
+    ///
+    /// # Panics
+    /// If `align` isn't a power of two.
+    pub fn align_with(&mut self, align: u64, fill: u8) -> Result<()> {
+        assert!(align.is_power_of_two(), "{align} isn't a power of two");
+        self.write_padding(align, Fill::Byte(fill))
     }
 
     /// Attaches a label at the current RIP
     /// It is a logic error to use a label from a different instance.
-    pub fn attach_label(&mut self, label: Label) {
-        self.labeler.attach_label(label, self.current_offset())
+    ///
+    /// If this was the last outstanding target of a forward branch, the buffered code gets written out.
+    ///
+    /// # Errors
+    /// If the label has already been attached, or writing out buffered code fails.
+    pub fn attach_label(&mut self, label: Label) -> Result<()> {
+        self.labeler.attach_label(label, self.current_offset())?;
+
+        if self
+            .relaxer
+            .as_mut()
+            .is_some_and(|relaxer| relaxer.label_attached(label))
+        {
+            self.flush_branches()?;
+        }
+
+        Ok(())
     }
 
     /// Writes the value 64-bit value of a label relative to the current PC out to the stream.
     ///
     /// If the label hasn't been attached yet, it will store it in an internal buffer to resolve it on finish.
+    /// Those get patched with the same relative value as an already attached label would get.
     ///
     /// It is a logic error to use a label from a different instance.
-    /// # panics
-    /// If the label isn't contained in this assembler.
-    pub fn write_label(&mut self, label: Label) -> io::Result<()> {
-        let value = if let Some(label_value) = self.labeler.resolve_label(label) {
-            label_value.wrapping_sub(self.current_offset())
+    pub fn write_label(&mut self, label: Label) -> Result<()> {
+        self.write_relocation(RelocationKind::Rel64, RelocationTarget::Label(label), 0)
+    }
+
+    /// Writes the 64-bit address of a label, as if the code starts at address 0.
+    ///
+    /// The use shows up as an [`RelocationKind::Abs64`] relocation when finishing,
+    /// so that it can be fixed up once the code's actual address is known.
+    ///
+    /// It is a logic error to use a label from a different instance.
+    pub fn write_label_address(&mut self, label: Label) -> Result<()> {
+        self.write_relocation(RelocationKind::Abs64, RelocationTarget::Label(label), 0)
+    }
+
+    /// Writes the value of a relocation against `target` at the current PC,
+    /// or a placeholder to be patched on finish if that value isn't known yet.
+    pub(crate) fn write_relocation(
+        &mut self,
+        kind: RelocationKind,
+        target: RelocationTarget,
+        addend: i64,
+    ) -> Result<()> {
+        let relocation = Relocation {
+            offset: self.current_offset(),
+            kind,
+            target,
+            addend,
+        };
+
+        let value = match target {
+            // labels are going to move if there are pending branches, so they can't be trusted yet.
+            RelocationTarget::Label(label) => match self.labeler.resolve_label(label) {
+                Some(label_addr) if self.relaxer.is_none() => {
+                    self.relocation_value(relocation, label_addr)?
+                }
+
+                _ => {
+                    // if the label hasn't been resolved yet we should make sure that we can resolve it when `finish` is called.
+                    self.unresolved.push(self.relocations.len());
+
+                    // temporarily write UD2s just in case this somehow gets executed as code.
+                    // (Even though this is a relative address)
+                    0x0f0b_0f0b_0f0b_0f0b
+                }
+            },
+
+            // whoever loads the code is responsible for these.
+            RelocationTarget::Symbol(_) => 0,
+        };
+
+        self.relocations.push(relocation);
+
+        match kind {
+            RelocationKind::Abs64 | RelocationKind::Rel64 => self.write_qword(value),
+            RelocationKind::Rel32 | RelocationKind::Branch32 => self.write_dword(value as u32),
+            RelocationKind::Rel8 => self.write_byte(value as u8),
+        }
+    }
+
+    /// Computes the value of a relocation, checking that it fits.
+    fn relocation_value(&self, relocation: Relocation, target: u64) -> Result<u64> {
+        let start = self.start_offset();
+        let value =
+            relocation
+                .kind
+                .value(target - start, relocation.addend, relocation.offset - start);
+
+        if relocation.kind.fits(value) {
+            Ok(value)
         } else {
-            // if the label hasn't been resolved yet we should make sure that we can resolve it when `finish` is called.
-            // todo: should we panic if there's already a label here?
-            self.unresolved_labels.insert(self.current_offset(), label);
-            // temporarily write UD2 x 4 just in case this somehow gets executed as code.
-            // (Even though this is a relative address)
-            0x0f0b_0f0b_0f0b_0f0b
+            Err(Error::DisplacementOutOfRange {
+                target: relocation.target,
+                offset: relocation.offset,
+            })
+        }
+    }
+
+    /// Writes a branch to `label`, picking the short form whenever the target is in range.
+    ///
+    /// Branches to labels that haven't been attached yet can't be sized right away,
+    /// so everything written after them is held in memory until every such target gets attached.
+    /// Until then, [`current_offset`](Self::current_offset) is provisional,
+    /// as it assumes that every outstanding branch is short.
+    pub(crate) fn write_branch(&mut self, kind: BranchKind, label: Label) -> Result<()> {
+        let target = self.labeler.resolve_label(label);
+
+        if let (None, Some(target)) = (&self.relaxer, target) {
+            let short_end = self.current_offset() + kind.len(false);
+            let near = i8::try_from(target.wrapping_sub(short_end) as i64).is_err();
+            let end = self.current_offset() + kind.len(near);
+
+            let mut encoded = Vec::with_capacity(6);
+            kind.encode(near, target.wrapping_sub(end) as i32, &mut encoded);
+            self.relocations.push(kind.relocation(near, end, label));
+            return Ok(self.emitter.write_all(&encoded)?);
+        }
+
+        let offset = self.current_offset();
+        let origin = self.start_offset();
+        let first_relocation = self.relocations.len();
+        let relaxer = self.relaxer.get_or_insert_with(|| {
+            self.emitter.begin_buffering();
+            Relaxer::new(offset, origin, first_relocation)
+        });
+
+        relaxer.push(offset, kind, label, target.is_some());
+
+        // placeholder, this is always rewritten once the region is laid out.
+        Ok(self.emitter.write_all(&[0xeb, 0xfe])?)
+    }
+
+    /// Pads with `fill` until the current RIP is a multiple of `align` bytes past the start.
+    ///
+    /// Inside a region of unsized branches, the padding gets redone once the region is laid out.
+    fn write_padding(&mut self, align: u64, fill: Fill) -> Result<()> {
+        let offset = self.current_offset();
+        let len = align::padding_len(offset - self.start_offset(), align);
+
+        if let Some(relaxer) = &mut self.relaxer {
+            relaxer.push_align(offset, align, fill, len);
+        }
+
+        let mut padding = Vec::with_capacity(len as usize);
+        align::write_padding(len, fill, &mut padding);
+        Ok(self.emitter.write_all(&padding)?)
+    }
+
+    /// Sizes every branch in the open region and writes the region out.
+    fn flush_branches(&mut self) -> Result<()> {
+        let Some(mut relaxer) = self.relaxer.take() else {
+            return Ok(());
+        };
+
+        let labeler = &self.labeler;
+        let resolve = |label| {
+            labeler
+                .resolve_label(label)
+                .expect("branch targets are attached before their region is flushed")
         };
 
-        self.write_qword(value)
+        relaxer.layout(resolve);
+
+        let provisional = self.emitter.end_buffering(relaxer.start());
+        let code = relaxer.assemble(&provisional, resolve);
+
+        // anything from before the region is already where it's going to stay.
+        for label in relaxer.labels() {
+            self.labeler
+                .remap_label(label, |address| relaxer.remap_label(label, address));
+        }
+
+        for relocation in &mut self.relocations[relaxer.first_relocation()..] {
+            relocation.offset = relaxer.remap(relocation.offset);
+        }
+
+        self.relocations.extend(relaxer.relocations());
+
+        Ok(self.emitter.write_all(&code)?)
     }
 
     #[inline(always)]
@@ -113,50 +444,74 @@ where
     }
 
     #[inline(always)]
-    pub fn write_byte(&mut self, byte: u8) -> io::Result<()> {
-        self.emitter.write_byte(byte)
+    pub fn write_byte(&mut self, byte: u8) -> Result<()> {
+        Ok(self.emitter.write_byte(byte)?)
     }
 
     #[inline(always)]
-    pub(crate) fn write_mod_rm(&mut self, mod_rm: ModRM) -> io::Result<()> {
-        self.emitter.write_byte(mod_rm.into())
+    pub(crate) fn write_mod_rm(&mut self, mod_rm: ModRM) -> Result<()> {
+        self.write_byte(mod_rm.into())
     }
 
     #[inline(always)]
-    pub(crate) fn write_sib(&mut self, sib: SIB) -> io::Result<()> {
-        self.emitter.write_byte(sib.into())
+    pub(crate) fn write_sib(&mut self, sib: SIB) -> Result<()> {
+        self.write_byte(sib.into())
     }
 
-    pub(crate) fn write_displacement(&mut self, displacement: Displacement) -> io::Result<()> {
+    /// `trailing` is how many bytes of the instruction come after the displacement (the immediate, if any).
+    pub(crate) fn write_displacement(
+        &mut self,
+        displacement: Displacement,
+        trailing: u8,
+    ) -> Result<()> {
         match displacement {
             Displacement::Disp8(v) => self.write_byte(v as u8),
             Displacement::Disp32(v) => self.write_dword(v as u32),
+            Displacement::Relative(target, addend) => {
+                // RIP relative addressing is relative to the _end_ of the instruction.
+                let addend = i64::from(addend) - 4 - i64::from(trailing);
+                self.write_relocation(RelocationKind::Rel32, target, addend)
+            }
         }
     }
 
     #[inline(always)]
-    pub fn write_word(&mut self, word: u16) -> io::Result<()> {
-        self.emitter.write_word(word)
+    pub fn write_word(&mut self, word: u16) -> Result<()> {
+        Ok(self.emitter.write_word(word)?)
     }
 
     #[inline(always)]
-    pub fn write_dword(&mut self, dword: u32) -> io::Result<()> {
-        self.emitter.write_dword(dword)
+    pub fn write_dword(&mut self, dword: u32) -> Result<()> {
+        Ok(self.emitter.write_dword(dword)?)
     }
 
     #[inline(always)]
-    pub fn write_qword(&mut self, qword: u64) -> io::Result<()> {
-        self.emitter.write_qword(qword)
+    pub fn write_qword(&mut self, qword: u64) -> Result<()> {
+        Ok(self.emitter.write_qword(qword)?)
+    }
+
+    /// Writes `vex`, using the 2 byte form when it can.
+    pub(crate) fn write_vex(&mut self, vex: Vex) -> Result<()> {
+        // the 2 byte form implies X, B, the `0F` map and W0.
+        if vex.0 & 0b0111_1111 == 0b0110_0001 && vex.1 & 0b1000_0000 == 0 {
+            self.write_byte(0xc5)?;
+            return self.write_byte((vex.0 & 0b1000_0000) | vex.1);
+        }
+
+        self.write_byte(0xc4)?;
+        self.write_byte(vex.0)?;
+        self.write_byte(vex.1)
     }
 
-    pub(crate) fn write_vex(&mut self, vex: Vex) -> io::Result<()> {
-        self.emitter.write_byte(0xc4)?;
-        self.emitter.write_byte(vex.0)?;
-        self.emitter.write_byte(vex.1)
+    pub(crate) fn write_evex(&mut self, evex: Evex) -> Result<()> {
+        self.write_byte(0x62)?;
+        self.write_byte(evex.0)?;
+        self.write_byte(evex.1)?;
+        self.write_byte(evex.2)
     }
 
     #[inline(always)]
-    pub(crate) fn write_immediate(&mut self, imm: WritableImmediate) -> io::Result<()> {
+    pub(crate) fn write_immediate(&mut self, imm: WritableImmediate) -> Result<()> {
         match imm {
             WritableImmediate::W8(byte) => self.write_byte(byte),
             WritableImmediate::W16(word) => self.write_word(word),
@@ -187,18 +542,74 @@ impl Vex {
         debug_assert!(pp <= 0b0011);
         debug_assert!(mm <= 0b1_1111);
 
-        let l = false;
         let b0 = ((r as u8) << 7) | ((x as u8) << 6) | ((b as u8) << 5) | (mm & 0b1_1111);
-        let b1 = ((w as u8) << 7) | ((vvvv & 0b1111) << 3) | ((l as u8) << 2) | (pp & 0b11);
+        let b1 = ((w as u8) << 7) | ((vvvv & 0b1111) << 3) | (pp & 0b11);
 
         Self(b0, b1)
     }
+
+    /// Sets VEX.L, for 256 bit vectors.
+    fn with_l(self, l: bool) -> Self {
+        Self(self.0, self.1 | ((l as u8) << 2))
+    }
+}
+
+/// The 3 payload bytes of an EVEX prefix.
+#[derive(Copy, Clone)]
+struct Evex(u8, u8, u8);
+
+impl Evex {
+    /// `reg` and `vvvv` are register numbers (0-31), `x` and `b` are the extension bits of ModRM.rm.
+    ///
+    /// Unlike [`Vex::new`], none of these are inverted yet.
+    fn new(reg: u8, vvvv: u8, x: bool, b: bool, pp: u8, mm: u8, w: bool) -> Self {
+        debug_assert!(reg < 32);
+        debug_assert!(vvvv < 32);
+        debug_assert!(pp <= 0b11);
+        debug_assert!(mm <= 0b11);
+
+        let r = reg & 0b1000 == 0;
+        let r_prime = reg & 0b1_0000 == 0;
+        let v_prime = vvvv & 0b1_0000 == 0;
+
+        let p0 =
+            ((r as u8) << 7) | ((!x as u8) << 6) | ((!b as u8) << 5) | ((r_prime as u8) << 4) | mm;
+        let p1 = ((w as u8) << 7) | ((!vvvv & 0b1111) << 3) | 0b100 | pp;
+        let p2 = (v_prime as u8) << 3;
+
+        Self(p0, p1, p2)
+    }
+
+    /// Sets EVEX.z and EVEX.aaa.
+    fn with_masking(self, masking: Masking) -> Self {
+        Self(
+            self.0,
+            self.1,
+            self.2 | ((masking.z() as u8) << 7) | masking.aaa(),
+        )
+    }
+
+    /// Sets EVEX.L'L, which is the vector length or the rounding mode if EVEX.b is set on a register operand.
+    fn with_ll(self, ll: u8) -> Self {
+        debug_assert!(ll <= 0b11);
+        Self(self.0, self.1, self.2 | (ll << 5))
+    }
+
+    /// Sets EVEX.b, which is broadcast for memory operands and static rounding for registers.
+    fn with_b(self) -> Self {
+        Self(self.0, self.1, self.2 | 0b1_0000)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::Assembler;
-    use std::io::{self, Cursor};
+    use super::{
+        Assembler, CodeBuffer, Error, Relocation, RelocationKind, RelocationTarget, Result,
+    };
+    use crate::params::mem::Mem;
+    use crate::params::reg::Reg64;
+    use std::collections::HashMap;
+    use std::io::Cursor;
 
     fn create_writer(size: usize) -> Cursor<Box<[u8]>> {
         let inner = vec![0; size].into_boxed_slice();
@@ -206,7 +617,7 @@ mod test {
     }
 
     #[test]
-    fn writes_label_behind() -> io::Result<()> {
+    fn writes_label_behind() -> Result<()> {
         let mut writer = create_writer(9);
         let mut assembler = Assembler::new(&mut writer)?;
         let label = assembler.make_label_attached();
@@ -226,13 +637,13 @@ mod test {
     }
 
     #[test]
-    fn writes_label_ahead() -> io::Result<()> {
+    fn writes_label_ahead() -> Result<()> {
         let mut writer = create_writer(8);
         let mut assembler = Assembler::new(&mut writer)?;
         let label = assembler.make_label();
         assembler.write_label(label)?;
 
-        assembler.attach_label(label);
+        assembler.attach_label(label)?;
 
         assert_eq!(assembler.start_offset(), 0);
         assert_eq!(assembler.current_offset(), 8);
@@ -248,7 +659,23 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
+    fn writes_label_ahead_relative() -> Result<()> {
+        let mut writer = create_writer(9);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let label = assembler.make_label();
+        assembler.nop()?;
+        assembler.write_label(label)?;
+
+        assembler.attach_label(label)?;
+        assembler.finish()?;
+
+        // the label is at 9 and the use is at 1, so it's 8 and not 9.
+        assert_eq!(&writer.into_inner()[1..], &8_u64.to_le_bytes());
+
+        Ok(())
+    }
+
+    #[test]
     fn errors_on_unattached_label() {
         let mut writer = create_writer(8);
         let mut assembler = Assembler::new(&mut writer).unwrap();
@@ -261,7 +688,436 @@ mod test {
         assert_eq!(assembler.start_offset(), 0);
         assert_eq!(assembler.current_offset(), 8);
 
-        // this should panic due to the unattached label.
-        assembler.finish().unwrap();
+        assert!(matches!(
+            assembler.finish(),
+            Err(Error::UnresolvedLabel(it)) if it == label
+        ));
+    }
+
+    #[test]
+    fn errors_on_unattached_branch_target() {
+        let mut writer = create_writer(2);
+        let mut assembler = Assembler::new(&mut writer).unwrap();
+        let label = assembler.make_label();
+        assembler.jmp_label(label).unwrap();
+
+        assert!(matches!(
+            assembler.finish(),
+            Err(Error::UnresolvedLabel(it)) if it == label
+        ));
+    }
+
+    #[test]
+    fn errors_on_duplicate_label() {
+        let mut writer = create_writer(1);
+        let mut assembler = Assembler::new(&mut writer).unwrap();
+        let label = assembler.make_label_attached();
+        assembler.nop().unwrap();
+
+        assert!(matches!(
+            assembler.attach_label(label),
+            Err(Error::DuplicateLabel(it)) if it == label
+        ));
+    }
+
+    #[test]
+    fn jmp_label_behind_is_short() -> Result<()> {
+        let mut writer = create_writer(3);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let label = assembler.make_label_attached();
+        assembler.nop()?;
+        assembler.jmp_label(label)?;
+        assembler.finish()?;
+
+        assert_eq!(&*writer.into_inner(), &[0x90, 0xeb, 0xfd]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn jmp_label_far_behind_is_near() -> Result<()> {
+        let mut writer = create_writer(0x85);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let label = assembler.make_label_attached();
+        for _ in 0..0x80 {
+            assembler.nop()?;
+        }
+
+        assembler.jmp_label(label)?;
+        assembler.finish()?;
+
+        assert_eq!(
+            &writer.into_inner()[0x80..],
+            &[0xe9, 0x7b, 0xff, 0xff, 0xff]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn jcc_label_ahead_is_short() -> Result<()> {
+        let mut writer = create_writer(3);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let label = assembler.make_label();
+        assembler.jne_label(label)?;
+        assembler.nop()?;
+        assembler.attach_label(label)?;
+
+        assert_eq!(assembler.current_offset(), 3);
+
+        assembler.finish()?;
+
+        assert_eq!(&*writer.into_inner(), &[0x75, 0x01, 0x90]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn jcc_label_far_ahead_is_near() -> Result<()> {
+        let mut writer = create_writer(0x86);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let label = assembler.make_label();
+        assembler.jz_label(label)?;
+        for _ in 0..0x80 {
+            assembler.nop()?;
+        }
+
+        assembler.attach_label(label)?;
+
+        assert_eq!(assembler.current_offset(), 0x86);
+
+        assembler.finish()?;
+
+        assert_eq!(
+            &writer.into_inner()[..6],
+            &[0x0f, 0x84, 0x80, 0x00, 0x00, 0x00]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn relaxation_grows_dependent_branches() -> Result<()> {
+        // `outer` starts out in range, but stops being in range once `inner` has to grow.
+        let mut writer = create_writer(0x94);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let outer = assembler.make_label();
+        let inner = assembler.make_label();
+
+        assembler.jmp_label(outer)?;
+        assembler.jmp_label(inner)?;
+        for _ in 0..0x7c {
+            assembler.nop()?;
+        }
+
+        assembler.attach_label(outer)?;
+        for _ in 0..0x4 {
+            assembler.nop()?;
+        }
+
+        assembler.attach_label(inner)?;
+
+        // both branches grew, so the labels after them moved.
+        assert_eq!(assembler.current_offset(), 0x8a);
+
+        assembler.jmp_label(inner)?;
+        assembler.write_label(outer)?;
+
+        assembler.finish()?;
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..5], &[0xe9, 0x81, 0x00, 0x00, 0x00]);
+        assert_eq!(&code[5..10], &[0xe9, 0x80, 0x00, 0x00, 0x00]);
+        assert_eq!(&code[0x8a..0x8c], &[0xeb, 0xfe]);
+        assert_eq!(&code[0x8c..], &(-6_i64).to_le_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn finish_reports_relocations() -> Result<()> {
+        let mut writer = create_writer(0x14);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let ahead = assembler.make_label();
+        let behind = assembler.make_label_attached();
+
+        assembler.jmp_label(ahead)?;
+        assembler.jmp_label(behind)?;
+        assembler.write_label_address(ahead)?;
+        assembler.write_label(behind)?;
+        assembler.attach_label(ahead)?;
+
+        let assembled = assembler.finish()?;
+
+        assert_eq!(assembled.code_len(), 0x14);
+        assert_eq!(
+            assembled.relocations(),
+            &[
+                Relocation {
+                    offset: 1,
+                    kind: RelocationKind::Rel8,
+                    target: RelocationTarget::Label(ahead),
+                    addend: -1,
+                },
+                Relocation {
+                    offset: 3,
+                    kind: RelocationKind::Rel8,
+                    target: RelocationTarget::Label(behind),
+                    addend: -1,
+                },
+                Relocation {
+                    offset: 4,
+                    kind: RelocationKind::Abs64,
+                    target: RelocationTarget::Label(ahead),
+                    addend: 0,
+                },
+                Relocation {
+                    offset: 0xc,
+                    kind: RelocationKind::Rel64,
+                    target: RelocationTarget::Label(behind),
+                    addend: 0,
+                },
+            ]
+        );
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..4], &[0xeb, 0x12, 0xeb, 0xfc]);
+        assert_eq!(&code[4..0xc], &0x14_u64.to_le_bytes());
+        assert_eq!(&code[0xc..0x14], &(-0xc_i64).to_le_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn external_symbols_are_left_for_the_loader() -> Result<()> {
+        let mut writer = create_writer(0x11);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let helper = assembler.external_symbol("helper");
+        let table = assembler.external_symbol("table");
+        assert_eq!(assembler.external_symbol("helper"), helper);
+
+        let start = assembler.make_label_attached();
+        assembler.call_symbol(helper)?;
+        assembler.mov_reg_mem(Reg64::ZAX, Mem::symbol(table))?;
+        assembler.jmp_symbol(helper)?;
+
+        let assembled = assembler.finish()?;
+        assert_eq!(assembled.symbol_name(table), "table");
+        assert_eq!(assembled.label_offset(start), Some(0));
+        assert_eq!(
+            assembled.relocations(),
+            &[
+                Relocation {
+                    offset: 1,
+                    kind: RelocationKind::Branch32,
+                    target: RelocationTarget::Symbol(helper),
+                    addend: -4,
+                },
+                Relocation {
+                    offset: 8,
+                    kind: RelocationKind::Rel32,
+                    target: RelocationTarget::Symbol(table),
+                    addend: -4,
+                },
+                Relocation {
+                    offset: 0xd,
+                    kind: RelocationKind::Branch32,
+                    target: RelocationTarget::Symbol(helper),
+                    addend: -4,
+                },
+            ]
+        );
+
+        let mut code = writer.into_inner();
+        assert_eq!(&code[..5], &[0xe8, 0, 0, 0, 0]);
+
+        let mut symbols = HashMap::new();
+        symbols.insert("helper".to_owned(), 0x1000);
+        assert!(matches!(
+            assembled.relocate(&mut code, 0x2000, &symbols),
+            Err(Error::UnresolvedSymbol(name)) if name == "table"
+        ));
+
+        symbols.insert("table".to_owned(), 0x3000);
+        assembled.relocate(&mut code, 0x2000, &symbols)?;
+        assert_eq!(&code[1..5], &(-0x1005_i32).to_le_bytes());
+        assert_eq!(&code[8..0xc], &0xff4_i32.to_le_bytes());
+        assert_eq!(&code[0xd..], &(-0x1011_i32).to_le_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn constant_pool_is_deduplicated_and_aligned() -> Result<()> {
+        let mut writer = create_writer(0x28);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        let constant = assembler.constant_u64(0x1122_3344_5566_7788);
+        assert_eq!(assembler.constant_u64(0x1122_3344_5566_7788), constant);
+        let mask = assembler.constant_bytes(&[0xff; 16], 16);
+
+        assembler.mov_reg_mem(Reg64::ZAX, Mem::label(constant))?;
+        assembler.ret()?;
+
+        let assembled = assembler.finish()?;
+        assert_eq!(assembled.label_offset(mask), Some(0x10));
+        assert_eq!(assembled.label_offset(constant), Some(0x20));
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..7], &[0x48, 0x8b, 0x05, 0x19, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            &code[7..0x10],
+            &[0xc3, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc]
+        );
+        assert_eq!(&code[0x10..0x20], &[0xff; 16]);
+        assert_eq!(&code[0x20..], &0x1122_3344_5566_7788_u64.to_le_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn pool_padding_is_redone_after_relaxation() -> Result<()> {
+        let mut writer = create_writer(0x90);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let end = assembler.make_label();
+        let constant = assembler.constant_f64(1.5);
+
+        assembler.jmp_label(end)?;
+        for _ in 0..0x80 {
+            assembler.nop()?;
+        }
+
+        // provisionally the pool starts at 0x82, but the jump grows by 3.
+        assembler.flush_pool()?;
+        assembler.attach_label(end)?;
+
+        let assembled = assembler.finish()?;
+        assert_eq!(assembled.code_len(), 0x90);
+        assert_eq!(assembled.label_offset(constant), Some(0x88));
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..5], &[0xe9, 0x8b, 0x00, 0x00, 0x00]);
+        assert_eq!(&code[0x85..0x88], &[0xcc; 3]);
+        assert_eq!(&code[0x88..], &1.5_f64.to_bits().to_le_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn aligned_label_stays_aligned_after_relaxation() -> Result<()> {
+        let mut writer = create_writer(0x91);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let end = assembler.make_label();
+
+        assembler.jmp_label(end)?;
+        for _ in 0..0x80 {
+            assembler.nop()?;
+        }
+
+        assembler.align(16)?;
+        let head = assembler.make_label_attached();
+        assembler.nop()?;
+        assembler.attach_label(end)?;
+
+        let assembled = assembler.finish()?;
+        assert_eq!(assembled.label_offset(head), Some(0x90));
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..5], &[0xe9, 0x8c, 0x00, 0x00, 0x00]);
+        // the jump grew by 3, so only 11 bytes of padding are left.
+        assert_eq!(
+            &code[0x85..0x8e],
+            &[0x66, 0x0f, 0x1f, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(&code[0x8e..0x90], &[0x66, 0x90]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn label_before_align_isnt_padded() -> Result<()> {
+        let mut writer = create_writer(0x90);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let end = assembler.make_label();
+
+        assembler.jmp_label(end)?;
+        for _ in 0..0x80 {
+            assembler.nop()?;
+        }
+
+        let before = assembler.make_label_attached();
+        assembler.align(16)?;
+        assembler.attach_label(end)?;
+
+        let assembled = assembler.finish()?;
+        assert_eq!(assembled.label_offset(before), Some(0x85));
+        assert_eq!(assembled.label_offset(end), Some(0x90));
+
+        Ok(())
+    }
+
+    #[test]
+    fn labels_around_unpadded_align() -> Result<()> {
+        let mut writer = create_writer(0xa0);
+        let mut assembler = Assembler::new(&mut writer)?;
+        let end = assembler.make_label();
+
+        assembler.jmp_label(end)?;
+        for _ in 0..0x8e {
+            assembler.nop()?;
+        }
+
+        // provisionally these are both at 0x90, which doesn't need any padding.
+        let before = assembler.make_label_attached();
+        assembler.align(16)?;
+        let after = assembler.make_label_attached();
+        assembler.attach_label(end)?;
+
+        let assembled = assembler.finish()?;
+        assert_eq!(assembled.label_offset(before), Some(0x93));
+        assert_eq!(assembled.label_offset(after), Some(0xa0));
+
+        let code = writer.into_inner();
+        assert_eq!(&code[..5], &[0xe9, 0x9b, 0x00, 0x00, 0x00]);
+        assert_eq!(&code[0x92..0x94], &[0x90, 0x66]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn align_with_fills() -> Result<()> {
+        let mut writer = create_writer(4);
+        let mut assembler = Assembler::new(&mut writer)?;
+
+        assembler.ret()?;
+        assembler.align_with(4, 0xcc)?;
+        assembler.align(4)?;
+
+        assembler.finish()?;
+
+        assert_eq!(&*writer.into_inner(), &[0xc3, 0xcc, 0xcc, 0xcc]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn code_buffer_patches_in_place() -> Result<()> {
+        let mut buffer = CodeBuffer::from(vec![0xcc]);
+        let mut assembler = Assembler::new(&mut buffer)?;
+        let ahead = assembler.make_label();
+
+        assembler.write_label_address(ahead)?;
+        assembler.attach_label(ahead)?;
+        assembler.ret()?;
+
+        let assembled = assembler.finish()?;
+        assert_eq!(assembled.code_len(), 9);
+
+        // the existing contents are kept, and the address is relative to where assembling started.
+        assert_eq!(&buffer[..1], &[0xcc]);
+        assert_eq!(&buffer[1..9], &8_u64.to_le_bytes());
+        assert_eq!(&buffer[9..], &[0xc3]);
+
+        Ok(())
     }
 }
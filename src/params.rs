@@ -1,3 +1,5 @@
+pub mod avx512;
+pub mod condition;
 pub mod imm;
 pub mod mem;
 pub mod reg;
@@ -7,9 +9,16 @@ pub struct W16;
 pub struct W32;
 pub struct W64;
 
+/// Vector widths, these only show up as the size of memory operands of vector instructions.
+pub struct W128;
+pub struct W256;
+pub struct W512;
+
+pub use avx512::{Masking, Rounding};
+pub use condition::Condition;
 pub use imm::{Immediate, WritableImmediate};
-pub use mem::{Mem, Memory};
-pub use reg::{GeneralRegister, Register};
+pub use mem::{Mem, Memory, VectorMemory};
+pub use reg::{GeneralRegister, Hi8, Opmask, Register, VectorRegister, Xmm, Ymm, Zmm};
 
 mod private {
     pub trait Sealed {}
@@ -18,6 +27,9 @@ mod private {
     impl Sealed for super::W16 {}
     impl Sealed for super::W32 {}
     impl Sealed for super::W64 {}
+    impl Sealed for super::W128 {}
+    impl Sealed for super::W256 {}
+    impl Sealed for super::W512 {}
 }
 
 pub trait WWidth: private::Sealed {
@@ -40,6 +52,24 @@ impl WWidth for W64 {
     const IS_W64: bool = true;
 }
 
+/// The width of a vector, as opposed to [`WWidth`], which is the width of a general purpose operand.
+pub trait VectorWidth: private::Sealed {
+    /// The value of VEX.L (or EVEX.L'L).
+    const LL: u8;
+}
+
+impl VectorWidth for W128 {
+    const LL: u8 = 0;
+}
+
+impl VectorWidth for W256 {
+    const LL: u8 = 1;
+}
+
+impl VectorWidth for W512 {
+    const LL: u8 = 2;
+}
+
 pub trait WidthAtLeast16: WWidth {}
 
 impl WidthAtLeast16 for W16 {}
@@ -57,6 +87,12 @@ impl WidthAtMost32 for W8 {}
 impl WidthAtMost32 for W16 {}
 impl WidthAtMost32 for W32 {}
 
+/// Widths that can be pushed and popped, in 64 bit mode these are 16 and 64 bits.
+pub trait StackWidth: WidthAtLeast16 {}
+
+impl StackWidth for W16 {}
+impl StackWidth for W64 {}
+
 pub trait WidthAtMost16: WWidth {}
 
 impl WidthAtMost16 for W8 {}
@@ -0,0 +1,50 @@
+use crate::params::Opmask;
+
+/// How an AVX-512 instruction writes elements of its destination that are masked off.
+///
+/// [`Opmask::K0`] can't be used as a write mask, instructions given it return [`Error::InvalidWritemask`](crate::Error::InvalidWritemask).
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum Masking {
+    /// Every element gets written.
+    None,
+    /// Elements with a clear bit in the mask keep their old value (`{k}`).
+    Merge(Opmask),
+    /// Elements with a clear bit in the mask get zeroed (`{k}{z}`).
+    Zero(Opmask),
+}
+
+impl Masking {
+    /// EVEX.z
+    pub(crate) fn z(self) -> bool {
+        matches!(self, Self::Zero(_))
+    }
+
+    /// Whether this can be encoded, `K0` in EVEX.aaa means no masking, so it can't be a write mask.
+    pub(crate) fn is_valid(self) -> bool {
+        !matches!(self, Self::Merge(Opmask::K0) | Self::Zero(Opmask::K0))
+    }
+
+    /// EVEX.aaa
+    pub(crate) fn aaa(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Merge(k) | Self::Zero(k) => k.value(),
+        }
+    }
+}
+
+impl From<Option<Opmask>> for Masking {
+    fn from(mask: Option<Opmask>) -> Self {
+        mask.map_or(Self::None, Self::Merge)
+    }
+}
+
+/// Static rounding for AVX-512 instructions, this also suppresses all floating point exceptions (`{rn-sae}` and friends).
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum Rounding {
+    Nearest = 0b00,
+    Down = 0b01,
+    Up = 0b10,
+    Zero = 0b11,
+}
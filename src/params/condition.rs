@@ -0,0 +1,139 @@
+/// A condition code, as used by `jcc`, `setcc` and `cmovcc`.
+///
+/// Every mnemonic alias (like `nae` or `z`) is available as an associated constant,
+/// so `Condition::NAE == Condition::C == Condition::Below`.
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum Condition {
+    Overflow = 0x0,
+    NotOverflow = 0x1,
+    Below = 0x2,
+    AboveOrEqual = 0x3,
+    Equal = 0x4,
+    NotEqual = 0x5,
+    BelowOrEqual = 0x6,
+    Above = 0x7,
+    Sign = 0x8,
+    NotSign = 0x9,
+    Parity = 0xa,
+    NotParity = 0xb,
+    Less = 0xc,
+    GreaterOrEqual = 0xd,
+    LessOrEqual = 0xe,
+    Greater = 0xf,
+}
+
+impl Condition {
+    pub const O: Self = Self::Overflow;
+    pub const NO: Self = Self::NotOverflow;
+    pub const B: Self = Self::Below;
+    pub const C: Self = Self::Below;
+    pub const NAE: Self = Self::Below;
+    pub const AE: Self = Self::AboveOrEqual;
+    pub const NB: Self = Self::AboveOrEqual;
+    pub const NC: Self = Self::AboveOrEqual;
+    pub const E: Self = Self::Equal;
+    pub const Z: Self = Self::Equal;
+    pub const NE: Self = Self::NotEqual;
+    pub const NZ: Self = Self::NotEqual;
+    pub const BE: Self = Self::BelowOrEqual;
+    pub const NA: Self = Self::BelowOrEqual;
+    pub const A: Self = Self::Above;
+    pub const NBE: Self = Self::Above;
+    pub const S: Self = Self::Sign;
+    pub const NS: Self = Self::NotSign;
+    pub const P: Self = Self::Parity;
+    pub const PE: Self = Self::Parity;
+    pub const NP: Self = Self::NotParity;
+    pub const PO: Self = Self::NotParity;
+    pub const L: Self = Self::Less;
+    pub const NGE: Self = Self::Less;
+    pub const GE: Self = Self::GreaterOrEqual;
+    pub const NL: Self = Self::GreaterOrEqual;
+    pub const LE: Self = Self::LessOrEqual;
+    pub const NG: Self = Self::LessOrEqual;
+    pub const G: Self = Self::Greater;
+    pub const NLE: Self = Self::Greater;
+
+    /// Every condition, in encoding order.
+    pub const ALL: [Self; 16] = [
+        Self::Overflow,
+        Self::NotOverflow,
+        Self::Below,
+        Self::AboveOrEqual,
+        Self::Equal,
+        Self::NotEqual,
+        Self::BelowOrEqual,
+        Self::Above,
+        Self::Sign,
+        Self::NotSign,
+        Self::Parity,
+        Self::NotParity,
+        Self::Less,
+        Self::GreaterOrEqual,
+        Self::LessOrEqual,
+        Self::Greater,
+    ];
+
+    /// Returns the condition that holds exactly when `self` doesn't.
+    #[must_use]
+    pub fn negate(self) -> Self {
+        // conditions come in pairs that only differ in the low bit.
+        Self::ALL[(self.value() ^ 1) as usize]
+    }
+
+    /// Looks up a condition by its mnemonic suffix, any alias is accepted (`"nae"`, `"c"` and `"b"` are all [`Condition::Below`]).
+    #[must_use]
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        let condition = match mnemonic {
+            "o" => Self::O,
+            "no" => Self::NO,
+            "b" | "c" | "nae" => Self::B,
+            "ae" | "nb" | "nc" => Self::AE,
+            "e" | "z" => Self::E,
+            "ne" | "nz" => Self::NE,
+            "be" | "na" => Self::BE,
+            "a" | "nbe" => Self::A,
+            "s" => Self::S,
+            "ns" => Self::NS,
+            "p" | "pe" => Self::P,
+            "np" | "po" => Self::NP,
+            "l" | "nge" => Self::L,
+            "ge" | "nl" => Self::GE,
+            "le" | "ng" => Self::LE,
+            "g" | "nle" => Self::G,
+            _ => return None,
+        };
+
+        Some(condition)
+    }
+
+    pub(crate) fn value(self) -> u8 {
+        self as u8
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Condition;
+
+    #[test]
+    fn negate_is_an_involution() {
+        for condition in Condition::ALL {
+            assert_ne!(condition.negate(), condition);
+            assert_eq!(condition.negate().negate(), condition);
+        }
+
+        assert_eq!(Condition::Equal.negate(), Condition::NotEqual);
+        assert_eq!(Condition::Less.negate(), Condition::GreaterOrEqual);
+        assert_eq!(Condition::Above.negate(), Condition::NA);
+    }
+
+    #[test]
+    fn aliases_agree() {
+        assert_eq!(Condition::from_mnemonic("nae"), Some(Condition::C));
+        assert_eq!(Condition::from_mnemonic("z"), Some(Condition::Equal));
+        assert_eq!(Condition::from_mnemonic("po"), Some(Condition::NotParity));
+        assert_eq!(Condition::from_mnemonic("zz"), None);
+    }
+}
@@ -47,3 +47,15 @@ pub enum WritableImmediate {
     W32(u32),
     W64(u64),
 }
+
+impl WritableImmediate {
+    /// The number of bytes this immediate takes up.
+    pub(crate) fn len(self) -> u8 {
+        match self {
+            Self::W8(_) => 1,
+            Self::W16(_) => 2,
+            Self::W32(_) => 4,
+            Self::W64(_) => 8,
+        }
+    }
+}
@@ -1,9 +1,12 @@
-use crate::params::{W8, W16, W32, W64, WWidth, reg::Register};
+use crate::params::{VectorWidth, W8, W16, W32, W64, W128, W256, W512, WWidth, reg::Register};
+use crate::{Error, Label, RelocationTarget, Result, Symbol};
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum Displacement {
     Disp8(i8),
     Disp32(i32),
+    /// A RIP relative disp32 that points `addend` bytes past a label or symbol, patched once its address is known.
+    Relative(RelocationTarget, i32),
 }
 
 #[repr(u8)]
@@ -122,6 +125,7 @@ pub struct Mem {
     has_index: bool,
     relative: bool,
     force_32x: bool,
+    target: Option<RelocationTarget>,
 }
 
 impl Mem {
@@ -137,6 +141,7 @@ impl Mem {
             has_index: false,
             relative: false,
             force_32x: false,
+            target: None,
         }
     }
 
@@ -153,20 +158,21 @@ impl Mem {
             has_index: false,
             relative: false,
             force_32x: false,
+            target: None,
         }
     }
 
     /// # Errors
     /// When [`index`] is [`Register::Zsp`], as Zsp can't be used as an index.
-    pub fn base_index(base: Register, index: Register) -> Result<Self, ()> {
+    pub fn base_index(base: Register, index: Register) -> Result<Self> {
         Self::base_index_scale(base, index, Scale::X1)
     }
 
     /// # Errors
     /// When [`index`] is [`Register::Zsp`], as Zsp can't be used as an index.
-    pub fn base_index_scale(base: Register, index: Register, scale: Scale) -> Result<Self, ()> {
+    pub fn base_index_scale(base: Register, index: Register, scale: Scale) -> Result<Self> {
         if index == Register::Zsp {
-            Err(())
+            Err(Error::InvalidIndexRegister(index))
         } else {
             Ok(Self {
                 base: Some(base),
@@ -176,6 +182,7 @@ impl Mem {
                 has_index: true,
                 relative: false,
                 force_32x: false,
+                target: None,
             })
         }
     }
@@ -186,9 +193,9 @@ impl Mem {
         index: Register,
         scale: Scale,
         displacement: i32,
-    ) -> Result<Self, ()> {
+    ) -> Result<Self> {
         if index == Register::Zsp {
-            Err(())
+            Err(Error::InvalidIndexRegister(index))
         } else {
             Ok(Self {
                 base: Some(Register::Zbp),
@@ -198,23 +205,26 @@ impl Mem {
                 has_index: true,
                 relative: false,
                 force_32x: false,
+                target: None,
             })
         }
     }
 
     /// # Errors
     /// When [`index`] is [`Register::Zsp`], as Zsp can't be used as an index.
-    pub fn with_index(index: Register) -> Result<Self, ()> {
+    pub fn with_index(index: Register) -> Result<Self> {
         Self::with_index_scale_displacement(index, Scale::X1, 0)
     }
 
     /// # Errors
     /// When [`index`] is [`Register::Zsp`], as Zsp can't be used as an index.
-    pub fn with_index_scale(index: Register, scale: Scale) -> Result<Self, ()> {
+    pub fn with_index_scale(index: Register, scale: Scale) -> Result<Self> {
         Self::with_index_scale_displacement(index, scale, 0)
     }
 
-    pub fn with_index_displacement(index: Register, displacement: i32) -> Result<Self, ()> {
+    /// # Errors
+    /// When [`index`] is [`Register::Zsp`], as Zsp can't be used as an index.
+    pub fn with_index_displacement(index: Register, displacement: i32) -> Result<Self> {
         Self::with_index_scale_displacement(index, Scale::X1, displacement)
     }
 
@@ -224,9 +234,9 @@ impl Mem {
         index: Register,
         scale: Scale,
         displacement: i32,
-    ) -> Result<Self, ()> {
+    ) -> Result<Self> {
         if index == Register::Zsp {
-            Err(())
+            Err(Error::InvalidIndexRegister(index))
         } else {
             Ok(Self {
                 base: None,
@@ -236,6 +246,7 @@ impl Mem {
                 has_index: true,
                 relative: false,
                 force_32x: false,
+                target: None,
             })
         }
     }
@@ -253,6 +264,37 @@ impl Mem {
             has_index: false,
             relative: true,
             force_32x: false,
+            target: None,
+        }
+    }
+
+    /// Creates a RIP relative `Mem` that points at `label`.
+    pub fn label(label: Label) -> Self {
+        Self::label_displacement(label, 0)
+    }
+
+    /// Creates a RIP relative `Mem` that points `displacement` bytes past `label`.
+    ///
+    /// The label doesn't need to be attached yet, the displacement gets patched once it is.
+    pub fn label_displacement(label: Label, displacement: i32) -> Self {
+        Self {
+            target: Some(RelocationTarget::Label(label)),
+            ..Self::relative_displacement(displacement)
+        }
+    }
+
+    /// Creates a RIP relative `Mem` that points at the external `symbol`.
+    pub fn symbol(symbol: Symbol) -> Self {
+        Self::symbol_displacement(symbol, 0)
+    }
+
+    /// Creates a RIP relative `Mem` that points `displacement` bytes past the external `symbol`.
+    ///
+    /// The displacement is left for whoever loads the code to patch, see [`Assembled`](crate::Assembled).
+    pub fn symbol_displacement(symbol: Symbol, displacement: i32) -> Self {
+        Self {
+            target: Some(RelocationTarget::Symbol(symbol)),
+            ..Self::relative_displacement(displacement)
         }
     }
 
@@ -269,11 +311,25 @@ impl Mem {
     }
 
     pub(crate) fn encoded(&self) -> (ModRM, Option<SIB>, Option<Displacement>) {
-        let mod_rm = self.mod_rm();
+        self.encoded_compressed(1)
+    }
+
+    /// Like [`encoded`](Self::encoded), but a disp8 gets multiplied by `n`, this is EVEX's compressed disp8*N.
+    pub(crate) fn encoded_compressed(&self, n: i32) -> (ModRM, Option<SIB>, Option<Displacement>) {
+        let mod_rm = self.mod_rm(n);
         if mod_rm.has_sib() {
-            (mod_rm, Some(self.sib()), self.get_displacement())
+            (mod_rm, Some(self.sib()), self.get_displacement(n))
         } else {
-            (mod_rm, None, self.get_displacement())
+            (mod_rm, None, self.get_displacement(n))
+        }
+    }
+
+    /// The displacement as a disp8, if it fits in one when it's going to get multiplied by `n`.
+    fn disp8(&self, n: i32) -> Option<i8> {
+        if self.displacement % n == 0 {
+            i8::try_from(self.displacement / n).ok()
+        } else {
+            None
         }
     }
 
@@ -288,14 +344,15 @@ impl Mem {
         }
     }
 
-    pub(crate) fn mod_rm(&self) -> ModRM {
+    /// `n` is the disp8 scale, see [`encoded_compressed`](Self::encoded_compressed).
+    pub(crate) fn mod_rm(&self, n: i32) -> ModRM {
         match self.base {
             Some(base) => {
                 // x86-64 encodes what would be [Zbp] pr [R13] as rip relative in this form, so we have to do [Zbp + 0].
                 let mod_bits =
                     if self.displacement == 0 && base != Register::Zbp && base != Register::R13 {
                         0b00
-                    } else if self.displacement <= 0xff {
+                    } else if self.disp8(n).is_some() {
                         0b01
                     } else {
                         0b10
@@ -330,12 +387,15 @@ impl Mem {
         SIB::new(self.scale as u8, index, base)
     }
 
-    pub(crate) fn get_displacement(&self) -> Option<Displacement> {
-        let mod_rm = self.mod_rm();
+    /// `n` is the disp8 scale, see [`encoded_compressed`](Self::encoded_compressed).
+    pub(crate) fn get_displacement(&self, n: i32) -> Option<Displacement> {
+        let mod_rm = self.mod_rm(n);
 
         if mod_rm.has_displacement() || (mod_rm.has_sib() && self.sib().has_displacement()) {
-            if mod_rm.r#mod() == 1 {
-                Some(Displacement::Disp8(self.displacement as i8))
+            if let Some(target) = self.target {
+                Some(Displacement::Relative(target, self.displacement))
+            } else if mod_rm.r#mod() == 1 {
+                Some(Displacement::Disp8((self.displacement / n) as i8))
             } else {
                 Some(Displacement::Disp32(self.displacement))
             }
@@ -352,6 +412,13 @@ impl Memory<W16> for Mem {}
 impl Memory<W32> for Mem {}
 impl Memory<W64> for Mem {}
 
+/// Like [`Memory`], but for the operands of vector instructions.
+pub trait VectorMemory<Width: VectorWidth>: Into<Mem> {}
+
+impl VectorMemory<W128> for Mem {}
+impl VectorMemory<W256> for Mem {}
+impl VectorMemory<W512> for Mem {}
+
 // while the following _would_ look nicer with a macro, IDEs have trouble auto-completing it.
 
 /// A wrapper for [`Mem`] That only implements [`Memory<W8>`], to make it more usable as a type param.
@@ -406,6 +473,45 @@ impl From<Mem64> for Mem {
 
 impl Memory<W64> for Mem64 {}
 
+/// A wrapper for [`Mem`] That only implements [`VectorMemory<W128>`], to make it more usable as a type param.
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct Mem128(pub Mem);
+
+impl From<Mem128> for Mem {
+    fn from(other: Mem128) -> Self {
+        other.0
+    }
+}
+
+impl VectorMemory<W128> for Mem128 {}
+
+/// A wrapper for [`Mem`] That only implements [`VectorMemory<W256>`], to make it more usable as a type param.
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct Mem256(pub Mem);
+
+impl From<Mem256> for Mem {
+    fn from(other: Mem256) -> Self {
+        other.0
+    }
+}
+
+impl VectorMemory<W256> for Mem256 {}
+
+/// A wrapper for [`Mem`] That only implements [`VectorMemory<W512>`], to make it more usable as a type param.
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct Mem512(pub Mem);
+
+impl From<Mem512> for Mem {
+    fn from(other: Mem512) -> Self {
+        other.0
+    }
+}
+
+impl VectorMemory<W512> for Mem512 {}
+
 #[cfg(test)]
 mod test {
     use super::Displacement;
@@ -422,4 +528,41 @@ mod test {
 
         assert_eq!(disp, Some(Displacement::Disp32(4)));
     }
+
+    #[test]
+    fn mod_rm_label_has_label_displacement() {
+        let label = crate::Label(0);
+        let mem = super::Mem::label_displacement(label, 8);
+        let (mod_rm, sib, disp) = mem.encoded();
+        assert_eq!(mod_rm.r#mod(), 0b00);
+        assert_eq!(mod_rm.rm(), 0b101);
+
+        assert_eq!(sib, None);
+
+        assert_eq!(
+            disp,
+            Some(Displacement::Relative(
+                crate::RelocationTarget::Label(label),
+                8
+            ))
+        );
+    }
+
+    #[test]
+    fn large_displacement_is_disp32() {
+        let mem = super::Mem::base_displacement(super::Register::Zax, 200);
+        assert_eq!(mem.get_displacement(1), Some(Displacement::Disp32(200)));
+
+        let mem = super::Mem::base_displacement(super::Register::Zax, -128);
+        assert_eq!(mem.get_displacement(1), Some(Displacement::Disp8(-128)));
+    }
+
+    #[test]
+    fn zsp_is_not_an_index() {
+        let err = super::Mem::base_index(super::Register::Zax, super::Register::Zsp);
+        assert!(matches!(
+            err,
+            Err(crate::Error::InvalidIndexRegister(super::Register::Zsp))
+        ));
+    }
 }
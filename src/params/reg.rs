@@ -1,4 +1,4 @@
-use super::{W8, W16, W32, W64, WWidth};
+use super::{VectorWidth, W8, W16, W32, W64, W128, W256, WWidth};
 
 #[repr(u8)]
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Ord, PartialOrd)]
@@ -35,6 +35,172 @@ impl Register {
     }
 }
 
+/// A high byte register, bits 8-15 of the first four general purpose registers.
+///
+/// These can't be encoded in instructions with a REX prefix, so they can't be used together with
+/// R8B-R15B, SPL, BPL, SIL, DIL or addresses using R8-R15, see [`Error::Hi8RexConflict`](crate::Error::Hi8RexConflict).
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Ord, PartialOrd)]
+pub enum Hi8 {
+    Ah = 4,
+    Ch = 5,
+    Dh = 6,
+    Bh = 7,
+}
+
+impl Hi8 {
+    pub(crate) fn value(self) -> u8 {
+        self as u8
+    }
+}
+
+/// An SSE register.
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Ord, PartialOrd)]
+pub enum Xmm {
+    Xmm0 = 0,
+    Xmm1 = 1,
+    Xmm2 = 2,
+    Xmm3 = 3,
+    Xmm4 = 4,
+    Xmm5 = 5,
+    Xmm6 = 6,
+    Xmm7 = 7,
+    Xmm8 = 8,
+    Xmm9 = 9,
+    Xmm10 = 10,
+    Xmm11 = 11,
+    Xmm12 = 12,
+    Xmm13 = 13,
+    Xmm14 = 14,
+    Xmm15 = 15,
+}
+
+impl Xmm {
+    pub(crate) fn value(self) -> u8 {
+        self as u8
+    }
+}
+
+/// An AVX register, the low half of each is the [`Xmm`] with the same number.
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Ord, PartialOrd)]
+pub enum Ymm {
+    Ymm0 = 0,
+    Ymm1 = 1,
+    Ymm2 = 2,
+    Ymm3 = 3,
+    Ymm4 = 4,
+    Ymm5 = 5,
+    Ymm6 = 6,
+    Ymm7 = 7,
+    Ymm8 = 8,
+    Ymm9 = 9,
+    Ymm10 = 10,
+    Ymm11 = 11,
+    Ymm12 = 12,
+    Ymm13 = 13,
+    Ymm14 = 14,
+    Ymm15 = 15,
+}
+
+impl From<Ymm> for Xmm {
+    fn from(other: Ymm) -> Self {
+        const XMMS: [Xmm; 16] = [
+            Xmm::Xmm0,
+            Xmm::Xmm1,
+            Xmm::Xmm2,
+            Xmm::Xmm3,
+            Xmm::Xmm4,
+            Xmm::Xmm5,
+            Xmm::Xmm6,
+            Xmm::Xmm7,
+            Xmm::Xmm8,
+            Xmm::Xmm9,
+            Xmm::Xmm10,
+            Xmm::Xmm11,
+            Xmm::Xmm12,
+            Xmm::Xmm13,
+            Xmm::Xmm14,
+            Xmm::Xmm15,
+        ];
+
+        XMMS[other as usize]
+    }
+}
+
+/// An AVX-512 register, unlike [`Xmm`] and [`Ymm`] there are 32 of these.
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Ord, PartialOrd)]
+pub enum Zmm {
+    Zmm0 = 0,
+    Zmm1 = 1,
+    Zmm2 = 2,
+    Zmm3 = 3,
+    Zmm4 = 4,
+    Zmm5 = 5,
+    Zmm6 = 6,
+    Zmm7 = 7,
+    Zmm8 = 8,
+    Zmm9 = 9,
+    Zmm10 = 10,
+    Zmm11 = 11,
+    Zmm12 = 12,
+    Zmm13 = 13,
+    Zmm14 = 14,
+    Zmm15 = 15,
+    Zmm16 = 16,
+    Zmm17 = 17,
+    Zmm18 = 18,
+    Zmm19 = 19,
+    Zmm20 = 20,
+    Zmm21 = 21,
+    Zmm22 = 22,
+    Zmm23 = 23,
+    Zmm24 = 24,
+    Zmm25 = 25,
+    Zmm26 = 26,
+    Zmm27 = 27,
+    Zmm28 = 28,
+    Zmm29 = 29,
+    Zmm30 = 30,
+    Zmm31 = 31,
+}
+
+impl Zmm {
+    pub(crate) fn value(self) -> u8 {
+        self as u8
+    }
+}
+
+/// An AVX-512 opmask register.
+///
+/// As a write mask `K0` means "no mask", see [`Masking`](super::Masking).
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash, Ord, PartialOrd)]
+pub enum Opmask {
+    K0 = 0,
+    K1 = 1,
+    K2 = 2,
+    K3 = 3,
+    K4 = 4,
+    K5 = 5,
+    K6 = 6,
+    K7 = 7,
+}
+
+impl Opmask {
+    pub(crate) fn value(self) -> u8 {
+        self as u8
+    }
+}
+
+/// A vector register that's `Width` wide, for instructions that work on both [`Xmm`] and [`Ymm`].
+pub trait VectorRegister<Width: VectorWidth>: Into<Xmm> {}
+
+impl VectorRegister<W128> for Xmm {}
+impl VectorRegister<W256> for Ymm {}
+
 pub trait GeneralRegister<Width: WWidth>: Into<Register> {}
 
 impl GeneralRegister<W8> for Register {}
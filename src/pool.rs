@@ -0,0 +1,62 @@
+use crate::Label;
+
+use std::collections::HashMap;
+
+pub struct Constant {
+    pub bytes: Vec<u8>,
+    pub align: u64,
+    pub label: Label,
+}
+
+/// Constants that have been asked for but not written out yet.
+///
+/// Identical constants share one entry (and label), using the strictest alignment any of them asked for.
+#[derive(Default)]
+pub struct ConstantPool {
+    constants: Vec<Constant>,
+    lookup: HashMap<Vec<u8>, usize>,
+}
+
+impl ConstantPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the label of the entry for `bytes`, using `make_label` to create it if there isn't one yet.
+    pub fn insert(
+        &mut self,
+        bytes: &[u8],
+        align: u64,
+        make_label: impl FnOnce() -> Label,
+    ) -> Label {
+        if let Some(&idx) = self.lookup.get(bytes) {
+            let constant = &mut self.constants[idx];
+            constant.align = constant.align.max(align);
+            return constant.label;
+        }
+
+        let label = make_label();
+        self.lookup.insert(bytes.to_vec(), self.constants.len());
+        self.constants.push(Constant {
+            bytes: bytes.to_vec(),
+            align,
+            label,
+        });
+
+        label
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.constants.is_empty()
+    }
+
+    /// Empties the pool, returning every entry with the most aligned first.
+    pub fn take(&mut self) -> Vec<Constant> {
+        self.lookup.clear();
+        let mut constants = std::mem::take(&mut self.constants);
+
+        // stable, so equally aligned constants stay in the order they were asked for.
+        constants.sort_by_key(|constant| std::cmp::Reverse(constant.align));
+        constants
+    }
+}
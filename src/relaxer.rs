@@ -0,0 +1,328 @@
+use crate::align::{Fill, padding_len, write_padding};
+use crate::{Label, Relocation, RelocationKind, RelocationTarget};
+
+use std::collections::{HashMap, HashSet};
+
+/// The size of every branch while it's still a placeholder, this is the size of the short (rel8) form.
+pub const SHORT_LEN: u64 = 2;
+
+#[derive(Copy, Clone)]
+pub enum BranchKind {
+    Jmp,
+    /// a conditional jump, with the condition code in the low nibble.
+    Jcc(u8),
+}
+
+impl BranchKind {
+    pub fn len(self, near: bool) -> u64 {
+        match (self, near) {
+            (_, false) => SHORT_LEN,
+            (Self::Jmp, true) => 5,
+            (Self::Jcc(_), true) => 6,
+        }
+    }
+
+    /// The relocation for the displacement of a branch that ends at `end`.
+    pub fn relocation(self, near: bool, end: u64, target: Label) -> Relocation {
+        let kind = if near {
+            RelocationKind::Branch32
+        } else {
+            RelocationKind::Rel8
+        };
+
+        let size = kind.size();
+
+        Relocation {
+            offset: end - u64::from(size),
+            kind,
+            target: RelocationTarget::Label(target),
+            addend: -i64::from(size),
+        }
+    }
+
+    pub fn encode(self, near: bool, displacement: i32, out: &mut Vec<u8>) {
+        match (self, near) {
+            (Self::Jmp, false) => out.push(0xeb),
+            (Self::Jmp, true) => out.push(0xe9),
+            (Self::Jcc(cc), false) => out.push(0x70 | cc),
+            (Self::Jcc(cc), true) => out.extend_from_slice(&[0x0f, 0x80 | cc]),
+        }
+
+        if near {
+            out.extend_from_slice(&displacement.to_le_bytes());
+        } else {
+            debug_assert!(i8::try_from(displacement).is_ok());
+            out.push(displacement as u8);
+        }
+    }
+}
+
+enum ItemKind {
+    Branch {
+        kind: BranchKind,
+        target: Label,
+        near: bool,
+    },
+    /// Padding up to a multiple of `align`, `provisional` is how much padding was written for the provisional offset.
+    Align {
+        align: u64,
+        fill: Fill,
+        provisional: u64,
+    },
+}
+
+struct Item {
+    /// the provisional offset of the item.
+    offset: u64,
+    kind: ItemKind,
+}
+
+impl Item {
+    /// Checks if the item is entirely before the code at `address`, and so moves it.
+    ///
+    /// Padding at `address` counts as being before it, since that code gets written after the padding.
+    /// Labels don't take up space, so they can't be remapped by address, see [`Relaxer::remap_label`].
+    fn is_before(&self, address: u64) -> bool {
+        match self.kind {
+            ItemKind::Branch { .. } => self.offset < address,
+            ItemKind::Align { .. } => self.offset <= address,
+        }
+    }
+}
+
+/// Tracks a region of code that contains branches whose size can't be known yet.
+///
+/// Every branch in the region is emitted as a short placeholder,
+/// once every target is attached the region is laid out by growing branches that don't fit until nothing changes.
+/// Branches only ever grow, so this always terminates.
+///
+/// Alignment padding in the region depends on where everything before it ends up, so it gets recomputed during layout too.
+pub struct Relaxer {
+    start: u64,
+    /// the offset that alignment is relative to.
+    origin: u64,
+    items: Vec<Item>,
+    waiting_on: HashSet<Label>,
+    /// every label attached inside the region (these are the only ones that can move), with how many items come before it.
+    labels: HashMap<Label, usize>,
+    /// the index of the first relocation made inside the region, the ones before it can't move either.
+    first_relocation: usize,
+    /// `growth[i]` is how many bytes the first `i` items grew (or shrank) by, only valid after `layout`.
+    growth: Vec<i64>,
+}
+
+impl Relaxer {
+    pub fn new(start: u64, origin: u64, first_relocation: usize) -> Self {
+        Self {
+            start,
+            origin,
+            items: Vec::new(),
+            waiting_on: HashSet::new(),
+            labels: HashMap::new(),
+            first_relocation,
+            growth: Vec::new(),
+        }
+    }
+
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    pub fn first_relocation(&self) -> usize {
+        self.first_relocation
+    }
+
+    /// Every label that was attached inside the region.
+    pub fn labels(&self) -> impl Iterator<Item = Label> + '_ {
+        self.labels.keys().copied()
+    }
+
+    /// Adds a branch placeholder at `offset`, `attached` is whether or not `target` has been attached yet.
+    pub fn push(&mut self, offset: u64, kind: BranchKind, target: Label, attached: bool) {
+        debug_assert!(self.items.last().is_none_or(|it| it.offset <= offset));
+
+        if !attached {
+            self.waiting_on.insert(target);
+        }
+
+        self.items.push(Item {
+            offset,
+            kind: ItemKind::Branch {
+                kind,
+                target,
+                near: false,
+            },
+        });
+    }
+
+    /// Adds alignment padding at `offset`, `provisional` bytes of padding were written for it.
+    pub fn push_align(&mut self, offset: u64, align: u64, fill: Fill, provisional: u64) {
+        debug_assert!(self.items.last().is_none_or(|it| it.offset <= offset));
+
+        self.items.push(Item {
+            offset,
+            kind: ItemKind::Align {
+                align,
+                fill,
+                provisional,
+            },
+        });
+    }
+
+    /// Notifies the region that `label` has been attached, returns true if the region can now be laid out.
+    pub fn label_attached(&mut self, label: Label) -> bool {
+        self.labels.insert(label, self.items.len());
+        self.waiting_on.remove(&label);
+        self.is_ready()
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.waiting_on.is_empty()
+    }
+
+    /// Returns a branch target that hasn't been attached yet, if there are any.
+    pub fn unattached(&self) -> Option<Label> {
+        self.waiting_on.iter().next().copied()
+    }
+
+    /// Picks the size of every branch.
+    ///
+    /// `resolve` gives the provisional address of a label.
+    pub fn layout(&mut self, resolve: impl Fn(Label) -> u64) {
+        loop {
+            self.compute_growth();
+
+            let grown: Vec<usize> = self
+                .items
+                .iter()
+                .enumerate()
+                .filter(|(idx, item)| match item.kind {
+                    ItemKind::Branch {
+                        target,
+                        near: false,
+                        ..
+                    } => {
+                        let end = self.final_offset(*idx) + SHORT_LEN;
+                        let target = self.remap_label(target, resolve(target));
+                        i8::try_from(target.wrapping_sub(end) as i64).is_err()
+                    }
+
+                    _ => false,
+                })
+                .map(|(idx, _)| idx)
+                .collect();
+
+            if grown.is_empty() {
+                break;
+            }
+
+            for idx in grown {
+                if let ItemKind::Branch { near, .. } = &mut self.items[idx].kind {
+                    *near = true;
+                }
+            }
+        }
+    }
+
+    fn compute_growth(&mut self) {
+        self.growth.clear();
+        self.growth.push(0);
+
+        let mut total = 0;
+        for item in &self.items {
+            total += match item.kind {
+                ItemKind::Branch { kind, near, .. } => (kind.len(near) - SHORT_LEN) as i64,
+                ItemKind::Align {
+                    align, provisional, ..
+                } => {
+                    let offset = item.offset.wrapping_add_signed(total) - self.origin;
+                    padding_len(offset, align) as i64 - provisional as i64
+                }
+            };
+
+            self.growth.push(total);
+        }
+    }
+
+    /// The final offset of the item at `idx`, only valid after `compute_growth`.
+    fn final_offset(&self, idx: usize) -> u64 {
+        self.items[idx].offset.wrapping_add_signed(self.growth[idx])
+    }
+
+    /// Maps a provisional address to its final address, only valid after `layout`.
+    pub fn remap(&self, address: u64) -> u64 {
+        if address < self.start {
+            return address;
+        }
+
+        let count = self.items.partition_point(|item| item.is_before(address));
+
+        address.wrapping_add_signed(self.growth[count])
+    }
+
+    /// Maps the provisional address of `label` to its final address, only valid after `layout`.
+    ///
+    /// A label attached right before padding has the same provisional address as one attached right after it
+    /// (when there wasn't any provisional padding), so labels move by the items that were pushed before them instead.
+    pub fn remap_label(&self, label: Label, address: u64) -> u64 {
+        match self.labels.get(&label) {
+            Some(&count) => address.wrapping_add_signed(self.growth[count]),
+            None => self.remap(address),
+        }
+    }
+
+    /// The relocation of every branch in the region, at their final offsets, only valid after `layout`.
+    pub fn relocations(&self) -> impl Iterator<Item = Relocation> + '_ {
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, item)| match item.kind {
+                ItemKind::Branch { kind, target, near } => {
+                    let end = self.final_offset(idx) + kind.len(near);
+                    Some(kind.relocation(near, end, target))
+                }
+
+                ItemKind::Align { .. } => None,
+            })
+    }
+
+    /// Produces the final code for the region from the `provisional` code, only valid after `layout`.
+    pub fn assemble(&self, provisional: &[u8], resolve: impl Fn(Label) -> u64) -> Vec<u8> {
+        let total = self.growth[self.items.len()];
+        let mut out =
+            Vec::with_capacity((provisional.len() as u64).wrapping_add_signed(total) as usize);
+        let mut cursor = 0;
+
+        for (idx, item) in self.items.iter().enumerate() {
+            let relative = (item.offset - self.start) as usize;
+            out.extend_from_slice(&provisional[cursor..relative]);
+
+            let offset = self.final_offset(idx);
+
+            match item.kind {
+                ItemKind::Branch { kind, target, near } => {
+                    let end = offset + kind.len(near);
+                    let target = self.remap_label(target, resolve(target));
+                    kind.encode(near, target.wrapping_sub(end) as i32, &mut out);
+
+                    cursor = relative + SHORT_LEN as usize;
+                }
+
+                ItemKind::Align {
+                    align,
+                    fill,
+                    provisional,
+                } => {
+                    let len = padding_len(offset - self.origin, align);
+                    write_padding(len, fill, &mut out);
+
+                    cursor = relative + provisional as usize;
+                }
+            }
+        }
+
+        out.extend_from_slice(&provisional[cursor..]);
+
+        out
+    }
+}
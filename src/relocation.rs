@@ -0,0 +1,206 @@
+use crate::{Error, Label, Result, Symbol};
+
+use std::collections::HashMap;
+
+/// How the value of a [`Relocation`] is computed and how big it is.
+///
+/// `S` is the address of the target, `A` is the addend, and `P` is the address of the relocation itself.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum RelocationKind {
+    /// `S + A`, 64 bits wide.
+    Abs64,
+    /// `S + A - P`, 64 bits wide.
+    Rel64,
+    /// `S + A - P`, 32 bits wide.
+    Rel32,
+    /// `S + A - P`, 32 bits wide, as the displacement of a `call`, `jmp` or `jcc`.
+    ///
+    /// This is computed like [`Rel32`](Self::Rel32), but a linker may send it through a PLT.
+    Branch32,
+    /// `S + A - P`, 8 bits wide.
+    Rel8,
+}
+
+impl RelocationKind {
+    /// The number of bytes the relocated value takes up.
+    #[must_use]
+    pub fn size(self) -> u8 {
+        match self {
+            Self::Abs64 | Self::Rel64 => 8,
+            Self::Rel32 | Self::Branch32 => 4,
+            Self::Rel8 => 1,
+        }
+    }
+
+    #[must_use]
+    pub fn is_relative(self) -> bool {
+        !matches!(self, Self::Abs64)
+    }
+
+    /// Checks if a value computed by `value` can be written without losing information.
+    pub(crate) fn fits(self, value: u64) -> bool {
+        match self {
+            Self::Abs64 | Self::Rel64 => true,
+            Self::Rel32 | Self::Branch32 => i32::try_from(value as i64).is_ok(),
+            Self::Rel8 => i8::try_from(value as i64).is_ok(),
+        }
+    }
+
+    /// Computes the value to write, `target` and `offset` have to be relative to the same base.
+    pub(crate) fn value(self, target: u64, addend: i64, offset: u64) -> u64 {
+        let value = target.wrapping_add(addend as u64);
+
+        if self.is_relative() {
+            value.wrapping_sub(offset)
+        } else {
+            value
+        }
+    }
+}
+
+/// What a [`Relocation`] points at.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum RelocationTarget {
+    Label(Label),
+    /// An external symbol, these are never resolved by the assembler itself.
+    Symbol(Symbol),
+}
+
+/// A place in the assembled code whose value depends on the address of something.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct Relocation {
+    /// Where the value is, relative to the start of the assembled code.
+    pub offset: u64,
+    pub kind: RelocationKind,
+    pub target: RelocationTarget,
+    pub addend: i64,
+}
+
+/// A summary of everything [`Assembler::finish`](crate::Assembler::finish) wrote.
+///
+/// Every relocation against a label has already been applied as if the code starts at address 0,
+/// so relative relocations only need to be reapplied if the code gets split up,
+/// and absolute relocations need the address the code ends up at added to them.
+/// Relocations against external symbols are left as zero, see [`relocate`](Self::relocate).
+#[derive(Clone, Debug)]
+pub struct Assembled {
+    len: u64,
+    relocations: Vec<Relocation>,
+    labels: HashMap<Label, u64>,
+    label_names: Vec<(Label, String)>,
+    symbols: Vec<String>,
+}
+
+impl Assembled {
+    pub(crate) fn new(
+        len: u64,
+        mut relocations: Vec<Relocation>,
+        labels: HashMap<Label, u64>,
+        label_names: Vec<(Label, String)>,
+        symbols: Vec<String>,
+    ) -> Self {
+        relocations.sort_by_key(|relocation| relocation.offset);
+        Self {
+            len,
+            relocations,
+            labels,
+            label_names,
+            symbols,
+        }
+    }
+
+    /// The number of bytes of code that were written.
+    #[must_use]
+    pub fn code_len(&self) -> u64 {
+        self.len
+    }
+
+    /// Every relocation site, sorted by offset.
+    #[must_use]
+    pub fn relocations(&self) -> &[Relocation] {
+        &self.relocations
+    }
+
+    /// The offset of `label` relative to the start of the code, if it was attached.
+    #[must_use]
+    pub fn label_offset(&self, label: Label) -> Option<u64> {
+        self.labels.get(&label).copied()
+    }
+
+    /// Every label made with [`make_named_label`](crate::Assembler::make_named_label), in creation order.
+    pub fn named_labels(&self) -> impl Iterator<Item = (Label, &str)> {
+        self.label_names
+            .iter()
+            .map(|(label, name)| (*label, name.as_str()))
+    }
+
+    /// The name `symbol` was created with.
+    ///
+    /// # Panics
+    /// If `symbol` is from a different assembler.
+    #[must_use]
+    pub fn symbol_name(&self, symbol: Symbol) -> &str {
+        &self.symbols[symbol.0]
+    }
+
+    /// Every external symbol that was created, in creation order.
+    pub fn symbols(&self) -> impl Iterator<Item = (Symbol, &str)> {
+        self.symbols
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| (Symbol(idx), name.as_str()))
+    }
+
+    /// Applies every relocation to `code` as if it was placed at `base`,
+    /// with external symbols at the addresses in `symbols`.
+    ///
+    /// # Errors
+    /// If a symbol isn't in `symbols`, or a relative relocation ends up out of range.
+    ///
+    /// # Panics
+    /// If `code` is shorter than the assembled code.
+    pub fn relocate(
+        &self,
+        code: &mut [u8],
+        base: u64,
+        symbols: &HashMap<String, u64>,
+    ) -> Result<()> {
+        for relocation in &self.relocations {
+            let target = match relocation.target {
+                RelocationTarget::Label(label) => {
+                    let offset = self
+                        .label_offset(label)
+                        .ok_or(Error::UnresolvedLabel(label))?;
+                    base.wrapping_add(offset)
+                }
+
+                RelocationTarget::Symbol(symbol) => {
+                    let name = self.symbol_name(symbol);
+                    *symbols
+                        .get(name)
+                        .ok_or_else(|| Error::UnresolvedSymbol(name.to_owned()))?
+                }
+            };
+
+            let kind = relocation.kind;
+            let value = kind.value(
+                target,
+                relocation.addend,
+                base.wrapping_add(relocation.offset),
+            );
+
+            if !kind.fits(value) {
+                return Err(Error::DisplacementOutOfRange {
+                    target: relocation.target,
+                    offset: relocation.offset,
+                });
+            }
+
+            let offset = relocation.offset as usize;
+            let size = usize::from(kind.size());
+            code[offset..offset + size].copy_from_slice(&value.to_le_bytes()[..size]);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use std::io::{self, Seek, SeekFrom, Write};
+use std::ops::Deref;
+
+/// Somewhere an [`Assembler`](crate::Assembler) can write code to.
+///
+/// Anything that's [`Write`] + [`Seek`] is a sink, patches are done by seeking back and forth.
+/// For code that's assembled in memory [`CodeBuffer`] avoids the seeking entirely.
+pub trait CodeSink {
+    /// Where the next [`append`](Self::append) goes.
+    ///
+    /// # Errors
+    /// If the position can't be queried.
+    fn position(&mut self) -> io::Result<u64>;
+
+    /// Writes `buf` at the current position.
+    ///
+    /// # Errors
+    /// If writing fails.
+    fn append(&mut self, buf: &[u8]) -> io::Result<()>;
+
+    /// Overwrites code that was already written at `offset` with `buf`, without changing the position.
+    ///
+    /// # Errors
+    /// If writing fails.
+    fn patch(&mut self, offset: u64, buf: &[u8]) -> io::Result<()>;
+}
+
+impl<T: Write + Seek> CodeSink for T {
+    fn position(&mut self) -> io::Result<u64> {
+        self.stream_position()
+    }
+
+    fn append(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf)
+    }
+
+    fn patch(&mut self, offset: u64, buf: &[u8]) -> io::Result<()> {
+        let position = self.stream_position()?;
+        self.seek(SeekFrom::Start(offset))?;
+        self.write_all(buf)?;
+        self.seek(SeekFrom::Start(position))?;
+
+        Ok(())
+    }
+}
+
+/// An in-memory sink that grows as code gets written and patches in place.
+///
+/// Code gets appended to the end, so a buffer created from a non-empty `Vec` keeps its contents.
+#[derive(Clone, Default, Debug)]
+pub struct CodeBuffer {
+    code: Vec<u8>,
+}
+
+impl CodeBuffer {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            code: Vec::with_capacity(capacity),
+        }
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.code
+    }
+}
+
+impl From<Vec<u8>> for CodeBuffer {
+    fn from(code: Vec<u8>) -> Self {
+        Self { code }
+    }
+}
+
+impl Deref for CodeBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.code
+    }
+}
+
+impl CodeSink for CodeBuffer {
+    fn position(&mut self) -> io::Result<u64> {
+        Ok(self.code.len() as u64)
+    }
+
+    fn append(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.code.extend_from_slice(buf);
+        Ok(())
+    }
+
+    fn patch(&mut self, offset: u64, buf: &[u8]) -> io::Result<()> {
+        let offset = offset as usize;
+        self.code[offset..offset + buf.len()].copy_from_slice(buf);
+        Ok(())
+    }
+}